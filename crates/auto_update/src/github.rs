@@ -4,6 +4,10 @@ use serde::Deserialize;
 #[derive(Debug, Deserialize)]
 pub struct GithubRelease {
     pub tag_name: String,
+    #[serde(default)]
+    pub prerelease: bool,
+    #[serde(default)]
+    pub draft: bool,
     pub assets: Vec<GithubAsset>,
 }
 
@@ -13,6 +17,16 @@ pub struct GithubAsset {
     pub browser_download_url: String,
 }
 
+/// Which releases the updater offers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum UpdateChannel {
+    /// Only the latest stable release.
+    #[default]
+    Stable,
+    /// The highest version among all published releases, prereleases included.
+    Prerelease,
+}
+
 #[derive(Debug, Clone)]
 pub struct ReleaseInfo {
     pub version: String,
@@ -76,6 +90,16 @@ fn find_platform_asset<'a>(assets: &'a [GithubAsset], arch: &str) -> Option<&'a
         _ => arch,
     };
 
+    // When running from an AppImage, update it in place with the AppImage asset
+    if std::env::var_os("APPIMAGE").is_some() {
+        let appimage = assets.iter().find(|a| {
+            (a.name.contains(arch) || a.name.contains(linux_arch)) && a.name.ends_with(".AppImage")
+        });
+        if appimage.is_some() {
+            return appimage;
+        }
+    }
+
     // Look for .tar.gz tarball with matching architecture
     assets
         .iter()
@@ -106,20 +130,55 @@ fn get_extension(name: &str) -> String {
         "msi".to_string()
     } else if name.ends_with(".exe") {
         "exe".to_string()
+    } else if name.ends_with(".AppImage") {
+        "AppImage".to_string()
     } else {
         "bin".to_string()
     }
 }
 
-pub fn fetch_latest_release() -> Result<ReleaseInfo> {
-    let url = "https://api.github.com/repos/lassejlv/termy/releases/latest";
-    let response: GithubRelease = ureq::get(url)
-        .set("User-Agent", "Termy-Updater/1.0")
-        .set("Accept", "application/vnd.github+json")
-        .call()
-        .context("Failed to fetch latest release from GitHub")?
-        .into_json()
-        .context("Failed to parse GitHub release JSON")?;
+const RELEASES_URL: &str = "https://api.github.com/repos/lassejlv/termy/releases";
+
+fn release_version(release: &GithubRelease) -> Option<semver::Version> {
+    let tag = release
+        .tag_name
+        .strip_prefix('v')
+        .unwrap_or(&release.tag_name);
+    semver::Version::parse(tag).ok()
+}
+
+/// The highest-versioned published release. Semver orders `1.2.0-beta.2` below
+/// `1.2.0`, so a stable release wins over its own prereleases.
+fn newest_release(releases: Vec<GithubRelease>) -> Option<GithubRelease> {
+    releases
+        .into_iter()
+        .filter(|release| !release.draft)
+        .filter_map(|release| release_version(&release).map(|version| (version, release)))
+        .max_by(|(a, _), (b, _)| a.cmp(b))
+        .map(|(_, release)| release)
+}
+
+pub fn fetch_latest_release(channel: UpdateChannel) -> Result<ReleaseInfo> {
+    let response = match channel {
+        UpdateChannel::Stable => ureq::get(&format!("{}/latest", RELEASES_URL))
+            .set("User-Agent", "Termy-Updater/1.0")
+            .set("Accept", "application/vnd.github+json")
+            .call()
+            .context("Failed to fetch latest release from GitHub")?
+            .into_json::<GithubRelease>()
+            .context("Failed to parse GitHub release JSON")?,
+        UpdateChannel::Prerelease => {
+            let releases: Vec<GithubRelease> = ureq::get(RELEASES_URL)
+                .query("per_page", "30")
+                .set("User-Agent", "Termy-Updater/1.0")
+                .set("Accept", "application/vnd.github+json")
+                .call()
+                .context("Failed to fetch releases from GitHub")?
+                .into_json()
+                .context("Failed to parse GitHub releases JSON")?;
+            newest_release(releases).context("No published release with a semver tag")?
+        }
+    };
 
     let version = response
         .tag_name
@@ -139,3 +198,37 @@ pub fn fetch_latest_release() -> Result<ReleaseInfo> {
         extension: get_extension(&asset.name),
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn release(tag: &str, prerelease: bool, draft: bool) -> GithubRelease {
+        GithubRelease {
+            tag_name: tag.to_string(),
+            prerelease,
+            draft,
+            assets: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn newest_release_orders_prereleases_below_their_stable_version() {
+        let releases = vec![
+            release("v1.2.0-beta.2", true, false),
+            release("v1.2.0", false, false),
+            release("v1.1.9", false, false),
+            release("v1.2.0-beta.10", true, false),
+        ];
+        assert_eq!(newest_release(releases).unwrap().tag_name, "v1.2.0");
+
+        let releases = vec![
+            release("v1.2.0", false, false),
+            release("v1.3.0-beta.2", true, false),
+            release("v1.3.0-beta.10", true, false),
+            release("v1.4.0", false, true),
+            release("nightly", true, false),
+        ];
+        assert_eq!(newest_release(releases).unwrap().tag_name, "v1.3.0-beta.10");
+    }
+}
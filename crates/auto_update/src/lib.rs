@@ -1,10 +1,15 @@
 mod github;
 
-pub use github::{ReleaseInfo, fetch_latest_release};
+pub use github::{ReleaseInfo, UpdateChannel, fetch_latest_release};
 
 use anyhow::{Context, Result};
 use gpui::{App, AsyncApp, WeakEntity};
+use std::collections::VecDeque;
 use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+/// Download speed is averaged over this much recent progress.
+const DOWNLOAD_RATE_WINDOW: Duration = Duration::from_secs(1);
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum UpdateState {
@@ -20,6 +25,9 @@ pub enum UpdateState {
         version: String,
         downloaded: u64,
         total: u64,
+        bytes_per_sec: u64,
+        /// `None` while the speed or the total size is unknown.
+        eta_secs: Option<u64>,
     },
     Downloaded {
         version: String,
@@ -36,17 +44,50 @@ pub enum UpdateState {
 
 pub struct AutoUpdater {
     current_version: &'static str,
+    channel: UpdateChannel,
+    skipped_version: Option<String>,
     pub state: UpdateState,
 }
 
 impl AutoUpdater {
-    pub fn new(current_version: &'static str) -> Self {
+    pub fn new(current_version: &'static str, channel: UpdateChannel) -> Self {
         Self {
             current_version,
+            channel,
+            skipped_version: None,
             state: UpdateState::Idle,
         }
     }
 
+    /// Stop offering `version`. An `Available` state for it turns into `UpToDate`;
+    /// newer versions are still offered.
+    pub fn skip_version(&mut self, version: String) {
+        if let UpdateState::Available {
+            version: available, ..
+        } = &self.state
+            && *available == version
+        {
+            self.state = UpdateState::UpToDate;
+        }
+        self.skipped_version = Some(version);
+    }
+
+    /// Restores the skipped version from settings, e.g. on startup.
+    pub fn set_skipped_version(&mut self, version: Option<String>) {
+        self.skipped_version = version;
+    }
+
+    fn is_skipped(&self, version: &str) -> bool {
+        self.skipped_version
+            .as_deref()
+            .is_some_and(|skipped| skipped.strip_prefix('v').unwrap_or(skipped) == version)
+    }
+
+    /// Takes effect on the next `check`.
+    pub fn set_channel(&mut self, channel: UpdateChannel) {
+        self.channel = channel;
+    }
+
     pub fn check(entity: WeakEntity<Self>, cx: &mut App) {
         let Some(this) = entity.upgrade() else { return };
         this.update(cx, |this, cx| {
@@ -55,9 +96,10 @@ impl AutoUpdater {
         });
 
         let current_version = this.read(cx).current_version.to_string();
+        let channel = this.read(cx).channel;
         let bg = cx
             .background_executor()
-            .spawn(async move { fetch_latest_release() });
+            .spawn(async move { fetch_latest_release(channel) });
 
         let weak = entity.clone();
         cx.spawn(async move |cx: &mut AsyncApp| {
@@ -70,7 +112,7 @@ impl AutoUpdater {
                             let current = semver::Version::parse(&current_version).ok();
                             let latest = semver::Version::parse(&info.version).ok();
                             match (current, latest) {
-                                (Some(c), Some(l)) if l > c => {
+                                (Some(c), Some(l)) if l > c && !this.is_skipped(&info.version) => {
                                     this.state = UpdateState::Available {
                                         version: info.version,
                                         url: info.download_url,
@@ -114,6 +156,8 @@ impl AutoUpdater {
                 version: version.clone(),
                 downloaded: 0,
                 total: 0,
+                bytes_per_sec: 0,
+                eta_secs: None,
             };
             cx.notify();
         });
@@ -129,17 +173,23 @@ impl AutoUpdater {
         let weak_progress = entity.clone();
         let progress_version = version.clone();
         cx.spawn(async move |cx: &mut AsyncApp| {
+            let mut rate = DownloadRate::default();
             while let Ok((downloaded, total)) = progress_rx.recv_async().await {
                 let Some(this) = weak_progress.upgrade() else {
                     break;
                 };
                 let ver = progress_version.clone();
+                let bytes_per_sec = rate.record(Instant::now(), downloaded);
+                let eta_secs = (total > 0 && bytes_per_sec > 0)
+                    .then(|| total.saturating_sub(downloaded).div_ceil(bytes_per_sec));
                 cx.update(|cx| {
                     this.update(cx, |this, cx| {
                         this.state = UpdateState::Downloading {
                             version: ver,
                             downloaded,
                             total,
+                            bytes_per_sec,
+                            eta_secs,
                         };
                         cx.notify();
                     });
@@ -227,6 +277,32 @@ impl AutoUpdater {
     }
 }
 
+/// Rolling download speed over the last `DOWNLOAD_RATE_WINDOW` of samples.
+#[derive(Default)]
+struct DownloadRate {
+    samples: VecDeque<(Instant, u64)>,
+}
+
+impl DownloadRate {
+    /// Records the bytes downloaded so far and returns the current speed.
+    fn record(&mut self, now: Instant, downloaded: u64) -> u64 {
+        self.samples.push_back((now, downloaded));
+        // Keep one sample at or before the window start as the baseline.
+        while self.samples.len() > 2
+            && now.duration_since(self.samples[1].0) >= DOWNLOAD_RATE_WINDOW
+        {
+            self.samples.pop_front();
+        }
+
+        let (start, start_bytes) = self.samples[0];
+        let elapsed = now.duration_since(start).as_secs_f64();
+        if elapsed <= 0.0 {
+            return 0;
+        }
+        (downloaded.saturating_sub(start_bytes) as f64 / elapsed) as u64
+    }
+}
+
 #[cfg(target_os = "macos")]
 fn cache_installer_path(version: &str, extension: &str) -> PathBuf {
     let home = std::env::var("HOME").unwrap_or_else(|_| "/tmp".to_string());
@@ -248,6 +324,16 @@ fn cache_installer_path(version: &str, extension: &str) -> PathBuf {
 
 #[cfg(target_os = "linux")]
 fn cache_installer_path(version: &str, extension: &str) -> PathBuf {
+    // Download AppImages next to the running one so the final rename stays on one
+    // filesystem and is atomic
+    if extension == "AppImage"
+        && let Some(dir) = current_appimage()
+            .as_deref()
+            .and_then(|appimage| appimage.parent())
+    {
+        return dir.join(format!(".termy-update-{}.AppImage", version));
+    }
+
     // Use XDG_CACHE_HOME or ~/.cache
     let cache_dir = std::env::var("XDG_CACHE_HOME")
         .map(PathBuf::from)
@@ -463,7 +549,73 @@ fn do_install(installer_path: &PathBuf) -> Result<()> {
 }
 
 #[cfg(target_os = "linux")]
-fn do_install(tarball_path: &PathBuf) -> Result<()> {
+fn current_appimage() -> Option<PathBuf> {
+    std::env::var_os("APPIMAGE")
+        .filter(|path| !path.is_empty())
+        .map(PathBuf::from)
+}
+
+#[cfg(target_os = "linux")]
+fn do_install(installer_path: &PathBuf) -> Result<()> {
+    let is_appimage = installer_path
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| e == "AppImage");
+
+    if is_appimage {
+        install_appimage(installer_path)
+    } else {
+        install_tarball(installer_path)
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn install_appimage(appimage_path: &PathBuf) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let target = current_appimage().context(
+        "Termy is not running from an AppImage (APPIMAGE is not set). \
+         Update it with the package manager it was installed with",
+    )?;
+    let target_dir = target
+        .parent()
+        .context("AppImage path has no parent directory")?;
+
+    // The rename below is only atomic within one filesystem
+    let staged = if appimage_path.parent() == Some(target_dir) {
+        appimage_path.clone()
+    } else {
+        let staged = target_dir.join(".termy-update.AppImage");
+        std::fs::copy(appimage_path, &staged)
+            .context("Failed to copy AppImage next to the running one")?;
+        staged
+    };
+
+    let mut perms = std::fs::metadata(&staged)?.permissions();
+    perms.set_mode(0o755);
+    std::fs::set_permissions(&staged, perms)?;
+
+    let mut header = [0u8; 4];
+    let is_elf = std::fs::File::open(&staged)
+        .and_then(|mut file| std::io::Read::read_exact(&mut file, &mut header))
+        .is_ok()
+        && header == *b"\x7fELF";
+    let is_executable = std::fs::metadata(&staged)?.permissions().mode() & 0o111 != 0;
+    if !is_elf || !is_executable {
+        let _ = std::fs::remove_file(&staged);
+        anyhow::bail!("Downloaded AppImage is not an executable");
+    }
+
+    std::fs::rename(&staged, &target).context(format!(
+        "Failed to replace AppImage at {}",
+        target.display()
+    ))?;
+
+    Ok(())
+}
+
+#[cfg(target_os = "linux")]
+fn install_tarball(tarball_path: &PathBuf) -> Result<()> {
     use std::process::Command;
 
     // Determine install directory: prefer ~/.local/bin, fall back to ~/bin
@@ -549,3 +701,23 @@ fn do_install(tarball_path: &PathBuf) -> Result<()> {
 fn do_install(_installer_path: &PathBuf) -> Result<()> {
     anyhow::bail!("Auto-install is only supported on macOS, Windows, and Linux")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn download_rate_averages_over_the_last_second() {
+        let start = Instant::now();
+        let at = |ms: u64| start + Duration::from_millis(ms);
+        let mut rate = DownloadRate::default();
+
+        assert_eq!(rate.record(at(0), 0), 0);
+        assert_eq!(rate.record(at(500), 1_000), 2_000);
+        assert_eq!(rate.record(at(1_000), 2_000), 2_000);
+
+        // A burst is smoothed against the sample from a second ago.
+        assert_eq!(rate.record(at(1_500), 5_000), 4_000);
+        assert_eq!(rate.record(at(2_500), 6_000), 1_000);
+    }
+}
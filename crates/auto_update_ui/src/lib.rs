@@ -10,6 +10,7 @@ pub enum UpdateBannerTone {
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum UpdateBannerAction {
     Install,
+    Skip,
     CompleteInstall,
     Restart,
     Dismiss,
@@ -38,6 +39,22 @@ pub struct UpdateBannerModel {
     pub buttons: Vec<UpdateBannerButton>,
 }
 
+fn format_speed(bytes_per_sec: u64) -> String {
+    if bytes_per_sec >= 1024 * 1024 {
+        format!("{:.1} MB/s", bytes_per_sec as f64 / (1024.0 * 1024.0))
+    } else {
+        format!("{} KB/s", bytes_per_sec / 1024)
+    }
+}
+
+fn format_eta(secs: u64) -> String {
+    if secs >= 60 {
+        format!("{}m {:02}s", secs / 60, secs % 60)
+    } else {
+        format!("{}s", secs)
+    }
+}
+
 impl UpdateBannerModel {
     pub fn from_state(state: &UpdateState) -> Option<Self> {
         match state {
@@ -53,6 +70,11 @@ impl UpdateBannerModel {
                         action: UpdateBannerAction::Install,
                         style: UpdateButtonStyle::Primary,
                     },
+                    UpdateBannerButton {
+                        label: "Skip",
+                        action: UpdateBannerAction::Skip,
+                        style: UpdateButtonStyle::Secondary,
+                    },
                     UpdateBannerButton {
                         label: "Dismiss",
                         action: UpdateBannerAction::Dismiss,
@@ -64,6 +86,8 @@ impl UpdateBannerModel {
                 version,
                 downloaded,
                 total,
+                bytes_per_sec,
+                eta_secs,
             } => {
                 let progress_percent = if *total > 0 {
                     Some(((*downloaded as f64 / *total as f64) * 100.0).clamp(0.0, 100.0) as u8)
@@ -71,16 +95,22 @@ impl UpdateBannerModel {
                     None
                 };
 
-                let detail = if let Some(percent) = progress_percent {
-                    Some(format!("Downloading {}%", percent))
+                let mut detail = if let Some(percent) = progress_percent {
+                    format!("Downloading {}%", percent)
                 } else {
-                    Some(format!("Downloaded {} KB", *downloaded / 1024))
+                    format!("Downloaded {} KB", *downloaded / 1024)
                 };
+                if *bytes_per_sec > 0 {
+                    detail.push_str(&format!(" \u{00b7} {}", format_speed(*bytes_per_sec)));
+                }
+                if let Some(eta) = eta_secs {
+                    detail.push_str(&format!(" \u{00b7} {} left", format_eta(*eta)));
+                }
 
                 Some(Self {
                     badge: "Downloading",
                     message: format!("Fetching version {}", version),
-                    detail,
+                    detail: Some(detail),
                     progress_percent,
                     tone: UpdateBannerTone::Info,
                     buttons: vec![],
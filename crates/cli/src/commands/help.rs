@@ -1,17 +0,0 @@
-pub fn run() {
-    println!("Available commands:");
-    println!();
-    println!("  -tui              Interactive TUI for all CLI features");
-    println!("  -version          Show version information");
-    println!("  -help             Show this help message");
-    println!("  -list-fonts       List available monospace fonts");
-    println!("  -list-keybinds    List all keybindings");
-    println!("  -list-themes      List available themes");
-    println!("  -list-colors      Show current theme colors");
-    println!("  -list-actions     List available keybind actions");
-    println!("  -edit-config      Open config file in editor");
-    println!("  -show-config      Display current configuration");
-    println!("  -validate-config  Validate configuration file");
-    println!("  -prettify-config  Prettify config (removes comments, formats)");
-    println!("  -update           Check for updates");
-}
@@ -0,0 +1,57 @@
+use crate::config::{config_path, replace_or_insert_section};
+use std::io::Read;
+use termy_cli_core::color_scheme;
+
+pub fn run(source: &str) {
+    match import(source) {
+        Ok(message) => println!("{}", message),
+        Err(error) => {
+            eprintln!("{}", error);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Reads an iTerm or Xresources color scheme from `source` (`-` for stdin) and
+/// writes it to the `[colors]` section of the config file.
+pub fn import(source: &str) -> Result<String, String> {
+    let contents = if source == "-" {
+        let mut contents = String::new();
+        std::io::stdin()
+            .read_to_string(&mut contents)
+            .map_err(|e| format!("Failed to read stdin: {}", e))?;
+        contents
+    } else {
+        std::fs::read_to_string(source).map_err(|e| format!("Failed to read {}: {}", source, e))?
+    };
+
+    if contents.trim_start().starts_with('{') {
+        return Err("JSON colors are imported with Import Colors in the app".to_string());
+    }
+    let scheme = color_scheme::parse_color_scheme(&contents).ok_or_else(|| {
+        "No colors found; expected an iTerm color scheme or Xresources".to_string()
+    })?;
+    let color_lines: Vec<String> = color_scheme::config_color_entries(&scheme)
+        .into_iter()
+        .map(|(key, hex)| format!("{} = {}", key, hex))
+        .collect();
+
+    let path = config_path().ok_or_else(|| "Could not determine config directory".to_string())?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create config directory: {}", e))?;
+    }
+    let existing = if path.exists() {
+        std::fs::read_to_string(&path).map_err(|e| format!("Failed to read config file: {}", e))?
+    } else {
+        String::new()
+    };
+
+    let updated = replace_or_insert_section(&existing, "colors", &color_lines);
+    std::fs::write(&path, updated).map_err(|e| format!("Failed to write config file: {}", e))?;
+    Ok(format!(
+        "Imported {} colors into {}",
+        color_lines.len(),
+        path.display()
+    ))
+}
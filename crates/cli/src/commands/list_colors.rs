@@ -171,6 +171,52 @@ fn get_theme_colors(theme_id: &str) -> Option<ThemeColors> {
                 (253, 246, 227),
             ],
         }),
+        "solarized-light" => Some(ThemeColors {
+            foreground: (101, 123, 131),
+            background: (253, 246, 227),
+            cursor: (88, 110, 117),
+            ansi: [
+                (7, 54, 66),
+                (220, 50, 47),
+                (133, 153, 0),
+                (181, 137, 0),
+                (38, 139, 210),
+                (211, 54, 130),
+                (42, 161, 152),
+                (238, 232, 213),
+                (0, 43, 54),
+                (203, 75, 22),
+                (88, 110, 117),
+                (101, 123, 131),
+                (131, 148, 150),
+                (108, 113, 196),
+                (147, 161, 161),
+                (253, 246, 227),
+            ],
+        }),
+        "github-light" => Some(ThemeColors {
+            foreground: (31, 35, 40),
+            background: (255, 255, 255),
+            cursor: (9, 105, 218),
+            ansi: [
+                (36, 41, 47),
+                (207, 34, 46),
+                (17, 99, 41),
+                (77, 45, 0),
+                (9, 105, 218),
+                (130, 80, 223),
+                (27, 124, 131),
+                (110, 119, 129),
+                (87, 96, 106),
+                (164, 14, 38),
+                (26, 127, 55),
+                (99, 60, 1),
+                (33, 139, 255),
+                (164, 117, 249),
+                (49, 146, 170),
+                (140, 149, 159),
+            ],
+        }),
         "one-dark" => Some(ThemeColors {
             foreground: (171, 178, 191),
             background: (40, 44, 52),
@@ -309,6 +355,29 @@ fn get_theme_colors(theme_id: &str) -> Option<ThemeColors> {
                 (255, 255, 255),
             ],
         }),
+        "high-contrast" => Some(ThemeColors {
+            foreground: (255, 255, 255),
+            background: (0, 0, 0),
+            cursor: (255, 255, 0),
+            ansi: [
+                (0, 0, 0),
+                (255, 107, 107),
+                (95, 255, 95),
+                (255, 255, 0),
+                (108, 182, 255),
+                (255, 123, 255),
+                (0, 255, 255),
+                (224, 224, 224),
+                (154, 154, 154),
+                (255, 143, 143),
+                (155, 255, 155),
+                (255, 255, 128),
+                (158, 207, 255),
+                (255, 168, 255),
+                (128, 255, 255),
+                (255, 255, 255),
+            ],
+        }),
         _ => None,
     }
 }
@@ -10,7 +10,7 @@ use ratatui::{
 use std::io::{self, stdout};
 
 use crate::commands::list_keybinds::KeybindDirective;
-use crate::commands::validate_config;
+use crate::commands::{list_actions, list_themes, validate_config};
 use crate::config::{config_path, parse_keybind_lines, parse_theme_id};
 
 #[derive(Clone, Copy, PartialEq)]
@@ -294,21 +294,7 @@ fn get_list_fonts_content() -> Vec<String> {
 }
 
 fn get_list_themes_content() -> Vec<String> {
-    vec![
-        "termy".to_string(),
-        "tokyo-night".to_string(),
-        "catppuccin-mocha".to_string(),
-        "dracula".to_string(),
-        "gruvbox-dark".to_string(),
-        "nord".to_string(),
-        "solarized-dark".to_string(),
-        "one-dark".to_string(),
-        "monokai".to_string(),
-        "material-dark".to_string(),
-        "palenight".to_string(),
-        "tomorrow-night".to_string(),
-        "oceanic-next".to_string(),
-    ]
+    list_themes::output().lines().map(str::to_string).collect()
 }
 
 fn get_list_colors_content() -> Vec<String> {
@@ -480,34 +466,7 @@ fn get_list_keybinds_content() -> Vec<String> {
 }
 
 fn get_list_actions_content() -> Vec<String> {
-    vec![
-        "new_tab".to_string(),
-        "close_tab".to_string(),
-        "minimize_window".to_string(),
-        "rename_tab".to_string(),
-        "app_info".to_string(),
-        "native_sdk_example".to_string(),
-        "restart_app".to_string(),
-        "open_config".to_string(),
-        "open_settings".to_string(),
-        "import_colors".to_string(),
-        "switch_theme".to_string(),
-        "zoom_in".to_string(),
-        "zoom_out".to_string(),
-        "zoom_reset".to_string(),
-        "open_search".to_string(),
-        "check_for_updates".to_string(),
-        "quit".to_string(),
-        "toggle_command_palette".to_string(),
-        "copy".to_string(),
-        "paste".to_string(),
-        "close_search".to_string(),
-        "search_next".to_string(),
-        "search_previous".to_string(),
-        "toggle_search_case_sensitive".to_string(),
-        "toggle_search_regex".to_string(),
-        "install_cli".to_string(),
-    ]
+    list_actions::output().lines().map(str::to_string).collect()
 }
 
 fn get_validate_config_content() -> Vec<String> {
@@ -1,3 +1,7 @@
 pub fn run() {
-    println!("Termy {}", env!("CARGO_PKG_VERSION"));
+    print!("{}", output());
+}
+
+pub fn output() -> String {
+    format!("Termy {}\n", env!("CARGO_PKG_VERSION"))
 }
@@ -1,7 +1,7 @@
 use clap::{Parser, Subcommand};
+use termy_cli_core::config;
 
 mod commands;
-mod config;
 
 #[derive(Parser)]
 #[command(name = "termy")]
@@ -50,6 +50,10 @@ enum Action {
     #[command(name = "-show-config")]
     ShowConfig,
 
+    /// Import an iTerm or Xresources color scheme into the config ("-" reads stdin)
+    #[command(name = "-import-colors")]
+    ImportColors { file: String },
+
     /// Validate configuration file
     #[command(name = "-validate-config")]
     ValidateConfig,
@@ -80,6 +84,7 @@ fn main() {
         Some(Action::ListActions) => commands::list_actions::run(),
         Some(Action::EditConfig) => commands::edit_config::run(),
         Some(Action::ShowConfig) => commands::show_config::run(),
+        Some(Action::ImportColors { file }) => commands::import_colors::run(&file),
         Some(Action::ValidateConfig) => commands::validate_config::run(),
         Some(Action::PrettifyConfig) => commands::prettify_config::run(),
         Some(Action::Tui) => commands::tui::run(),
@@ -0,0 +1,317 @@
+//! Reads iTerm and Xresources color schemes into `[colors]` config entries. Kept
+//! apart from the theme crate so the CLI can import schemes without the GUI.
+
+use std::collections::HashMap;
+
+/// An sRGB color with channels in `0.0..=1.0`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SchemeColor {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+}
+
+impl SchemeColor {
+    pub fn from_rgb8(r: u8, g: u8, b: u8) -> Self {
+        Self {
+            r: r as f32 / 255.0,
+            g: g as f32 / 255.0,
+            b: b as f32 / 255.0,
+        }
+    }
+
+    /// The color as `#rrggbb`.
+    pub fn to_hex(self) -> String {
+        let channel = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+        format!(
+            "#{:02x}{:02x}{:02x}",
+            channel(self.r),
+            channel(self.g),
+            channel(self.b)
+        )
+    }
+}
+
+/// The colors a scheme file defines.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColorScheme {
+    pub foreground: SchemeColor,
+    pub background: SchemeColor,
+    pub cursor: SchemeColor,
+    pub ansi: [SchemeColor; 16],
+}
+
+/// Names of the 16 ANSI colors as they appear in the `[colors]` config section,
+/// in palette order.
+pub const ANSI_COLOR_NAMES: [&str; 16] = [
+    "black",
+    "red",
+    "green",
+    "yellow",
+    "blue",
+    "magenta",
+    "cyan",
+    "white",
+    "bright_black",
+    "bright_red",
+    "bright_green",
+    "bright_yellow",
+    "bright_blue",
+    "bright_magenta",
+    "bright_cyan",
+    "bright_white",
+];
+
+/// The `[colors]` config entries for `scheme`: foreground, background, cursor
+/// and the 16 ANSI colors by name, each as `#rrggbb`.
+pub fn config_color_entries(scheme: &ColorScheme) -> Vec<(&'static str, String)> {
+    let mut entries = vec![
+        ("foreground", scheme.foreground.to_hex()),
+        ("background", scheme.background.to_hex()),
+        ("cursor", scheme.cursor.to_hex()),
+    ];
+    entries.extend(
+        ANSI_COLOR_NAMES
+            .iter()
+            .zip(scheme.ansi)
+            .map(|(name, color)| (*name, color.to_hex())),
+    );
+    entries
+}
+
+/// Reads a color scheme in either iTerm (`.itermcolors` plist) or Xresources
+/// format, telling them apart by content.
+pub fn parse_color_scheme(contents: &str) -> Option<ColorScheme> {
+    if contents.contains("<plist") {
+        parse_iterm_colors(contents)
+    } else {
+        parse_xresources(contents)
+    }
+}
+
+/// Reads the foreground, background, cursor and `color0`..`color15` resources
+/// from Xresources, e.g. `*.color1: #f7768e` or `URxvt.background: rgb:1a/1b/26`.
+/// `#define` names are substituted. A missing cursor color falls back to the
+/// foreground; anything else missing yields `None`.
+pub fn parse_xresources(contents: &str) -> Option<ColorScheme> {
+    let mut defines = HashMap::new();
+    let mut resources = HashMap::new();
+
+    for line in contents.lines() {
+        let line = line.trim();
+        if let Some(define) = line.strip_prefix("#define") {
+            let mut parts = define.split_whitespace();
+            if let (Some(name), Some(value)) = (parts.next(), parts.next()) {
+                defines.insert(name, value);
+            }
+            continue;
+        }
+        if line.is_empty() || line.starts_with('!') || line.starts_with('#') {
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        // Only the last component matters: `URxvt*color0` and `*.color0` are the same.
+        let name = key.rsplit(['.', '*']).next().unwrap_or(key).trim();
+        let value = value.trim();
+        let value = defines.get(value).copied().unwrap_or(value);
+        if let Some(color) = parse_xresources_color(value) {
+            resources.insert(name.to_ascii_lowercase(), color);
+        }
+    }
+
+    let mut ansi = [SchemeColor::default(); 16];
+    for (index, slot) in ansi.iter_mut().enumerate() {
+        *slot = *resources.get(&format!("color{}", index))?;
+    }
+    let foreground = *resources.get("foreground")?;
+    Some(ColorScheme {
+        ansi,
+        foreground,
+        background: *resources.get("background")?,
+        cursor: resources.get("cursorcolor").copied().unwrap_or(foreground),
+    })
+}
+
+fn parse_xresources_color(value: &str) -> Option<SchemeColor> {
+    if let Some(channels) = value.strip_prefix("rgb:") {
+        let mut parts = channels.split('/');
+        let mut channel = || {
+            let digits = parts.next()?;
+            let max = 16_u32.checked_pow(digits.len() as u32)?.checked_sub(1)?;
+            let value = u32::from_str_radix(digits, 16).ok()?;
+            Some(value as f32 / max as f32)
+        };
+        let (r, g, b) = (channel()?, channel()?, channel()?);
+        return Some(SchemeColor { r, g, b });
+    }
+
+    let hex = value.trim_start_matches('#');
+    let channel = |index: usize| {
+        hex.get(index..index + 2)
+            .and_then(|digits| u8::from_str_radix(digits, 16).ok())
+    };
+    match (hex.len(), channel(0), channel(2), channel(4)) {
+        (6, Some(r), Some(g), Some(b)) => Some(SchemeColor::from_rgb8(r, g, b)),
+        _ => None,
+    }
+}
+
+/// Reads an iTerm2 `.itermcolors` property list. The 16 `Ansi N Color` entries
+/// plus `Foreground Color` and `Background Color` are required; a missing
+/// `Cursor Color` falls back to the foreground.
+pub fn parse_iterm_colors(contents: &str) -> Option<ColorScheme> {
+    let mut colors = HashMap::new();
+    let mut rest = contents;
+    while let Some(start) = rest.find("<key>") {
+        let (_, name, after) = next_element(&rest[start..])?;
+        rest = after;
+        // Each color is a flat dict of components, so its body can be taken whole.
+        if after.trim_start().starts_with("<dict>") {
+            let (_, body, after) = next_element(after)?;
+            if let Some(color) = parse_iterm_color(body) {
+                colors.insert(name, color);
+            }
+            rest = after;
+        }
+    }
+
+    let mut ansi = [SchemeColor::default(); 16];
+    for (index, slot) in ansi.iter_mut().enumerate() {
+        *slot = *colors.get(format!("Ansi {} Color", index).as_str())?;
+    }
+    let foreground = *colors.get("Foreground Color")?;
+    Some(ColorScheme {
+        ansi,
+        foreground,
+        background: *colors.get("Background Color")?,
+        cursor: colors.get("Cursor Color").copied().unwrap_or(foreground),
+    })
+}
+
+fn parse_iterm_color(body: &str) -> Option<SchemeColor> {
+    let mut channels = [None; 3];
+    let mut rest = body;
+    while let Some((_, key, after)) = next_element(rest) {
+        let (_, value, after) = next_element(after)?;
+        rest = after;
+        let slot = match key {
+            "Red Component" => 0,
+            "Green Component" => 1,
+            "Blue Component" => 2,
+            _ => continue,
+        };
+        channels[slot] = value.parse::<f32>().ok().map(|value| value.clamp(0.0, 1.0));
+    }
+
+    let [r, g, b] = channels;
+    Some(SchemeColor {
+        r: r?,
+        g: g?,
+        b: b?,
+    })
+}
+
+/// Splits the first `<tag>text</tag>` off `contents`, returning the tag, its
+/// trimmed text and what follows. Not a general XML parser: elements must not
+/// nest inside one of the same name.
+fn next_element(contents: &str) -> Option<(&str, &str, &str)> {
+    let open = contents.find('<')?;
+    let after_open = &contents[open + 1..];
+    let tag_end = after_open.find('>')?;
+    let tag = &after_open[..tag_end];
+    let body = &after_open[tag_end + 1..];
+    let close = format!("</{}>", tag);
+    let end = body.find(&close)?;
+    Some((tag, body[..end].trim(), &body[end + close.len()..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ITERM_FIXTURE: &str = include_str!("../fixtures/tokyo-night.itermcolors");
+    const XRESOURCES_FIXTURE: &str = include_str!("../fixtures/tokyo-night.Xresources");
+
+    fn rgba(r: u8, g: u8, b: u8) -> SchemeColor {
+        SchemeColor::from_rgb8(r, g, b)
+    }
+
+    fn assert_tokyo_night(theme: &ColorScheme) {
+        assert_eq!(theme.foreground, rgba(0xc0, 0xca, 0xf5));
+        assert_eq!(theme.background, rgba(0x1a, 0x1b, 0x26));
+        assert_eq!(theme.ansi[0], rgba(0x15, 0x16, 0x1e));
+        assert_eq!(theme.ansi[1], rgba(0xf7, 0x76, 0x8e));
+        assert_eq!(theme.ansi[8], rgba(0x41, 0x48, 0x68));
+        assert_eq!(theme.ansi[15], rgba(0xc0, 0xca, 0xf5));
+    }
+
+    fn assert_close(actual: SchemeColor, expected: SchemeColor) {
+        for (a, e) in [
+            (actual.r, expected.r),
+            (actual.g, expected.g),
+            (actual.b, expected.b),
+        ] {
+            assert!((a - e).abs() < 1e-3, "{:?} != {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn parses_xresources_with_defines_and_rgb_values() {
+        let theme = parse_xresources(XRESOURCES_FIXTURE).unwrap();
+        assert_tokyo_night(&theme);
+        assert_eq!(theme.cursor, rgba(0xff, 0x9e, 0x64));
+        assert_eq!(theme.ansi[4], rgba(0x7a, 0xa2, 0xf7));
+    }
+
+    #[test]
+    fn xresources_cursor_defaults_to_foreground_and_other_colors_are_required() {
+        let without_cursor: String = XRESOURCES_FIXTURE
+            .lines()
+            .filter(|line| !line.contains("cursorColor"))
+            .map(|line| format!("{}\n", line))
+            .collect();
+        let theme = parse_xresources(&without_cursor).unwrap();
+        assert_eq!(theme.cursor, theme.foreground);
+
+        let without_color3 = without_cursor.replace("*.color3:", "*.colour3:");
+        assert!(parse_xresources(&without_color3).is_none());
+    }
+
+    #[test]
+    fn parses_itermcolors_and_defaults_the_cursor() {
+        let theme = parse_iterm_colors(ITERM_FIXTURE).unwrap();
+        assert_eq!(theme.ansi.len(), 16);
+        assert_close(theme.foreground, rgba(0xc0, 0xca, 0xf5));
+        assert_close(theme.background, rgba(0x1a, 0x1b, 0x26));
+        assert_close(theme.ansi[1], rgba(0xf7, 0x76, 0x8e));
+        assert_close(theme.ansi[15], rgba(0xc0, 0xca, 0xf5));
+        assert_eq!(theme.cursor, theme.foreground);
+
+        let without_ansi_7 = ITERM_FIXTURE.replace("Ansi 7 Color", "Ansi 7 Colour");
+        assert!(parse_iterm_colors(&without_ansi_7).is_none());
+    }
+
+    #[test]
+    fn config_color_entries_round_trip_itermcolors_to_hex() {
+        let theme = parse_iterm_colors(ITERM_FIXTURE).unwrap();
+        let entries = config_color_entries(&theme);
+
+        assert_eq!(entries.len(), 19);
+        assert_eq!(entries[0], ("foreground", "#c0caf5".to_string()));
+        assert_eq!(entries[1], ("background", "#1a1b26".to_string()));
+        assert_eq!(entries[2], ("cursor", "#c0caf5".to_string()));
+        assert_eq!(entries[4], ("red", "#f7768e".to_string()));
+        assert_eq!(entries[18], ("bright_white", "#c0caf5".to_string()));
+    }
+
+    #[test]
+    fn parse_color_scheme_detects_the_format() {
+        assert_tokyo_night(&parse_color_scheme(XRESOURCES_FIXTURE).unwrap());
+        let theme = parse_color_scheme(ITERM_FIXTURE).unwrap();
+        assert_close(theme.background, rgba(0x1a, 0x1b, 0x26));
+        assert!(parse_color_scheme("not a color scheme").is_none());
+    }
+}
@@ -0,0 +1,24 @@
+pub fn run() {
+    print!("{}", output());
+}
+
+pub fn output() -> String {
+    let mut output = String::new();
+    output.push_str("Available commands:\n");
+    output.push('\n');
+    output.push_str("  -tui              Interactive TUI for all CLI features\n");
+    output.push_str("  -version          Show version information\n");
+    output.push_str("  -help             Show this help message\n");
+    output.push_str("  -list-fonts       List available monospace fonts\n");
+    output.push_str("  -list-keybinds    List all keybindings\n");
+    output.push_str("  -list-themes      List available themes\n");
+    output.push_str("  -list-colors      Show current theme colors\n");
+    output.push_str("  -list-actions     List available keybind actions\n");
+    output.push_str("  -edit-config      Open config file in editor\n");
+    output.push_str("  -show-config      Display current configuration\n");
+    output.push_str("  -import-colors    Import iTerm/Xresources colors from a file or -\n");
+    output.push_str("  -validate-config  Validate configuration file\n");
+    output.push_str("  -prettify-config  Prettify config (removes comments, formats)\n");
+    output.push_str("  -update           Check for updates\n");
+    output
+}
@@ -0,0 +1,75 @@
+const ACTIONS: &[&str] = &[
+    "new_tab",
+    "close_tab",
+    "minimize_window",
+    "rename_tab",
+    "app_info",
+    "native_sdk_example",
+    "restart_app",
+    "open_config",
+    "open_settings",
+    "import_colors",
+    "switch_theme",
+    "zoom_in",
+    "zoom_out",
+    "zoom_reset",
+    "open_search",
+    "check_for_updates",
+    "quit",
+    "toggle_command_palette",
+    "copy",
+    "paste",
+    "close_search",
+    "search_next",
+    "search_previous",
+    "copy_match_and_next",
+    "copy_search_matches",
+    "search_first_match",
+    "search_last_match",
+    "search_next_page",
+    "search_previous_page",
+    "replace_current",
+    "replace_all",
+    "toggle_search_case_sensitive",
+    "toggle_search_regex",
+    "toggle_search_whole_word",
+    "install_cli",
+    "copy_working_dir",
+    "reveal_working_dir",
+    "run_cli_command",
+    "scroll_page_up",
+    "scroll_page_down",
+    "scroll_half_page_up",
+    "scroll_half_page_down",
+    "next_diagnostic",
+    "previous_diagnostic",
+    "increase_opacity",
+    "decrease_opacity",
+    "clear_tab_title",
+    "toggle_fullscreen",
+    "toggle_search_output_only",
+    "insert_command_output",
+    "save_scrollback",
+    "copy_screen",
+    "copy_scrollback",
+    "switch_profile",
+    "clear_all_highlights",
+    "resume_output",
+    "cycle_cursor_style",
+    "toggle_shell_integration",
+    "toggle_whitespace",
+    "duplicate_tab_rerun",
+    "reload_themes",
+    "fold_long_lines",
+];
+
+pub fn run() {
+    print!("{}", output());
+}
+
+pub fn output() -> String {
+    ACTIONS
+        .iter()
+        .map(|action| format!("{}\n", action))
+        .collect()
+}
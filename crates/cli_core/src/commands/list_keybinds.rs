@@ -108,6 +108,10 @@ const DEFAULT_KEYBINDS: &[DefaultKeybind] = &[
 ];
 
 pub fn run() {
+    print!("{}", output());
+}
+
+pub fn output() -> String {
     let mut keybinds: Vec<(String, String)> = Vec::new();
 
     // Start with defaults
@@ -144,10 +148,10 @@ pub fn run() {
         }
     }
 
-    // Print all keybindings
-    for (trigger, action) in &keybinds {
-        println!("{} = {}", trigger, action);
-    }
+    keybinds
+        .iter()
+        .map(|(trigger, action)| format!("{} = {}\n", trigger, action))
+        .collect()
 }
 
 pub enum KeybindDirective {
@@ -6,16 +6,24 @@ const BUILTIN_THEMES: &[&str] = &[
     "gruvbox-dark",
     "nord",
     "solarized-dark",
+    "solarized-light",
+    "github-light",
     "one-dark",
     "monokai",
     "material-dark",
     "palenight",
     "tomorrow-night",
     "oceanic-next",
+    "high-contrast",
 ];
 
 pub fn run() {
-    for theme in BUILTIN_THEMES {
-        println!("{}", theme);
-    }
+    print!("{}", output());
+}
+
+pub fn output() -> String {
+    BUILTIN_THEMES
+        .iter()
+        .map(|theme| format!("{}\n", theme))
+        .collect()
 }
@@ -0,0 +1,6 @@
+pub mod help;
+pub mod list_actions;
+pub mod list_keybinds;
+pub mod list_themes;
+pub mod show_config;
+pub mod validate_config;
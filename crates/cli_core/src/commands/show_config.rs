@@ -0,0 +1,57 @@
+use crate::config::config_path;
+
+pub fn run() {
+    match output() {
+        Ok(output) => print!("{}", output),
+        Err(error) => eprintln!("{}", error),
+    }
+}
+
+pub fn output() -> Result<String, String> {
+    let path = config_path().ok_or_else(|| "Could not determine config directory".to_string())?;
+
+    let mut output = String::new();
+    if !path.exists() {
+        output.push_str(&format!(
+            "# Config file: {} (not created yet)\n",
+            path.display()
+        ));
+        output.push_str("# Using default configuration\n");
+        output.push('\n');
+        push_defaults(&mut output);
+        return Ok(output);
+    }
+
+    output.push_str(&format!("# Config file: {}\n", path.display()));
+    output.push('\n');
+
+    let contents =
+        std::fs::read_to_string(&path).map_err(|e| format!("Failed to read config file: {}", e))?;
+    if contents.trim().is_empty() {
+        output.push_str("# (empty file - using defaults)\n");
+        output.push('\n');
+        push_defaults(&mut output);
+    } else {
+        output.push_str(&contents);
+        if !contents.ends_with('\n') {
+            output.push('\n');
+        }
+    }
+
+    Ok(output)
+}
+
+fn push_defaults(output: &mut String) {
+    output.push_str("# Default values:\n");
+    output.push_str("theme = termy\n");
+    output.push_str("font_family = JetBrains Mono\n");
+    output.push_str("font_size = 14\n");
+    output.push_str("term = xterm-256color\n");
+    output.push_str("cursor_style = line\n");
+    output.push_str("cursor_blink = true\n");
+    output.push_str("background_opacity = 1.0\n");
+    output.push_str("padding_x = 12\n");
+    output.push_str("padding_y = 8\n");
+    output.push_str("scrollback_history = 10000\n");
+    output.push_str("use_tabs = true\n");
+}
@@ -0,0 +1,499 @@
+use crate::config::config_path;
+
+const VALID_KEYS: &[&str] = &[
+    "theme",
+    "theme_cache_size",
+    "font_family",
+    "font_size",
+    "term",
+    "colorterm",
+    "shell",
+    "startup_command",
+    "flow_control",
+    "working_dir",
+    "cursor_style",
+    "cursor_blink",
+    "cursor_contrast",
+    "background_opacity",
+    "background_blur",
+    "reduce_transparency",
+    "transparency_friendly_colors",
+    "padding_x",
+    "max_content_columns",
+    "padding_y",
+    "mouse_scroll_multiplier",
+    "wheel_scroll_multiplier",
+    "trackpad_scroll_multiplier",
+    "invert_wheel_scroll",
+    "invert_trackpad_scroll",
+    "scroll_to_bottom_on_input",
+    "key_repeat_delay",
+    "key_repeat_rate",
+    "toast_duration_info",
+    "toast_duration_success",
+    "toast_duration_warning",
+    "toast_duration_error",
+    "show_wrap_indicator",
+    "render_whitespace",
+    "long_lines",
+    "long_line_limit",
+    "require_url_scheme",
+    "trim_trailing_whitespace_on_copy",
+    "double_click_selection",
+    "sanitize_paste",
+    "macos_option_as_meta",
+    "bold_is_bright",
+    "builtin_box_drawing",
+    "minimum_contrast",
+    "diagnostic_pattern",
+    "window_width",
+    "window_height",
+    "startup_window_mode",
+    "terminal_scrollbar_visibility",
+    "terminal_scrollbar_style",
+    "scrollbar_prompt_markers",
+    "scrollbar_match_density",
+    "search_match_color",
+    "search_current_color",
+    "search_bar_style",
+    "search_trim_query",
+    "search_center_on_match",
+    "close_search_on_click",
+    "scrollback_history",
+    "inactive_tab_scrollback",
+    "update_channel",
+    "skipped_update_version",
+    "use_tabs",
+    "tab_bar_visibility",
+    "inherit_tab_overrides",
+    "max_tabs",
+    "warn_on_quit_with_running_process",
+    "confirm_quit_multiple_tabs",
+    "on_shell_exit",
+    "command_palette_show_keybinds",
+    "keybind",
+    "tab_title_mode",
+    "tab_title_fallback",
+    "tab_title_explicit_prefix",
+    "tab_title_shell_integration",
+    "tab_title_prompt_format",
+    "tab_title_command_format",
+];
+
+const VALID_SECTIONS: &[&str] = &["colors", "tab_title"];
+
+const VALID_ACTIONS: &[&str] = &[
+    "new_tab",
+    "close_tab",
+    "minimize_window",
+    "rename_tab",
+    "app_info",
+    "native_sdk_example",
+    "restart_app",
+    "open_config",
+    "open_settings",
+    "import_colors",
+    "switch_theme",
+    "zoom_in",
+    "zoom_out",
+    "zoom_reset",
+    "open_search",
+    "check_for_updates",
+    "quit",
+    "toggle_command_palette",
+    "copy",
+    "paste",
+    "close_search",
+    "search_next",
+    "search_previous",
+    "copy_match_and_next",
+    "copy_search_matches",
+    "search_first_match",
+    "search_last_match",
+    "search_next_page",
+    "search_previous_page",
+    "replace_current",
+    "replace_all",
+    "toggle_search_case_sensitive",
+    "toggle_search_regex",
+    "toggle_search_whole_word",
+    "install_cli",
+    "copy_working_dir",
+    "reveal_working_dir",
+    "run_cli_command",
+    "scroll_page_up",
+    "scroll_page_down",
+    "scroll_half_page_up",
+    "scroll_half_page_down",
+    "next_diagnostic",
+    "previous_diagnostic",
+    "increase_opacity",
+    "decrease_opacity",
+    "clear_tab_title",
+    "toggle_fullscreen",
+    "toggle_search_output_only",
+    "insert_command_output",
+    "save_scrollback",
+    "copy_screen",
+    "copy_scrollback",
+    "switch_profile",
+    "clear_all_highlights",
+    "resume_output",
+    "cycle_cursor_style",
+    "toggle_shell_integration",
+    "toggle_whitespace",
+    "duplicate_tab_rerun",
+    "reload_themes",
+    "fold_long_lines",
+    "unbind",
+    "clear",
+];
+
+const VALID_THEMES: &[&str] = &[
+    "termy",
+    "tokyo-night",
+    "catppuccin-mocha",
+    "dracula",
+    "gruvbox-dark",
+    "nord",
+    "solarized-dark",
+    "solarized-light",
+    "github-light",
+    "one-dark",
+    "monokai",
+    "material-dark",
+    "palenight",
+    "tomorrow-night",
+    "oceanic-next",
+    "high-contrast",
+];
+
+pub fn run() {
+    let (output, valid) = output();
+    print!("{}", output);
+    if !valid {
+        std::process::exit(1);
+    }
+}
+
+/// Renders the validation report for the user's config file and whether it is valid.
+pub fn output() -> (String, bool) {
+    let mut output = String::new();
+    let Some(path) = config_path() else {
+        output.push_str("Could not determine config directory\n");
+        return (output, false);
+    };
+
+    output.push_str(&format!("Config file: {}\n", path.display()));
+
+    if !path.exists() {
+        output.push_str("Status: File does not exist (using defaults)\n");
+        output.push_str("Result: Valid\n");
+        return (output, true);
+    }
+
+    let contents = match std::fs::read_to_string(&path) {
+        Ok(c) => c,
+        Err(e) => {
+            output.push_str("Status: Failed to read file\n");
+            output.push_str(&format!("Error: {}\n", e));
+            return (output, false);
+        }
+    };
+
+    let ValidationReport { errors, warnings } = validate_contents(&contents);
+
+    if errors.is_empty() && warnings.is_empty() {
+        output.push_str("Status: Valid\n");
+        return (output, true);
+    }
+
+    if !errors.is_empty() {
+        output.push('\n');
+        output.push_str("Errors:\n");
+        for error in &errors {
+            output.push_str(&format!("  {}\n", error));
+        }
+    }
+
+    if !warnings.is_empty() {
+        output.push('\n');
+        output.push_str("Warnings:\n");
+        for warning in &warnings {
+            output.push_str(&format!("  {}\n", warning));
+        }
+    }
+
+    output.push('\n');
+    let valid = errors.is_empty();
+    if valid {
+        output.push_str("Result: Valid (with warnings)\n");
+    } else {
+        output.push_str("Result: Invalid\n");
+    }
+
+    (output, valid)
+}
+
+pub struct ValidationReport {
+    pub errors: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+pub fn validate_contents(contents: &str) -> ValidationReport {
+    let mut errors: Vec<String> = Vec::new();
+    let mut warnings: Vec<String> = Vec::new();
+    let mut in_section: Option<&str> = None;
+
+    for (line_num, line) in contents.lines().enumerate() {
+        let line_num = line_num + 1;
+        let trimmed = line.trim();
+
+        // Skip empty lines and comments
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        // Check for section headers
+        if trimmed.starts_with('[') && trimmed.ends_with(']') {
+            let section_name = &trimmed[1..trimmed.len() - 1];
+            if VALID_SECTIONS.contains(&section_name)
+                || ["profile.", "command."].iter().any(|prefix| {
+                    section_name
+                        .strip_prefix(prefix)
+                        .is_some_and(|name| !name.trim().is_empty())
+                })
+            {
+                in_section = Some(section_name);
+            } else {
+                warnings.push(format!(
+                    "Line {}: Unknown section [{}]",
+                    line_num, section_name
+                ));
+                in_section = None;
+            }
+            continue;
+        }
+
+        // Parse key = value
+        if let Some((key, value)) = trimmed.split_once('=') {
+            let key = key.trim();
+            let value = value.trim();
+
+            // Inside a section, allow any keys
+            if in_section.is_some() {
+                continue;
+            }
+
+            // Check if key is valid
+            if !VALID_KEYS.contains(&key) {
+                warnings.push(format!("Line {}: Unknown key '{}'", line_num, key));
+                continue;
+            }
+
+            // Validate specific keys
+            match key {
+                "theme" => {
+                    if !VALID_THEMES.contains(&value) {
+                        warnings.push(format!(
+                            "Line {}: Unknown theme '{}'. Valid themes: {}",
+                            line_num,
+                            value,
+                            VALID_THEMES.join(", ")
+                        ));
+                    }
+                }
+                "keybind" => {
+                    if value == "clear" {
+                        continue;
+                    }
+                    if let Some((_, action)) = value.split_once('=') {
+                        let action = action.trim();
+                        if !VALID_ACTIONS.contains(&action) {
+                            warnings.push(format!(
+                                "Line {}: Unknown keybind action '{}'",
+                                line_num, action
+                            ));
+                        }
+                    } else {
+                        errors.push(format!(
+                            "Line {}: Invalid keybind format. Expected 'keybind = <trigger>=<action>'",
+                            line_num
+                        ));
+                    }
+                }
+                "font_size" => {
+                    if value.parse::<f32>().is_err() {
+                        errors.push(format!("Line {}: font_size must be a number", line_num));
+                    }
+                }
+                "background_opacity" => {
+                    if let Ok(v) = value.parse::<f32>() {
+                        if !(0.0..=1.0).contains(&v) {
+                            errors.push(format!(
+                                "Line {}: background_opacity must be between 0.0 and 1.0",
+                                line_num
+                            ));
+                        }
+                    } else {
+                        errors.push(format!(
+                            "Line {}: background_opacity must be a number",
+                            line_num
+                        ));
+                    }
+                }
+                "cursor_style" => {
+                    if !["line", "block", "underline", "hollow_block"]
+                        .contains(&value.to_lowercase().as_str())
+                    {
+                        errors.push(format!(
+                            "Line {}: cursor_style must be 'line', 'block', 'underline' or 'hollow_block'",
+                            line_num
+                        ));
+                    }
+                }
+                "reduce_transparency" => {
+                    if !["true", "false", "auto"].contains(&value.to_lowercase().as_str()) {
+                        errors.push(format!(
+                            "Line {}: reduce_transparency must be 'true', 'false' or 'auto'",
+                            line_num
+                        ));
+                    }
+                }
+                "double_click_selection" => {
+                    if !["token", "word"].contains(&value.to_lowercase().as_str()) {
+                        errors.push(format!(
+                            "Line {}: double_click_selection must be 'token' or 'word'",
+                            line_num
+                        ));
+                    }
+                }
+                "update_channel" => {
+                    if !["stable", "prerelease"].contains(&value.to_lowercase().as_str()) {
+                        errors.push(format!(
+                            "Line {}: update_channel must be 'stable' or 'prerelease'",
+                            line_num
+                        ));
+                    }
+                }
+                "render_whitespace" => {
+                    if value.eq_ignore_ascii_case("trailing") {
+                        errors.push(format!(
+                            "Line {}: render_whitespace has no 'trailing' mode; trailing spaces can't be told apart from empty cells, use 'all'",
+                            line_num
+                        ));
+                    } else if !["none", "all"].contains(&value.to_lowercase().as_str()) {
+                        errors.push(format!(
+                            "Line {}: render_whitespace must be 'none' or 'all'",
+                            line_num
+                        ));
+                    }
+                }
+                "startup_window_mode" => {
+                    if !["windowed", "maximized", "maximised", "fullscreen"]
+                        .contains(&value.to_lowercase().as_str())
+                    {
+                        errors.push(format!(
+                            "Line {}: startup_window_mode must be 'windowed', 'maximized' or 'fullscreen'",
+                            line_num
+                        ));
+                    }
+                }
+                "long_lines" => {
+                    if !["wrap", "truncate", "fold"].contains(&value.to_lowercase().as_str()) {
+                        errors.push(format!(
+                            "Line {}: long_lines must be 'wrap', 'truncate' or 'fold'",
+                            line_num
+                        ));
+                    }
+                }
+                "search_bar_style" => {
+                    if !["neutral", "accent"].contains(&value.to_lowercase().as_str()) {
+                        errors.push(format!(
+                            "Line {}: search_bar_style must be 'neutral' or 'accent'",
+                            line_num
+                        ));
+                    }
+                }
+                "tab_bar_visibility" => {
+                    if !["always", "auto", "hover_reveal", "hoverreveal", "hover"]
+                        .contains(&value.to_lowercase().as_str())
+                    {
+                        errors.push(format!(
+                            "Line {}: tab_bar_visibility must be 'always', 'auto' or 'hover_reveal'",
+                            line_num
+                        ));
+                    }
+                }
+                "on_shell_exit" => {
+                    if !["close", "hold", "restart"].contains(&value.to_lowercase().as_str()) {
+                        errors.push(format!(
+                            "Line {}: on_shell_exit must be 'close', 'hold' or 'restart'",
+                            line_num
+                        ));
+                    }
+                }
+                "cursor_blink"
+                | "cursor_contrast"
+                | "background_blur"
+                | "transparency_friendly_colors"
+                | "use_tabs"
+                | "inherit_tab_overrides"
+                | "warn_on_quit_with_running_process"
+                | "confirm_quit_multiple_tabs"
+                | "show_wrap_indicator"
+                | "require_url_scheme"
+                | "trim_trailing_whitespace_on_copy"
+                | "sanitize_paste"
+                | "flow_control"
+                | "macos_option_as_meta"
+                | "search_trim_query"
+                | "search_center_on_match"
+                | "close_search_on_click"
+                | "bold_is_bright"
+                | "builtin_box_drawing"
+                | "command_palette_show_keybinds"
+                | "tab_title_shell_integration"
+                | "invert_wheel_scroll"
+                | "invert_trackpad_scroll"
+                | "scroll_to_bottom_on_input"
+                | "scrollbar_prompt_markers"
+                | "scrollbar_match_density" => {
+                    if !["true", "false"].contains(&value.to_lowercase().as_str()) {
+                        errors.push(format!(
+                            "Line {}: {} must be 'true' or 'false'",
+                            line_num, key
+                        ));
+                    }
+                }
+                "scrollback_history"
+                | "theme_cache_size"
+                | "inactive_tab_scrollback"
+                | "max_tabs"
+                | "key_repeat_delay"
+                | "key_repeat_rate"
+                | "long_line_limit"
+                | "max_content_columns"
+                | "toast_duration_info"
+                | "toast_duration_success"
+                | "toast_duration_warning"
+                | "toast_duration_error" => {
+                    if value.parse::<usize>().is_err() {
+                        errors.push(format!(
+                            "Line {}: {} must be a positive integer",
+                            line_num, key
+                        ));
+                    }
+                }
+                _ => {}
+            }
+        } else {
+            errors.push(format!(
+                "Line {}: Invalid syntax. Expected 'key = value'",
+                line_num
+            ));
+        }
+    }
+
+    ValidationReport { errors, warnings }
+}
@@ -84,3 +84,56 @@ pub fn parse_theme_id(contents: &str) -> Option<String> {
 
     None
 }
+
+/// Replaces the settings in the `[section_name]` section with `section_lines`,
+/// appending the section when the config has none. Comments and blank lines in
+/// the section are kept after the new settings.
+pub fn replace_or_insert_section(
+    contents: &str,
+    section_name: &str,
+    section_lines: &[String],
+) -> String {
+    let mut new_config = String::new();
+    let mut in_target_section = false;
+    let mut target_section_found = false;
+    let target_header = format!("[{}]", section_name);
+
+    for line in contents.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('[') && trimmed.ends_with(']') {
+            in_target_section = false;
+            if trimmed.eq_ignore_ascii_case(&target_header) {
+                target_section_found = true;
+                in_target_section = true;
+                new_config.push_str(line);
+                new_config.push('\n');
+                for section_line in section_lines {
+                    new_config.push_str(section_line);
+                    new_config.push('\n');
+                }
+                continue;
+            }
+        }
+
+        if in_target_section && !trimmed.is_empty() && !trimmed.starts_with('#') {
+            continue;
+        }
+
+        new_config.push_str(line);
+        new_config.push('\n');
+    }
+
+    if !target_section_found {
+        if !new_config.is_empty() {
+            new_config.push('\n');
+        }
+        new_config.push_str(&target_header);
+        new_config.push('\n');
+        for section_line in section_lines {
+            new_config.push_str(section_line);
+            new_config.push('\n');
+        }
+    }
+
+    new_config
+}
@@ -0,0 +1,6 @@
+//! Config helpers and command output shared by the `termy-cli` binary and the
+//! GUI, kept free of the CLI's terminal UI dependencies.
+
+pub mod color_scheme;
+pub mod commands;
+pub mod config;
@@ -1,16 +1,31 @@
 #[cfg(target_os = "macos")]
-use dispatch2::run_on_main;
+use block2::RcBlock;
 #[cfg(target_os = "macos")]
-use objc2_app_kit::{NSAlert, NSAlertFirstButtonReturn, NSAlertSecondButtonReturn};
+use dispatch2::{DispatchQueue, run_on_main};
 #[cfg(target_os = "macos")]
-use objc2_foundation::NSString;
+use objc2::MainThreadMarker;
+#[cfg(target_os = "macos")]
+use objc2_app_kit::{
+    NSAlert, NSAlertFirstButtonReturn, NSAlertSecondButtonReturn, NSAlertThirdButtonReturn,
+    NSApplication, NSModalResponse, NSModalResponseOK, NSOpenPanel,
+};
+#[cfg(target_os = "macos")]
+use objc2_foundation::{NSArray, NSString, NSUserNotification, NSUserNotificationCenter};
+#[cfg(target_os = "macos")]
+use std::cell::Cell;
 
+use std::path::PathBuf;
 #[cfg(target_os = "linux")]
 use std::process::Command;
 
+#[cfg(target_os = "windows")]
+use windows::Win32::UI::Controls::Dialogs::{
+    GetOpenFileNameW, OFN_FILEMUSTEXIST, OFN_NOCHANGEDIR, OFN_PATHMUSTEXIST, OPENFILENAMEW,
+};
 #[cfg(target_os = "windows")]
 use windows::Win32::UI::WindowsAndMessaging::{
-    IDYES, MB_ICONINFORMATION, MB_OK, MB_YESNO, MessageBoxW,
+    IDCANCEL, IDNO, IDYES, MB_ICONINFORMATION, MB_ICONWARNING, MB_OK, MB_YESNO, MB_YESNOCANCEL,
+    MessageBoxW,
 };
 
 #[cfg(target_os = "windows")]
@@ -28,6 +43,9 @@ fn has_command(cmd: &str) -> bool {
         .is_ok_and(|s| s.success())
 }
 
+/// Shows a message with an OK button and blocks until it is dismissed. On macOS
+/// the alert runs modally on the main thread, so calling this from the UI
+/// thread stalls rendering; the app should use [`show_alert_async`].
 pub fn show_alert(title: &str, message: &str) {
     #[cfg(target_os = "macos")]
     {
@@ -79,6 +97,8 @@ pub fn show_alert(title: &str, message: &str) {
     }
 }
 
+/// Asks an OK/Cancel question and blocks until it is answered. Same threading
+/// caveat as [`show_alert`]; the app should use [`confirm_async`].
 pub fn confirm(title: &str, message: &str) -> bool {
     #[cfg(target_os = "macos")]
     {
@@ -144,3 +164,339 @@ pub fn confirm(title: &str, message: &str) -> bool {
         false
     }
 }
+
+/// Non-blocking [`show_alert`]: returns at once and calls `on_dismiss` when the
+/// alert closes. On macOS the alert is a sheet on the key window, so the app
+/// keeps running underneath it, and `on_dismiss` runs on the main thread.
+/// Elsewhere `on_dismiss` runs on a background thread. Either way it should
+/// only hand the result to the app's executor (e.g. through a channel that a
+/// spawned task awaits) rather than touch app state directly.
+pub fn show_alert_async(title: &str, message: &str, on_dismiss: impl FnOnce() + Send + 'static) {
+    #[cfg(target_os = "macos")]
+    {
+        begin_alert_sheet(title, message, &["OK"], move |_| on_dismiss());
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        let (title, message) = (title.to_owned(), message.to_owned());
+        std::thread::spawn(move || {
+            show_alert(&title, &message);
+            on_dismiss();
+        });
+    }
+}
+
+/// Non-blocking [`confirm`]: returns at once and calls `on_complete` with the
+/// answer, under the same threading contract as [`show_alert_async`].
+pub fn confirm_async(title: &str, message: &str, on_complete: impl FnOnce(bool) + Send + 'static) {
+    #[cfg(target_os = "macos")]
+    {
+        begin_alert_sheet(title, message, &["Cancel", "OK"], move |response| {
+            on_complete(response == NSAlertSecondButtonReturn)
+        });
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        let (title, message) = (title.to_owned(), message.to_owned());
+        std::thread::spawn(move || on_complete(confirm(&title, &message)));
+    }
+}
+
+/// Shows an alert as a sheet on the key window without waiting for it, and
+/// calls `on_response` on the main thread once a button is picked. With no
+/// window to attach to, the alert falls back to running modally.
+#[cfg(target_os = "macos")]
+fn begin_alert_sheet(
+    title: &str,
+    message: &str,
+    buttons: &'static [&'static str],
+    on_response: impl FnOnce(NSModalResponse) + Send + 'static,
+) {
+    let (title, message) = (title.to_owned(), message.to_owned());
+    DispatchQueue::main().exec_async(move || {
+        // SAFETY: work queued on the main dispatch queue runs on the main thread.
+        let mtm = unsafe { MainThreadMarker::new_unchecked() };
+        let alert = NSAlert::new(mtm);
+        alert.setMessageText(&NSString::from_str(&title));
+        alert.setInformativeText(&NSString::from_str(&message));
+        for button in buttons {
+            let _ = alert.addButtonWithTitle(&NSString::from_str(button));
+        }
+
+        let app = NSApplication::sharedApplication(mtm);
+        let Some(window) = app.keyWindow().or_else(|| app.mainWindow()) else {
+            on_response(alert.runModal());
+            return;
+        };
+        // The block type is `Fn`, but AppKit calls the handler once.
+        let on_response = Cell::new(Some(on_response));
+        let handler = RcBlock::new(move |response: NSModalResponse| {
+            if let Some(on_response) = on_response.take() {
+                on_response(response);
+            }
+        });
+        // SAFETY: the alert retains the handler until the sheet ends, and
+        // `window` is a live window owned by the app.
+        unsafe { alert.beginSheetModalForWindow_completionHandler(&window, Some(&handler)) };
+    });
+}
+
+/// Asks a question with three answers, e.g. `["Save", "Don't Save", "Cancel"]`,
+/// and returns the index of the chosen button. The last button should be the
+/// one that backs out: closing the dialog also picks it. Windows can't relabel
+/// its buttons, so there they read Yes/No/Cancel. Returns `None` when no
+/// dialog is available.
+pub fn confirm_three(title: &str, message: &str, buttons: [&str; 3]) -> Option<usize> {
+    #[cfg(target_os = "macos")]
+    {
+        run_on_main(|mtm| {
+            let alert = NSAlert::new(mtm);
+            alert.setMessageText(&NSString::from_str(title));
+            alert.setInformativeText(&NSString::from_str(message));
+            for button in buttons {
+                let _ = alert.addButtonWithTitle(&NSString::from_str(button));
+            }
+
+            let response = alert.runModal();
+            if response == NSAlertFirstButtonReturn {
+                Some(0)
+            } else if response == NSAlertSecondButtonReturn {
+                Some(1)
+            } else if response == NSAlertThirdButtonReturn {
+                Some(2)
+            } else {
+                None
+            }
+        })
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        if has_command("zenity") {
+            let output = Command::new("zenity")
+                .args(["--question", "--title", title, "--text", message])
+                .args(["--ok-label", buttons[0], "--extra-button", buttons[1]])
+                .args(["--cancel-label", buttons[2]])
+                .output()
+                .ok()?;
+            // The extra button exits like cancel but prints its label.
+            if output.status.success() {
+                Some(0)
+            } else if String::from_utf8_lossy(&output.stdout).trim() == buttons[1] {
+                Some(1)
+            } else {
+                Some(2)
+            }
+        } else if has_command("kdialog") {
+            let status = Command::new("kdialog")
+                .args(["--yesnocancel", message, "--title", title])
+                .args(["--yes-label", buttons[0], "--no-label", buttons[1]])
+                .args(["--cancel-label", buttons[2]])
+                .status()
+                .ok()?;
+            match status.code() {
+                Some(0) => Some(0),
+                Some(1) => Some(1),
+                _ => Some(2),
+            }
+        } else {
+            eprintln!("[native_sdk] confirm_three: {title}: {message}");
+            None
+        }
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        let _ = buttons;
+        let wide_title = wide_string(title);
+        let wide_message = wide_string(message);
+        let result = unsafe {
+            MessageBoxW(
+                None,
+                windows::core::PCWSTR(wide_message.as_ptr()),
+                windows::core::PCWSTR(wide_title.as_ptr()),
+                MB_YESNOCANCEL | MB_ICONWARNING,
+            )
+        };
+        if result == IDYES {
+            Some(0)
+        } else if result == IDNO {
+            Some(1)
+        } else if result == IDCANCEL {
+            Some(2)
+        } else {
+            None
+        }
+    }
+
+    #[cfg(not(any(target_os = "macos", target_os = "linux", target_os = "windows")))]
+    {
+        let _ = buttons;
+        eprintln!("[native_sdk] confirm_three: {title}: {message}");
+        None
+    }
+}
+
+/// Asks the user for an existing file. `allowed_extensions` (without the dot)
+/// limits what can be picked; an empty slice allows any file. Returns `None`
+/// when the dialog is cancelled or no dialog is available.
+pub fn pick_file(title: &str, allowed_extensions: &[&str]) -> Option<PathBuf> {
+    #[cfg(target_os = "macos")]
+    {
+        run_on_main(|mtm| {
+            let panel = NSOpenPanel::openPanel(mtm);
+            panel.setCanChooseFiles(true);
+            panel.setCanChooseDirectories(false);
+            panel.setAllowsMultipleSelection(false);
+            panel.setMessage(Some(&NSString::from_str(title)));
+            if !allowed_extensions.is_empty() {
+                let types: Vec<_> = allowed_extensions
+                    .iter()
+                    .map(|extension| NSString::from_str(extension))
+                    .collect();
+                // allowedContentTypes needs UTTypes; plain extensions are enough here.
+                #[allow(deprecated)]
+                panel.setAllowedFileTypes(Some(&NSArray::from_retained_slice(&types)));
+            }
+
+            if panel.runModal() != NSModalResponseOK {
+                return None;
+            }
+            let path = panel.URL()?.path()?;
+            Some(PathBuf::from(path.to_string()))
+        })
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        let patterns = allowed_extensions
+            .iter()
+            .map(|extension| format!("*.{extension}"))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let output = if has_command("zenity") {
+            let mut command = Command::new("zenity");
+            command.args(["--file-selection", "--title", title]);
+            if !patterns.is_empty() {
+                command.arg(format!("--file-filter={patterns}"));
+            }
+            command.output()
+        } else if has_command("kdialog") {
+            let filter = if patterns.is_empty() { "*" } else { &patterns };
+            Command::new("kdialog")
+                .args(["--getopenfilename", ".", filter, "--title", title])
+                .output()
+        } else {
+            eprintln!("[native_sdk] pick_file: {title}");
+            return None;
+        };
+
+        let output = output.ok().filter(|output| output.status.success())?;
+        let path = String::from_utf8(output.stdout).ok()?;
+        let path = path.trim_end_matches(['\r', '\n']);
+        (!path.is_empty()).then(|| PathBuf::from(path))
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        // Filter pairs are NUL-separated and the list ends with an extra NUL.
+        let patterns = if allowed_extensions.is_empty() {
+            "*.*".to_string()
+        } else {
+            allowed_extensions
+                .iter()
+                .map(|extension| format!("*.{extension}"))
+                .collect::<Vec<_>>()
+                .join(";")
+        };
+        let filter: Vec<u16> = format!("{patterns}\0{patterns}\0")
+            .encode_utf16()
+            .chain(std::iter::once(0))
+            .collect();
+        let wide_title = wide_string(title);
+        let mut file = [0u16; 1024];
+
+        let mut dialog = OPENFILENAMEW {
+            lStructSize: std::mem::size_of::<OPENFILENAMEW>() as u32,
+            lpstrFilter: windows::core::PCWSTR(filter.as_ptr()),
+            lpstrFile: windows::core::PWSTR(file.as_mut_ptr()),
+            nMaxFile: file.len() as u32,
+            lpstrTitle: windows::core::PCWSTR(wide_title.as_ptr()),
+            Flags: OFN_FILEMUSTEXIST | OFN_PATHMUSTEXIST | OFN_NOCHANGEDIR,
+            ..Default::default()
+        };
+        if !unsafe { GetOpenFileNameW(&mut dialog) }.as_bool() {
+            return None;
+        }
+        let len = file.iter().position(|&c| c == 0).unwrap_or(file.len());
+        Some(PathBuf::from(String::from_utf16_lossy(&file[..len])))
+    }
+
+    #[cfg(not(any(target_os = "macos", target_os = "linux", target_os = "windows")))]
+    {
+        let _ = allowed_extensions;
+        eprintln!("[native_sdk] pick_file: {title}");
+        None
+    }
+}
+
+/// Posts an OS notification, separate from in-app toasts. Best effort: it
+/// returns without waiting and does nothing when the platform has no way to
+/// show one.
+pub fn notify(title: &str, body: &str) {
+    #[cfg(target_os = "macos")]
+    {
+        // UNUserNotificationCenter needs an authorization round trip; the
+        // deprecated center still delivers for a bundled app.
+        #[allow(deprecated)]
+        {
+            let notification = NSUserNotification::new();
+            notification.setTitle(Some(&NSString::from_str(title)));
+            notification.setInformativeText(Some(&NSString::from_str(body)));
+            NSUserNotificationCenter::defaultUserNotificationCenter()
+                .deliverNotification(&notification);
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        match Command::new("notify-send")
+            .args(["--app-name", "Termy", title, body])
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .spawn()
+        {
+            // Reap the child off the caller's thread so it doesn't linger as a
+            // zombie until the app exits.
+            Ok(mut child) => {
+                std::thread::spawn(move || {
+                    let _ = child.wait();
+                });
+            }
+            Err(_) => eprintln!("[native_sdk] notify: {title}: {body}"),
+        }
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        // Toasts need a registered AppUserModelID, so fall back to a message
+        // box on its own thread to keep the caller from blocking.
+        let wide_title = wide_string(title);
+        let wide_body = wide_string(body);
+        std::thread::spawn(move || unsafe {
+            MessageBoxW(
+                None,
+                windows::core::PCWSTR(wide_body.as_ptr()),
+                windows::core::PCWSTR(wide_title.as_ptr()),
+                MB_OK | MB_ICONINFORMATION,
+            );
+        });
+    }
+
+    #[cfg(not(any(target_os = "macos", target_os = "linux", target_os = "windows")))]
+    {
+        eprintln!("[native_sdk] notify: {title}: {body}");
+    }
+}
@@ -1,6 +1,8 @@
-use regex::{Regex, RegexBuilder};
+use regex::{Captures, NoExpand, Regex, RegexBuilder};
 
-use crate::matcher::{SearchMatch, SearchResults};
+use std::ops::RangeInclusive;
+
+use crate::matcher::{PartialResults, SearchMatch, SearchResults};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum SearchMode {
@@ -9,10 +11,27 @@ pub enum SearchMode {
     Regex,
 }
 
+/// Lines a search looks at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SearchScope {
+    #[default]
+    EntireBuffer,
+    /// Only lines `start_line..=end_line`.
+    Range { start_line: i32, end_line: i32 },
+}
+
 #[derive(Debug, Clone)]
 pub struct SearchConfig {
     pub case_sensitive: bool,
     pub mode: SearchMode,
+    /// Ignore leading and trailing whitespace in the pattern.
+    pub trim_query: bool,
+    /// Only match where the text on both sides of the match is not a word character.
+    pub whole_word: bool,
+    /// Match case-insensitively unless the pattern has an uppercase letter, like
+    /// ripgrep's and Vim's smart case. Overrides `case_sensitive` when set.
+    pub smart_case: bool,
+    pub scope: SearchScope,
 }
 
 impl Default for SearchConfig {
@@ -20,10 +39,40 @@ impl Default for SearchConfig {
         Self {
             case_sensitive: false,
             mode: SearchMode::Literal,
+            trim_query: false,
+            whole_word: false,
+            smart_case: false,
+            scope: SearchScope::EntireBuffer,
         }
     }
 }
 
+/// Letters, digits and underscore in any script, like the regex `\w` class.
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Smart case looks for an uppercase letter the user typed. In a regex the
+/// letter after a `\` is part of an escape like `\S` or `\W`, so it doesn't
+/// count.
+fn pattern_has_uppercase(pattern: &str, mode: SearchMode) -> bool {
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' && mode == SearchMode::Regex {
+            chars.next();
+        } else if c.is_uppercase() {
+            return true;
+        }
+    }
+    false
+}
+
+/// True when neither the character before `start` nor the one at `end` is a word character.
+fn is_whole_word(text: &str, start: usize, end: usize) -> bool {
+    !text[..start].chars().next_back().is_some_and(is_word_char)
+        && !text[end..].chars().next().is_some_and(is_word_char)
+}
+
 pub struct SearchEngine {
     config: SearchConfig,
     compiled_regex: Option<Regex>,
@@ -46,6 +95,11 @@ impl SearchEngine {
 
         self.pattern = pattern.to_string();
 
+        let pattern = if self.config.trim_query {
+            pattern.trim()
+        } else {
+            pattern
+        };
         if pattern.is_empty() {
             self.compiled_regex = None;
             return Ok(());
@@ -53,11 +107,12 @@ impl SearchEngine {
 
         let regex_pattern = match self.config.mode {
             SearchMode::Literal => regex::escape(pattern),
+            SearchMode::Regex if self.config.whole_word => format!(r"\b(?:{})\b", pattern),
             SearchMode::Regex => pattern.to_string(),
         };
 
         match RegexBuilder::new(&regex_pattern)
-            .case_insensitive(!self.config.case_sensitive)
+            .case_insensitive(!self.case_sensitive_for(pattern))
             .build()
         {
             Ok(regex) => {
@@ -71,14 +126,39 @@ impl SearchEngine {
         }
     }
 
+    fn case_sensitive_for(&self, pattern: &str) -> bool {
+        if self.config.smart_case {
+            pattern_has_uppercase(pattern, self.config.mode)
+        } else {
+            self.config.case_sensitive
+        }
+    }
+
+    /// Whether the current pattern matches case-sensitively, after smart case.
+    pub fn is_case_sensitive(&self) -> bool {
+        self.case_sensitive_for(&self.pattern)
+    }
+
     pub fn set_config(&mut self, config: SearchConfig) {
-        if self.config.case_sensitive != config.case_sensitive || self.config.mode != config.mode {
+        if self.config.case_sensitive != config.case_sensitive
+            || self.config.smart_case != config.smart_case
+            || self.config.mode != config.mode
+            || self.config.trim_query != config.trim_query
+            || self.config.whole_word != config.whole_word
+        {
             self.config = config;
-            let pattern = self.pattern.clone();
+            let pattern = std::mem::take(&mut self.pattern);
             let _ = self.set_pattern(&pattern);
+        } else {
+            // The scope doesn't change what the pattern compiles to.
+            self.config.scope = config.scope;
         }
     }
 
+    pub fn config(&self) -> &SearchConfig {
+        &self.config
+    }
+
     pub fn pattern(&self) -> &str {
         &self.pattern
     }
@@ -94,18 +174,103 @@ impl SearchEngine {
 
         regex
             .find_iter(text)
+            .filter(|m| self.accepts_match(text, m.start(), m.end()))
             .map(|m| SearchMatch::new(line_idx, m.start(), m.end()))
             .collect()
     }
 
+    /// Regex mode gets its word boundaries from `\b` in the compiled pattern; literal
+    /// matches are checked against the surrounding text here.
+    fn accepts_match(&self, text: &str, start: usize, end: usize) -> bool {
+        !(self.config.whole_word && self.config.mode == SearchMode::Literal)
+            || is_whole_word(text, start, end)
+    }
+
+    /// Replaces every match in `line` with `replacement`. Regex mode expands `$1` and
+    /// `${name}` capture references; literal mode inserts `replacement` as-is.
+    /// Returns `None` when nothing matches.
+    pub fn replace_preview(&self, line: &str, replacement: &str) -> Option<String> {
+        let regex = self.compiled_regex.as_ref()?;
+        if !regex
+            .find_iter(line)
+            .any(|m| self.accepts_match(line, m.start(), m.end()))
+        {
+            return None;
+        }
+
+        let replaced = match self.config.mode {
+            SearchMode::Literal if self.config.whole_word => {
+                regex.replace_all(line, |caps: &Captures| {
+                    let m = caps.get(0).expect("group 0 always participates");
+                    if is_whole_word(line, m.start(), m.end()) {
+                        replacement.to_string()
+                    } else {
+                        m.as_str().to_string()
+                    }
+                })
+            }
+            SearchMode::Literal => regex.replace_all(line, NoExpand(replacement)),
+            SearchMode::Regex => regex.replace_all(line, replacement),
+        };
+        Some(replaced.into_owned())
+    }
+
+    /// Text that replaces the single match starting at byte `start` of `line`, with
+    /// capture references expanded in regex mode. Returns `None` when no match
+    /// starts there.
+    pub fn replace_match(&self, line: &str, start: usize, replacement: &str) -> Option<String> {
+        let regex = self.compiled_regex.as_ref()?;
+        let caps = regex.captures_at(line, start)?;
+        let m = caps.get(0)?;
+        if m.start() != start || !self.accepts_match(line, m.start(), m.end()) {
+            return None;
+        }
+
+        match self.config.mode {
+            SearchMode::Literal => Some(replacement.to_string()),
+            SearchMode::Regex => {
+                let mut expanded = String::new();
+                caps.expand(replacement, &mut expanded);
+                Some(expanded)
+            }
+        }
+    }
+
     pub fn search<F>(&self, start_line: i32, end_line: i32, line_provider: F) -> SearchResults
     where
         F: Fn(i32) -> Option<String>,
     {
+        SearchResults::from_matches(
+            self.search_chunk(start_line..=end_line, line_provider)
+                .matches,
+        )
+    }
+
+    /// Searches one slice of a larger range, so a long scrollback can be covered a
+    /// chunk at a time. Lines outside the configured scope are skipped but still
+    /// counted in `PartialResults::lines`.
+    pub fn search_chunk<F>(&self, range: RangeInclusive<i32>, line_provider: F) -> PartialResults
+    where
+        F: Fn(i32) -> Option<String>,
+    {
+        let lines = range.clone().count();
         if !self.has_pattern() {
-            return SearchResults::new();
+            return PartialResults {
+                matches: Vec::new(),
+                lines,
+            };
         }
 
+        let (start_line, end_line) = match self.config.scope {
+            SearchScope::EntireBuffer => (*range.start(), *range.end()),
+            SearchScope::Range {
+                start_line: range_start,
+                end_line: range_end,
+            } => (
+                (*range.start()).max(range_start),
+                (*range.end()).min(range_end),
+            ),
+        };
         let mut matches = Vec::new();
 
         for line_idx in start_line..=end_line {
@@ -115,7 +280,7 @@ impl SearchEngine {
             }
         }
 
-        SearchResults::from_matches(matches)
+        PartialResults { matches, lines }
     }
 }
 
@@ -141,6 +306,10 @@ mod tests {
         let mut engine = SearchEngine::new(SearchConfig {
             case_sensitive: false,
             mode: SearchMode::Literal,
+            trim_query: false,
+            whole_word: false,
+            smart_case: false,
+            scope: SearchScope::EntireBuffer,
         });
         engine.set_pattern("HELLO").unwrap();
 
@@ -153,6 +322,10 @@ mod tests {
         let mut engine = SearchEngine::new(SearchConfig {
             case_sensitive: true,
             mode: SearchMode::Literal,
+            trim_query: false,
+            whole_word: false,
+            smart_case: false,
+            scope: SearchScope::EntireBuffer,
         });
         engine.set_pattern("HELLO").unwrap();
 
@@ -168,6 +341,10 @@ mod tests {
         let mut engine = SearchEngine::new(SearchConfig {
             case_sensitive: false,
             mode: SearchMode::Regex,
+            trim_query: false,
+            whole_word: false,
+            smart_case: false,
+            scope: SearchScope::EntireBuffer,
         });
         engine.set_pattern(r"\d+").unwrap();
 
@@ -182,6 +359,10 @@ mod tests {
         let mut engine = SearchEngine::new(SearchConfig {
             case_sensitive: false,
             mode: SearchMode::Literal,
+            trim_query: false,
+            whole_word: false,
+            smart_case: false,
+            scope: SearchScope::EntireBuffer,
         });
         // These would be regex metacharacters
         engine.set_pattern("foo.*bar").unwrap();
@@ -200,6 +381,10 @@ mod tests {
         let mut engine = SearchEngine::new(SearchConfig {
             case_sensitive: false,
             mode: SearchMode::Regex,
+            trim_query: false,
+            whole_word: false,
+            smart_case: false,
+            scope: SearchScope::EntireBuffer,
         });
         let result = engine.set_pattern("[invalid");
         assert!(result.is_err());
@@ -233,6 +418,202 @@ mod tests {
         assert!(results.is_empty());
     }
 
+    #[test]
+    fn test_replace_preview() {
+        let mut engine = SearchEngine::new(SearchConfig {
+            case_sensitive: false,
+            mode: SearchMode::Regex,
+            trim_query: false,
+            whole_word: false,
+            smart_case: false,
+            scope: SearchScope::EntireBuffer,
+        });
+        engine.set_pattern(r"(\w+)@(\w+)").unwrap();
+        assert_eq!(
+            engine.replace_preview("mail bob@host now", "$2:$1"),
+            Some("mail host:bob now".to_string())
+        );
+        assert_eq!(engine.replace_preview("no address", "$2:$1"), None);
+
+        let mut literal = SearchEngine::new(SearchConfig::default());
+        literal.set_pattern("$HOME").unwrap();
+        assert_eq!(
+            literal.replace_preview("cd $HOME/src", "$1~"),
+            Some("cd $1~/src".to_string())
+        );
+    }
+
+    #[test]
+    fn test_replace_match() {
+        let mut engine = SearchEngine::new(SearchConfig {
+            mode: SearchMode::Regex,
+            ..SearchConfig::default()
+        });
+        engine.set_pattern(r"(\w+)=(\w+)").unwrap();
+        let line = "a=1 b=2";
+        assert_eq!(
+            engine.replace_match(line, 4, "$2=$1"),
+            Some("2=b".to_string())
+        );
+        assert_eq!(engine.replace_match(line, 1, "$2=$1"), None);
+
+        let mut literal = SearchEngine::new(SearchConfig::default());
+        literal.set_pattern("b").unwrap();
+        assert_eq!(literal.replace_match(line, 4, "$1"), Some("$1".to_string()));
+    }
+
+    #[test]
+    fn test_smart_case() {
+        let mut engine = SearchEngine::new(SearchConfig {
+            smart_case: true,
+            ..SearchConfig::default()
+        });
+
+        engine.set_pattern("error").unwrap();
+        assert!(!engine.is_case_sensitive());
+        assert_eq!(engine.search_line(0, "Error error ERROR").len(), 3);
+
+        engine.set_pattern("Error").unwrap();
+        assert!(engine.is_case_sensitive());
+        let matches = engine.search_line(0, "Error error ERROR");
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].start_col, 0);
+    }
+
+    #[test]
+    fn test_smart_case_skips_regex_escapes() {
+        let mut engine = SearchEngine::new(SearchConfig {
+            smart_case: true,
+            mode: SearchMode::Regex,
+            ..SearchConfig::default()
+        });
+
+        engine.set_pattern(r"\Sfoo").unwrap();
+        assert!(!engine.is_case_sensitive());
+        assert_eq!(engine.search_line(0, "xfoo xFOO").len(), 2);
+
+        engine.set_pattern(r"\SFoo").unwrap();
+        assert!(engine.is_case_sensitive());
+        assert_eq!(engine.search_line(0, "xfoo xFoo").len(), 1);
+
+        engine.set_pattern("Foo").unwrap();
+        assert!(engine.is_case_sensitive());
+
+        let mut literal = SearchEngine::new(SearchConfig {
+            smart_case: true,
+            ..SearchConfig::default()
+        });
+        literal.set_pattern(r"\Sfoo").unwrap();
+        assert!(literal.is_case_sensitive());
+    }
+
+    #[test]
+    fn test_search_chunk_counts_lines_outside_the_scope() {
+        let mut engine = SearchEngine::new(SearchConfig {
+            scope: SearchScope::Range {
+                start_line: 2,
+                end_line: 3,
+            },
+            ..SearchConfig::default()
+        });
+        engine.set_pattern("x").unwrap();
+
+        let partial = engine.search_chunk(0..=4, |_| Some("x".to_string()));
+        assert_eq!(partial.lines, 5);
+        assert_eq!(
+            partial.matches.iter().map(|m| m.line).collect::<Vec<_>>(),
+            vec![2, 3]
+        );
+        assert!(
+            engine
+                .search_chunk(5..=4, |_| Some("x".to_string()))
+                .matches
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_trim_query() {
+        let mut engine = SearchEngine::new(SearchConfig::default());
+        engine.set_pattern(" foo ").unwrap();
+        assert!(engine.search_line(0, "foo bar").is_empty());
+
+        engine.set_config(SearchConfig {
+            trim_query: true,
+            ..SearchConfig::default()
+        });
+        assert_eq!(engine.pattern(), " foo ");
+        let matches = engine.search_line(0, "foo bar");
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].start_col, 0);
+        assert_eq!(matches[0].end_col, 3);
+
+        engine.set_pattern("   ").unwrap();
+        assert!(!engine.has_pattern());
+    }
+
+    #[test]
+    fn test_whole_word_literal() {
+        let mut engine = SearchEngine::new(SearchConfig {
+            whole_word: true,
+            ..SearchConfig::default()
+        });
+        engine.set_pattern("log").unwrap();
+
+        let matches = engine.search_line(0, "login catalog log, (log) _log");
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].start_col, 14);
+        assert_eq!(matches[1].start_col, 20);
+
+        engine.set_pattern("über").unwrap();
+        assert!(engine.search_line(0, "überall").is_empty());
+        assert_eq!(engine.search_line(0, "für über").len(), 1);
+
+        assert_eq!(
+            engine.replace_preview("log catalog", "LOG"),
+            Some("LOG catalog".to_string())
+        );
+        assert_eq!(engine.replace_preview("catalog", "LOG"), None);
+    }
+
+    #[test]
+    fn test_whole_word_regex() {
+        let mut engine = SearchEngine::new(SearchConfig {
+            mode: SearchMode::Regex,
+            whole_word: true,
+            ..SearchConfig::default()
+        });
+        engine.set_pattern("lo.|cat").unwrap();
+
+        let matches = engine.search_line(0, "login log catalog cat");
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].start_col, 6);
+        assert_eq!(matches[1].start_col, 18);
+
+        engine.set_pattern("é").unwrap();
+        assert!(engine.search_line(0, "café").is_empty());
+    }
+
+    #[test]
+    fn test_range_scope_skips_lines_outside() {
+        let mut engine = SearchEngine::new(SearchConfig {
+            scope: SearchScope::Range {
+                start_line: -1,
+                end_line: 1,
+            },
+            ..SearchConfig::default()
+        });
+        engine.set_pattern("hit").unwrap();
+
+        let results = engine.search(-3, 3, |_| Some("hit".to_string()));
+        let lines: Vec<i32> = results.matches().iter().map(|m| m.line).collect();
+        assert_eq!(lines, vec![-1, 0, 1]);
+
+        engine.set_config(SearchConfig::default());
+        assert!(engine.has_pattern());
+        assert_eq!(engine.search(-3, 3, |_| Some("hit".to_string())).count(), 7);
+    }
+
     #[test]
     fn test_unicode_search() {
         let mut engine = SearchEngine::new(SearchConfig::default());
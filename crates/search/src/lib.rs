@@ -4,6 +4,6 @@ mod engine;
 mod matcher;
 mod state;
 
-pub use engine::{SearchConfig, SearchEngine, SearchMode};
-pub use matcher::{SearchMatch, SearchResults};
+pub use engine::{SearchConfig, SearchEngine, SearchMode, SearchScope};
+pub use matcher::{PartialResults, SearchMatch, SearchResults};
 pub use state::SearchState;
@@ -19,6 +19,19 @@ impl SearchMatch {
     pub fn contains(&self, line: i32, col: usize) -> bool {
         self.line == line && col >= self.start_col && col < self.end_col
     }
+
+    /// The matched substring of `line_text`, the same text the match was found in.
+    pub fn text<'a>(&self, line_text: &'a str) -> Option<&'a str> {
+        line_text.get(self.start_col..self.end_col)
+    }
+}
+
+/// Matches found in one chunk of a search, in line order.
+#[derive(Debug, Clone, Default)]
+pub struct PartialResults {
+    pub matches: Vec<SearchMatch>,
+    /// Lines the chunk covered, whether or not they were in scope.
+    pub lines: usize,
 }
 
 #[derive(Debug, Clone)]
@@ -64,6 +77,45 @@ impl SearchResults {
         ranges_by_line
     }
 
+    /// Adds the matches of a chunk of lines that no existing match lies within,
+    /// keeping line order and the current match.
+    pub fn merge_chunk(&mut self, chunk: Vec<SearchMatch>) {
+        let Some(first) = chunk.first() else {
+            return;
+        };
+        let at = self
+            .matches
+            .partition_point(|m| (m.line, m.start_col) < (first.line, first.start_col));
+        if let Some(current) = self.current_index.as_mut()
+            && *current >= at
+        {
+            *current += chunk.len();
+        }
+        for m in &chunk {
+            self.match_ranges_by_line
+                .entry(m.line)
+                .or_default()
+                .push((m.start_col, m.end_col));
+        }
+        self.matches.splice(at..at, chunk);
+    }
+
+    /// Moves every match `delta` lines and drops the ones that end up before
+    /// `first_line`, as when output scrolls lines into and out of history.
+    pub fn shift_lines(&mut self, delta: i32, first_line: i32) {
+        let before = self.matches.len();
+        self.matches.retain_mut(|m| {
+            m.line += delta;
+            m.line >= first_line
+        });
+        let dropped = before - self.matches.len();
+        self.current_index = self
+            .current_index
+            .filter(|_| !self.matches.is_empty())
+            .map(|index| index.saturating_sub(dropped));
+        self.match_ranges_by_line = Self::build_match_ranges_by_line(&self.matches);
+    }
+
     pub fn count(&self) -> usize {
         self.matches.len()
     }
@@ -114,6 +166,18 @@ impl SearchResults {
         self.matches.get(prev_index)
     }
 
+    /// Moves the current match `offset` places, stopping at the first or last match
+    /// instead of wrapping like `next`/`previous`.
+    pub fn advance_by(&mut self, offset: isize) -> Option<&SearchMatch> {
+        let last = self.matches.len().checked_sub(1)?;
+        let index = match self.current_index {
+            Some(i) => i.saturating_add_signed(offset).min(last),
+            None if offset < 0 => last,
+            None => 0,
+        };
+        self.jump_to(index)
+    }
+
     pub fn jump_to(&mut self, index: usize) -> Option<&SearchMatch> {
         if index < self.matches.len() {
             self.current_index = Some(index);
@@ -147,6 +211,18 @@ impl SearchResults {
         self.matches.get(index)
     }
 
+    /// Makes the match closest to `line`/`col` current: fewest lines away first,
+    /// then fewest columns.
+    pub fn jump_to_closest(&mut self, line: i32, col: usize) -> Option<&SearchMatch> {
+        let index = self
+            .matches
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, m)| (m.line.abs_diff(line), m.start_col.abs_diff(col)))
+            .map(|(index, _)| index)?;
+        self.jump_to(index)
+    }
+
     pub fn is_current_match(&self, line: i32, col: usize) -> bool {
         self.current()
             .map(|m| m.contains(line, col))
@@ -164,6 +240,41 @@ impl SearchResults {
             .unwrap_or(false)
     }
 
+    /// Whether any match starts on `line`, answered from the per-line index.
+    pub fn line_has_match(&self, line: i32) -> bool {
+        self.match_ranges_by_line.contains_key(&line)
+    }
+
+    /// Number of matches on each line that has any, in match order.
+    pub fn match_density(&self) -> Vec<(i32, usize)> {
+        let mut density: Vec<(i32, usize)> = Vec::new();
+        for m in &self.matches {
+            match density.last_mut() {
+                Some((line, count)) if *line == m.line => *count += 1,
+                _ => density.push((m.line, 1)),
+            }
+        }
+        density
+    }
+
+    /// Text of every line with a match, oldest first and once per line however many
+    /// matches it holds. Lines `line_provider` can't read are skipped.
+    pub fn matched_lines<F>(&self, line_provider: F) -> Vec<(i32, String)>
+    where
+        F: Fn(i32) -> Option<String>,
+    {
+        let mut lines: Vec<(i32, String)> = Vec::new();
+        for m in &self.matches {
+            if lines.last().is_some_and(|(line, _)| *line == m.line) {
+                continue;
+            }
+            if let Some(text) = line_provider(m.line) {
+                lines.push((m.line, text));
+            }
+        }
+        lines
+    }
+
     pub fn matches_in_range(&self, min_line: i32, max_line: i32) -> Vec<&SearchMatch> {
         self.matches
             .iter()
@@ -257,4 +368,86 @@ mod tests {
         results.jump_to_first();
         assert_eq!(results.current().unwrap().line, -10);
     }
+
+    #[test]
+    fn test_advance_by_stops_at_the_ends() {
+        let matches = (0..3).map(|line| SearchMatch::new(line, 0, 1)).collect();
+        let mut results = SearchResults::from_matches(matches);
+
+        results.advance_by(10);
+        assert_eq!(results.position(), Some((3, 3)));
+        results.advance_by(-2);
+        assert_eq!(results.position(), Some((1, 3)));
+        results.advance_by(-10);
+        assert_eq!(results.position(), Some((1, 3)));
+
+        assert!(SearchResults::new().advance_by(5).is_none());
+    }
+
+    #[test]
+    fn test_line_has_match() {
+        let matches = vec![
+            SearchMatch::new(-3, 0, 5),
+            SearchMatch::new(2, 1, 4),
+            SearchMatch::new(2, 8, 9),
+        ];
+        let results = SearchResults::from_matches(matches);
+
+        assert!(results.line_has_match(-3));
+        assert!(results.line_has_match(2));
+        assert!(!results.line_has_match(0));
+        assert!(!SearchResults::new().line_has_match(0));
+    }
+
+    #[test]
+    fn test_matched_lines_dedupes_lines() {
+        let matches = vec![
+            SearchMatch::new(-3, 0, 5),
+            SearchMatch::new(2, 1, 4),
+            SearchMatch::new(2, 8, 9),
+            SearchMatch::new(4, 0, 1),
+        ];
+        let results = SearchResults::from_matches(matches);
+
+        let lines = results.matched_lines(|line| (line != 4).then(|| format!("line {}", line)));
+        assert_eq!(
+            lines,
+            vec![(-3, "line -3".to_string()), (2, "line 2".to_string())]
+        );
+        assert!(SearchResults::new().matched_lines(|_| None).is_empty());
+    }
+
+    #[test]
+    fn test_match_density_counts_matches_per_line() {
+        let results = SearchResults::from_matches(vec![
+            SearchMatch::new(-3, 0, 5),
+            SearchMatch::new(2, 1, 4),
+            SearchMatch::new(2, 8, 9),
+            SearchMatch::new(2, 12, 13),
+            SearchMatch::new(4, 0, 1),
+        ]);
+
+        assert_eq!(results.match_density(), vec![(-3, 1), (2, 3), (4, 1)]);
+        assert!(SearchResults::new().match_density().is_empty());
+    }
+
+    #[test]
+    fn test_merge_chunk_and_shift_lines_keep_the_current_match() {
+        let mut results =
+            SearchResults::from_matches(vec![SearchMatch::new(5, 0, 1), SearchMatch::new(6, 0, 1)]);
+        results.jump_to_last();
+
+        results.merge_chunk(vec![SearchMatch::new(1, 0, 1), SearchMatch::new(2, 3, 4)]);
+        let lines: Vec<i32> = results.matches().iter().map(|m| m.line).collect();
+        assert_eq!(lines, vec![1, 2, 5, 6]);
+        assert_eq!(results.current().map(|m| m.line), Some(6));
+        assert!(results.is_any_match(2, 3));
+
+        results.shift_lines(-2, 0);
+        let lines: Vec<i32> = results.matches().iter().map(|m| m.line).collect();
+        assert_eq!(lines, vec![0, 3, 4]);
+        assert_eq!(results.current().map(|m| m.line), Some(4));
+        assert!(!results.is_any_match(2, 3));
+        assert!(results.is_any_match(0, 3));
+    }
 }
@@ -1,11 +1,39 @@
-use crate::engine::{SearchConfig, SearchEngine, SearchMode};
+use std::collections::VecDeque;
+
+use crate::engine::{SearchConfig, SearchEngine, SearchMode, SearchScope};
 use crate::matcher::SearchResults;
 
+/// A search spread over several `continue_search` calls. Its ranges keep the line
+/// numbers from when it began, so output scrolling the screen in between doesn't
+/// make it search a line twice or skip one.
+struct PendingSearch {
+    /// Line ranges still to search, in the order they will be searched.
+    ranges: VecDeque<(i32, i32)>,
+    /// Screen top line the ranges are numbered against.
+    origin: u64,
+    /// Newest line queued so far, in the numbering of `ranges`.
+    end_line: i32,
+    /// Bottom screen line; lines printed since the search began end up above it.
+    last_screen_line: i32,
+    total_lines: usize,
+    searched_lines: usize,
+    /// Where the current match is kept as chunks arrive; set from the first
+    /// match found when the search was started without one.
+    anchor: Option<(i32, usize)>,
+}
+
 pub struct SearchState {
     engine: SearchEngine,
     results: SearchResults,
     results_revision: u64,
+    /// Screen top line the results are numbered against, like
+    /// `Terminal::screen_top_line`.
+    results_screen_top_line: u64,
+    pending: Option<PendingSearch>,
     query: String,
+    /// Query in effect when search was last closed, restored by `open`.
+    last_query: String,
+    replacement: String,
     is_active: bool,
     error: Option<String>,
 }
@@ -19,21 +47,37 @@ impl Default for SearchState {
 impl SearchState {
     pub fn new() -> Self {
         Self {
-            engine: SearchEngine::new(SearchConfig::default()),
+            engine: SearchEngine::new(SearchConfig {
+                smart_case: true,
+                ..SearchConfig::default()
+            }),
             results: SearchResults::new(),
             results_revision: 0,
+            results_screen_top_line: 0,
+            pending: None,
             query: String::new(),
+            last_query: String::new(),
+            replacement: String::new(),
             is_active: false,
             error: None,
         }
     }
 
+    /// Activates search with the query it had when it was last closed.
     pub fn open(&mut self) {
         self.is_active = true;
+        if self.query.is_empty() && !self.last_query.is_empty() {
+            let query = self.last_query.clone();
+            self.set_query(&query);
+        }
     }
 
     pub fn close(&mut self) {
         self.is_active = false;
+        if !self.query.is_empty() {
+            self.last_query = self.query.clone();
+        }
+        self.set_scope(SearchScope::EntireBuffer);
         self.clear();
     }
 
@@ -45,7 +89,14 @@ impl SearchState {
         &self.query
     }
 
+    pub fn last_query(&self) -> &str {
+        &self.last_query
+    }
+
     pub fn set_query(&mut self, query: &str) {
+        if query != self.query {
+            self.pending = None;
+        }
         self.query = query.to_string();
         match self.engine.set_pattern(query) {
             Ok(()) => self.error = None,
@@ -53,7 +104,28 @@ impl SearchState {
         }
     }
 
+    /// Text that `replace_current` and `replace_all` put in place of a match. Regex
+    /// mode expands `$1`-style capture references in it.
+    pub fn set_replacement(&mut self, replacement: String) {
+        self.replacement = replacement;
+    }
+
+    pub fn replacement(&self) -> &str {
+        &self.replacement
+    }
+
+    /// `line` with every match replaced, or `None` when nothing in it matches.
+    pub fn replace_all(&self, line: &str) -> Option<String> {
+        self.engine.replace_preview(line, &self.replacement)
+    }
+
+    /// Replacement for the match starting at byte `start` of `line`.
+    pub fn replace_current(&self, line: &str, start: usize) -> Option<String> {
+        self.engine.replace_match(line, start, &self.replacement)
+    }
+
     pub fn clear(&mut self) {
+        self.pending = None;
         self.query.clear();
         let _ = self.engine.set_pattern("");
         self.results = SearchResults::new();
@@ -62,6 +134,7 @@ impl SearchState {
     }
 
     pub fn clear_results_preserving_query(&mut self) {
+        self.pending = None;
         self.results = SearchResults::new();
         self.results_revision = self.results_revision.wrapping_add(1);
     }
@@ -74,6 +147,11 @@ impl SearchState {
         self.results_revision
     }
 
+    /// Screen top line the results' line numbers are relative to.
+    pub fn results_screen_top_line(&self) -> u64 {
+        self.results_screen_top_line
+    }
+
     pub fn error(&self) -> Option<&str> {
         self.error.as_deref()
     }
@@ -82,12 +160,25 @@ impl SearchState {
         self.engine.has_pattern()
     }
 
+    /// Flips case sensitivity from whatever the current query matches with. This
+    /// takes over from smart case until `set_smart_case(true)` turns it back on.
     pub fn toggle_case_sensitive(&mut self) {
         let mut config = self.config();
-        config.case_sensitive = !config.case_sensitive;
+        config.case_sensitive = !self.is_case_sensitive();
+        config.smart_case = false;
         self.engine.set_config(config);
     }
 
+    pub fn set_smart_case(&mut self, smart_case: bool) {
+        let mut config = self.engine.config().clone();
+        config.smart_case = smart_case;
+        self.engine.set_config(config);
+    }
+
+    pub fn is_smart_case(&self) -> bool {
+        self.engine.config().smart_case
+    }
+
     pub fn toggle_regex_mode(&mut self) {
         let mut config = self.config();
         config.mode = match config.mode {
@@ -101,45 +192,306 @@ impl SearchState {
 
     pub fn config(&self) -> SearchConfig {
         SearchConfig {
-            case_sensitive: self.is_case_sensitive(),
+            case_sensitive: self.engine.config().case_sensitive,
             mode: self.mode(),
+            trim_query: self.trims_query(),
+            whole_word: self.is_whole_word(),
+            smart_case: self.is_smart_case(),
+            scope: self.scope(),
         }
     }
 
+    /// Ignore leading and trailing whitespace in the query when matching.
+    pub fn set_trim_query(&mut self, trim_query: bool) {
+        let mut config = self.engine.config().clone();
+        config.trim_query = trim_query;
+        self.engine.set_config(config);
+    }
+
+    pub fn trims_query(&self) -> bool {
+        self.engine.config().trim_query
+    }
+
+    /// Only count matches that stand alone as a word.
+    pub fn set_whole_word(&mut self, whole_word: bool) {
+        let mut config = self.engine.config().clone();
+        config.whole_word = whole_word;
+        self.engine.set_config(config);
+    }
+
+    pub fn is_whole_word(&self) -> bool {
+        self.engine.config().whole_word
+    }
+
+    /// Limits `search` to a range of lines. Takes effect on the next search.
+    pub fn set_scope(&mut self, scope: SearchScope) {
+        let mut config = self.engine.config().clone();
+        config.scope = scope;
+        self.engine.set_config(config);
+    }
+
+    pub fn scope(&self) -> SearchScope {
+        self.engine.config().scope
+    }
+
+    /// True when the query is non-empty but made up only of whitespace.
+    pub fn query_is_blank(&self) -> bool {
+        !self.query.is_empty() && self.query.trim().is_empty()
+    }
+
+    /// Whether the current query matches case-sensitively, after smart case.
     pub fn is_case_sensitive(&self) -> bool {
-        false
+        self.engine.is_case_sensitive()
     }
 
     pub fn mode(&self) -> SearchMode {
-        SearchMode::Literal
+        self.engine.config().mode
     }
 
-    pub fn search<F>(&mut self, start_line: i32, end_line: i32, line_provider: F)
-    where
+    /// Recomputes the results. With `anchor` (line, column), typically the previous
+    /// current match, the match closest to it becomes current instead of the first.
+    pub fn search<F>(
+        &mut self,
+        start_line: i32,
+        end_line: i32,
+        line_provider: F,
+        anchor: Option<(i32, usize)>,
+    ) where
         F: Fn(i32) -> Option<String>,
     {
+        self.pending = None;
         self.results = self.engine.search(start_line, end_line, line_provider);
+        if let Some((line, col)) = anchor {
+            self.results.jump_to_closest(line, col);
+        }
+        self.results_revision = self.results_revision.wrapping_add(1);
+    }
+
+    /// Starts a search of `start_line..=end_line` that `continue_search` works through
+    /// a chunk at a time, beginning with the `first` range (usually the viewport) so
+    /// nearby matches show up straight away. Any search already in flight is dropped.
+    /// The match closest to `anchor` is kept current; without one the newest match
+    /// of the first chunk that has any becomes current. Lines are numbered from the
+    /// screen whose top line is `screen_top_line`; see `follow_screen`.
+    pub fn begin_search(
+        &mut self,
+        start_line: i32,
+        end_line: i32,
+        first: (i32, i32),
+        anchor: Option<(i32, usize)>,
+        screen_top_line: u64,
+    ) {
+        let first = (first.0.max(start_line), first.1.min(end_line));
+        let mut ranges = VecDeque::new();
+        if first.0 <= first.1 {
+            ranges.push_back(first);
+            if first.1 < end_line {
+                ranges.push_back((first.1 + 1, end_line));
+            }
+            if start_line < first.0 {
+                ranges.push_back((start_line, first.0 - 1));
+            }
+        } else if start_line <= end_line {
+            ranges.push_back((start_line, end_line));
+        }
+
+        self.pending = Some(PendingSearch {
+            ranges,
+            origin: screen_top_line,
+            end_line,
+            last_screen_line: end_line,
+            total_lines: (end_line - start_line + 1).max(0) as usize,
+            searched_lines: 0,
+            anchor,
+        });
+        self.results = SearchResults::new();
+        self.results_screen_top_line = screen_top_line;
+        self.results_revision = self.results_revision.wrapping_add(1);
+    }
+
+    /// Renumbers the results, and any search in flight, for a screen whose top line
+    /// is now `screen_top_line` with `history_size` lines above it. Matches on lines
+    /// that left history are dropped, and lines printed since a search began are
+    /// queued for it.
+    pub fn follow_screen(&mut self, screen_top_line: u64, history_size: usize) {
+        if screen_top_line <= self.results_screen_top_line {
+            // Only a different terminal's numbering goes backwards.
+            self.results_screen_top_line = screen_top_line;
+            return;
+        }
+        let moved = (screen_top_line - self.results_screen_top_line).min(i32::MAX as u64) as i32;
+        self.results_screen_top_line = screen_top_line;
+        let first_line = -(history_size.min(i32::MAX as usize) as i32);
+        self.results.shift_lines(-moved, first_line);
         self.results_revision = self.results_revision.wrapping_add(1);
+
+        let Some(pending) = self.pending.as_mut() else {
+            return;
+        };
+        if let Some((line, _)) = pending.anchor.as_mut() {
+            *line -= moved;
+        }
+        let shift = (screen_top_line - pending.origin).min(i32::MAX as u64) as i32;
+        let newest = pending.last_screen_line.saturating_add(shift);
+        if newest > pending.end_line {
+            pending.ranges.push_front((pending.end_line + 1, newest));
+            pending.total_lines += (newest - pending.end_line) as usize;
+            pending.end_line = newest;
+        }
+        let oldest = first_line.saturating_add(shift);
+        let mut evicted = 0;
+        for (start, end) in pending.ranges.iter_mut() {
+            if *start < oldest {
+                evicted += (oldest.min(*end + 1) - *start) as usize;
+                *start = oldest;
+            }
+        }
+        pending.ranges.retain(|(start, end)| start <= end);
+        pending.total_lines = pending.total_lines.saturating_sub(evicted);
+    }
+
+    /// Searches up to `max_lines` more lines of the search started by `begin_search`
+    /// and merges what they hold into the results. `line_provider` is asked for lines
+    /// in the numbering last passed to `follow_screen`.
+    pub fn continue_search<F>(&mut self, max_lines: usize, line_provider: F)
+    where
+        F: Fn(i32) -> Option<String>,
+    {
+        let Some(mut pending) = self.pending.take() else {
+            return;
+        };
+        let shift = self
+            .results_screen_top_line
+            .saturating_sub(pending.origin)
+            .min(i32::MAX as u64) as i32;
+
+        let mut budget = max_lines.max(1);
+        while budget > 0
+            && let Some((start, end)) = pending.ranges.pop_front()
+        {
+            // Work from the end of each range so history is searched newest first.
+            let len = (end - start + 1) as usize;
+            let chunk_start = if len > budget {
+                pending.ranges.push_front((start, end - budget as i32));
+                end - budget as i32 + 1
+            } else {
+                start
+            };
+            let mut partial = self
+                .engine
+                .search_chunk(chunk_start..=end, |line| line_provider(line - shift));
+            for m in &mut partial.matches {
+                m.line -= shift;
+            }
+            pending.searched_lines += partial.lines;
+            budget -= partial.lines.min(budget);
+            self.results.merge_chunk(partial.matches);
+        }
+
+        match pending.anchor {
+            Some((line, col)) => {
+                self.results.jump_to_closest(line, col);
+            }
+            None => {
+                pending.anchor = self
+                    .results
+                    .jump_to_last()
+                    .map(|current| (current.line, current.start_col));
+            }
+        }
+        self.results_revision = self.results_revision.wrapping_add(1);
+
+        if !pending.ranges.is_empty() {
+            self.pending = Some(pending);
+        }
+    }
+
+    pub fn is_searching(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Fraction of lines searched so far, or `None` when no search is in flight.
+    pub fn search_progress(&self) -> Option<f32> {
+        let pending = self.pending.as_ref()?;
+        if pending.total_lines == 0 {
+            return Some(1.0);
+        }
+        Some(pending.searched_lines as f32 / pending.total_lines as f32)
+    }
+
+    /// Keeps the match the user moved to current while later chunks come in.
+    fn follow_current_match(&mut self) {
+        if let Some(pending) = self.pending.as_mut() {
+            pending.anchor = self
+                .results
+                .current()
+                .map(|current| (current.line, current.start_col));
+        }
+    }
+
+    /// Text of the current match, read from the line `line_provider` returns for it.
+    pub fn current_match_text<F>(&self, line_provider: F) -> Option<String>
+    where
+        F: FnOnce(i32) -> Option<String>,
+    {
+        let current = self.results.current()?;
+        let line_text = line_provider(current.line)?;
+        current.text(&line_text).map(str::to_string)
     }
 
     pub fn next_match(&mut self) {
         self.results.next();
+        self.follow_current_match();
     }
 
     pub fn previous_match(&mut self) {
         self.results.previous();
+        self.follow_current_match();
+    }
+
+    pub fn first_match(&mut self) {
+        self.jump_to_first();
+    }
+
+    pub fn last_match(&mut self) {
+        self.jump_to_last();
+    }
+
+    /// Moves `count` matches forward, stopping at the last match.
+    pub fn next_page(&mut self, count: usize) {
+        self.results.advance_by(count as isize);
+        self.follow_current_match();
+    }
+
+    /// Moves `count` matches back, stopping at the first match.
+    pub fn previous_page(&mut self, count: usize) {
+        self.results.advance_by(-(count as isize));
+        self.follow_current_match();
     }
 
     pub fn jump_to_nearest(&mut self, line: i32) {
         self.results.jump_to_nearest(line);
+        self.follow_current_match();
     }
 
     pub fn jump_to_first(&mut self) {
         self.results.jump_to_first();
+        self.follow_current_match();
     }
 
     pub fn jump_to_last(&mut self) {
         self.results.jump_to_last();
+        self.follow_current_match();
+    }
+
+    /// Makes the match at zero-based `index` current, clamped to the last match, so
+    /// `position()` reports `index + 1`. Like the other navigation methods it leaves
+    /// `results_revision` alone: the set of matches is unchanged and the current match is
+    /// read fresh whenever highlights are painted.
+    pub fn select_match(&mut self, index: usize) {
+        let index = index.min(self.results.count().saturating_sub(1));
+        self.results.jump_to(index);
+        self.follow_current_match();
     }
 }
 
@@ -152,12 +504,17 @@ mod tests {
         let mut state = SearchState::new();
         let baseline = state.results_revision();
 
-        state.search(0, 2, |line| match line {
-            0 => Some("alpha".to_string()),
-            1 => Some("beta".to_string()),
-            2 => Some("gamma".to_string()),
-            _ => None,
-        });
+        state.search(
+            0,
+            2,
+            |line| match line {
+                0 => Some("alpha".to_string()),
+                1 => Some("beta".to_string()),
+                2 => Some("gamma".to_string()),
+                _ => None,
+            },
+            None,
+        );
         assert_eq!(state.results_revision(), baseline.wrapping_add(1));
 
         state.clear();
@@ -167,22 +524,76 @@ mod tests {
     #[test]
     fn results_revision_does_not_change_for_selection_navigation() {
         let mut state = SearchState::new();
-        state.search(0, 3, |line| match line {
-            0 => Some("match".to_string()),
-            1 => Some("x".to_string()),
-            2 => Some("match".to_string()),
-            3 => Some("y".to_string()),
-            _ => None,
-        });
+        state.search(
+            0,
+            3,
+            |line| match line {
+                0 => Some("match".to_string()),
+                1 => Some("x".to_string()),
+                2 => Some("match".to_string()),
+                3 => Some("y".to_string()),
+                _ => None,
+            },
+            None,
+        );
 
         let revision = state.results_revision();
         state.next_match();
         state.previous_match();
         state.jump_to_nearest(10);
+        state.select_match(1);
 
         assert_eq!(state.results_revision(), revision);
     }
 
+    #[test]
+    fn select_match_jumps_directly_and_clamps() {
+        let mut state = SearchState::new();
+        state.select_match(3);
+        assert_eq!(state.results().position(), None);
+
+        state.set_query("match");
+        state.search(
+            0,
+            2,
+            |line| match line {
+                0 => Some("match match".to_string()),
+                1 => Some("x".to_string()),
+                2 => Some("match".to_string()),
+                _ => None,
+            },
+            None,
+        );
+
+        state.select_match(1);
+        assert_eq!(state.results().position(), Some((2, 3)));
+        assert_eq!(state.results().current().map(|m| m.start_col), Some(6));
+
+        state.select_match(99);
+        assert_eq!(state.results().position(), Some((3, 3)));
+        assert_eq!(state.results().current().map(|m| m.line), Some(2));
+    }
+
+    #[test]
+    fn current_match_text_reads_the_current_match() {
+        let mut state = SearchState::new();
+        assert_eq!(
+            state.current_match_text(|_| Some("id=42".to_string())),
+            None
+        );
+
+        state.set_query("id=\\d+");
+        state.toggle_regex_mode();
+        let lines = ["id=7 ok", "skip", "x id=42"];
+        let provider = |line: i32| lines.get(line as usize).map(|text| text.to_string());
+        state.search(0, 2, provider, None);
+
+        state.jump_to_first();
+        assert_eq!(state.current_match_text(provider).as_deref(), Some("id=7"));
+        state.next_match();
+        assert_eq!(state.current_match_text(provider).as_deref(), Some("id=42"));
+    }
+
     #[test]
     fn close_advances_results_revision_via_clear() {
         let mut state = SearchState::new();
@@ -196,11 +607,16 @@ mod tests {
     fn clear_results_preserving_query_keeps_query_and_error() {
         let mut state = SearchState::new();
         state.set_query("match");
-        state.search(0, 1, |line| match line {
-            0 => Some("match".to_string()),
-            1 => Some("x".to_string()),
-            _ => None,
-        });
+        state.search(
+            0,
+            1,
+            |line| match line {
+                0 => Some("match".to_string()),
+                1 => Some("x".to_string()),
+                _ => None,
+            },
+            None,
+        );
         assert!(!state.results().is_empty());
 
         let revision = state.results_revision();
@@ -222,4 +638,251 @@ mod tests {
         assert!(state.results().is_empty());
         assert_eq!(state.results_revision(), revision.wrapping_add(1));
     }
+
+    #[test]
+    fn trim_query_ignores_surrounding_whitespace() {
+        let mut state = SearchState::new();
+        assert!(!state.trims_query());
+        state.set_query("  ");
+        assert!(state.query_is_blank());
+        assert!(state.has_valid_pattern());
+
+        state.set_trim_query(true);
+        assert!(state.trims_query());
+        assert!(!state.has_valid_pattern());
+
+        state.set_query(" match ");
+        assert!(!state.query_is_blank());
+        state.search(0, 0, |_| Some("a match".to_string()), None);
+        assert_eq!(state.results().count(), 1);
+        assert_eq!(state.query(), " match ");
+    }
+
+    #[test]
+    fn whole_word_filters_matches_inside_words() {
+        let mut state = SearchState::new();
+        assert!(!state.is_whole_word());
+        state.set_query("log");
+        let provider = |_| Some("login log catalog".to_string());
+        state.search(0, 0, provider, None);
+        assert_eq!(state.results().count(), 3);
+
+        state.set_whole_word(true);
+        assert!(state.is_whole_word());
+        state.search(0, 0, provider, None);
+        assert_eq!(state.results().count(), 1);
+        assert_eq!(state.results().current().map(|m| m.start_col), Some(6));
+    }
+
+    #[test]
+    fn search_with_anchor_keeps_the_closest_match_current() {
+        let mut state = SearchState::new();
+        let provider = |line: i32| Some(format!("item{} x", line));
+        state.set_query("item");
+        state.search(0, 9, provider, None);
+        assert_eq!(state.results().count(), 10);
+        for _ in 0..4 {
+            state.next_match();
+        }
+        assert_eq!(state.results().position(), Some((5, 10)));
+
+        let previous = state.results().current().cloned().unwrap();
+        state.set_query("ite");
+        state.search(0, 9, provider, Some((previous.line, previous.start_col)));
+        let current = state.results().current().unwrap();
+        assert_eq!(current.line, previous.line);
+        assert_eq!(state.results().position(), Some((5, 10)));
+
+        state.search(0, 9, provider, None);
+        assert_eq!(state.results().position(), Some((1, 10)));
+    }
+
+    #[test]
+    fn range_scope_limits_search_and_resets_on_close() {
+        let mut state = SearchState::new();
+        state.open();
+        state.set_scope(SearchScope::Range {
+            start_line: 1,
+            end_line: 2,
+        });
+        state.set_query("x");
+        state.search(0, 3, |_| Some("x".to_string()), None);
+        assert_eq!(state.results().count(), 2);
+
+        state.close();
+        assert_eq!(state.scope(), SearchScope::EntireBuffer);
+    }
+
+    #[test]
+    fn chunked_search_starts_with_the_first_range_and_keeps_the_current_match() {
+        let provider = |line: i32| Some(format!("item {}", line));
+        let mut state = SearchState::new();
+        state.set_query("item");
+
+        state.begin_search(0, 9, (7, 9), None, 0);
+        state.continue_search(3, provider);
+        assert!(state.is_searching());
+        assert_eq!(state.search_progress(), Some(0.3));
+        assert_eq!(state.results().count(), 3);
+        assert_eq!(state.results().current().map(|m| m.line), Some(9));
+
+        while state.is_searching() {
+            state.continue_search(3, provider);
+        }
+        assert_eq!(state.search_progress(), None);
+        assert_eq!(state.results().count(), 10);
+        assert_eq!(state.results().current().map(|m| m.line), Some(9));
+        let lines: Vec<i32> = state.results().matches().iter().map(|m| m.line).collect();
+        assert_eq!(lines, (0..=9).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn chunked_search_follows_output_scrolling_the_screen() {
+        // Every line reads "item N" with N its absolute line number.
+        let provider = |top: u64| move |line: i32| Some(format!("item {}", top as i32 + line));
+        let mut state = SearchState::new();
+        state.set_query("item");
+
+        state.begin_search(-5, 4, (0, 4), None, 10);
+        state.continue_search(5, provider(10));
+        assert_eq!(state.results().current().map(|m| m.line), Some(4));
+
+        // Two lines of output: what was line 4 is now line 2.
+        state.follow_screen(12, 7);
+        assert_eq!(state.results().current().map(|m| m.line), Some(2));
+        while state.is_searching() {
+            state.continue_search(3, provider(12));
+        }
+        let lines: Vec<i32> = state.results().matches().iter().map(|m| m.line).collect();
+        assert_eq!(lines, (-7..=4).collect::<Vec<_>>());
+        assert_eq!(state.results().current().map(|m| m.line), Some(2));
+
+        // Lines leaving history take their matches with them.
+        state.follow_screen(13, 3);
+        let lines: Vec<i32> = state.results().matches().iter().map(|m| m.line).collect();
+        assert_eq!(lines, (-3..=3).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn changing_the_query_cancels_a_chunked_search() {
+        let provider = |line: i32| Some(format!("item {}", line));
+        let mut state = SearchState::new();
+        state.set_query("item");
+        state.begin_search(0, 99, (90, 99), Some((50, 0)), 0);
+        state.continue_search(10, provider);
+        assert!(state.is_searching());
+
+        state.set_query("item 5");
+        assert!(!state.is_searching());
+        state.continue_search(10, provider);
+        assert_eq!(state.results().count(), 10);
+    }
+
+    #[test]
+    fn paging_clamps_when_there_are_fewer_matches_than_a_page() {
+        let mut state = SearchState::new();
+        state.set_query("hit");
+        state.search(0, 3, |_| Some("hit".to_string()), None);
+
+        state.next_page(10);
+        assert_eq!(state.results().position(), Some((4, 4)));
+        state.next_page(10);
+        assert_eq!(state.results().position(), Some((4, 4)));
+        state.previous_page(3);
+        assert_eq!(state.results().position(), Some((1, 4)));
+        state.previous_page(10);
+        assert_eq!(state.results().position(), Some((1, 4)));
+
+        state.last_match();
+        assert_eq!(state.results().position(), Some((4, 4)));
+        state.first_match();
+        assert_eq!(state.results().position(), Some((1, 4)));
+
+        // Single-step navigation still wraps.
+        state.previous_match();
+        assert_eq!(state.results().position(), Some((4, 4)));
+    }
+
+    #[test]
+    fn smart_case_is_the_default_and_the_toggle_overrides_it() {
+        let lines = ["error: disk full", "Error: timeout"];
+        let provider = |line: i32| lines.get(line as usize).map(|text| text.to_string());
+        let mut state = SearchState::new();
+        assert!(state.is_smart_case());
+
+        state.set_query("error");
+        state.search(0, 1, provider, None);
+        assert_eq!(state.results().count(), 2);
+
+        state.set_query("Error");
+        state.search(0, 1, provider, None);
+        assert_eq!(state.results().count(), 1);
+
+        state.toggle_case_sensitive();
+        assert!(!state.is_smart_case());
+        assert!(!state.is_case_sensitive());
+        state.search(0, 1, provider, None);
+        assert_eq!(state.results().count(), 2);
+
+        state.set_query("error");
+        state.toggle_case_sensitive();
+        state.search(0, 1, provider, None);
+        assert_eq!(state.results().count(), 1);
+    }
+
+    #[test]
+    fn toggles_keep_the_search_mode() {
+        let lines = ["key=Value", "key=value"];
+        let provider = |line: i32| lines.get(line as usize).map(|text| text.to_string());
+        let mut state = SearchState::new();
+        state.toggle_regex_mode();
+        assert_eq!(state.mode(), SearchMode::Regex);
+
+        state.set_query("key=(v)alue");
+        state.toggle_case_sensitive();
+        assert_eq!(state.mode(), SearchMode::Regex);
+        assert!(state.is_case_sensitive());
+        state.search(0, 1, provider, None);
+        assert_eq!(state.results().count(), 1);
+
+        state.toggle_case_sensitive();
+        state.search(0, 1, provider, None);
+        assert_eq!(state.results().count(), 2);
+
+        state.toggle_regex_mode();
+        assert_eq!(state.mode(), SearchMode::Literal);
+        state.search(0, 1, provider, None);
+        assert_eq!(state.results().count(), 0);
+    }
+
+    #[test]
+    fn reopening_restores_the_last_query() {
+        let mut state = SearchState::new();
+        state.open();
+        state.set_query("foo");
+        state.close();
+        assert_eq!(state.query(), "");
+        assert_eq!(state.last_query(), "foo");
+
+        state.open();
+        assert_eq!(state.query(), "foo");
+        assert!(state.has_valid_pattern());
+
+        // Closing with an empty query keeps the earlier one around.
+        state.set_query("");
+        state.close();
+        assert_eq!(state.last_query(), "foo");
+    }
+
+    #[test]
+    fn replacement_applies_to_current_and_all_matches() {
+        let mut state = SearchState::new();
+        state.set_query("cat");
+        state.set_replacement("dog".to_string());
+
+        assert_eq!(state.replace_current("cat cat", 4), Some("dog".to_string()));
+        assert_eq!(state.replace_current("cat cat", 1), None);
+        assert_eq!(state.replace_all("cat cat"), Some("dog dog".to_string()));
+        assert_eq!(state.replace_all("bird"), None);
+    }
 }
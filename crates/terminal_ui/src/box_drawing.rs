@@ -0,0 +1,457 @@
+//! Built-in glyphs for box-drawing (U+2500..=U+257F) and block elements (U+2580..=U+259F).
+//!
+//! Fonts often leave gaps between these characters or misalign them with the cell grid, which
+//! breaks TUI borders. Drawing them as rectangles sized to the cell keeps adjacent cells joined.
+//! Rounded corners and diagonals are left to the font.
+
+/// Filled rectangle in cell-local pixels. `alpha` scales the foreground color (shade blocks).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct GlyphRect {
+    pub(crate) x: f32,
+    pub(crate) y: f32,
+    pub(crate) width: f32,
+    pub(crate) height: f32,
+    pub(crate) alpha: f32,
+}
+
+impl GlyphRect {
+    fn solid(x0: f32, y0: f32, x1: f32, y1: f32) -> Self {
+        Self {
+            x: x0.min(x1),
+            y: y0.min(y1),
+            width: (x1 - x0).abs(),
+            height: (y1 - y0).abs(),
+            alpha: 1.0,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Weight {
+    None,
+    Light,
+    Heavy,
+    Double,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Arm {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+const ARMS: [Arm; 4] = [Arm::Up, Arm::Right, Arm::Down, Arm::Left];
+
+impl Arm {
+    /// Perpendicular arms on the negative and positive side of this arm's cross axis.
+    fn sides(self) -> (Arm, Arm) {
+        match self {
+            Arm::Up | Arm::Down => (Arm::Left, Arm::Right),
+            Arm::Left | Arm::Right => (Arm::Up, Arm::Down),
+        }
+    }
+}
+
+/// Returns the rectangles that draw `c` in a `width` x `height` cell, or `None` when the
+/// character should be rendered by the font.
+pub(crate) fn glyph_rects(c: char, width: f32, height: f32) -> Option<Vec<GlyphRect>> {
+    if width <= 0.0 || height <= 0.0 {
+        return None;
+    }
+
+    match c as u32 {
+        0x2500..=0x257f => line_rects(c, width, height),
+        0x2580..=0x259f => Some(block_rects(c, width, height)),
+        _ => None,
+    }
+}
+
+fn line_rects(c: char, width: f32, height: f32) -> Option<Vec<GlyphRect>> {
+    let light = (width / 8.0).round().max(1.0);
+    let heavy = light * 2.0;
+
+    if let Some((weight, vertical, dashes)) = dashed_line(c) {
+        let thickness = if weight == Weight::Heavy {
+            heavy
+        } else {
+            light
+        };
+        return Some(dashed_rects(width, height, thickness, vertical, dashes));
+    }
+
+    let [up, right, down, left] = line_weights(c)?;
+    let weights = |arm| match arm {
+        Arm::Up => up,
+        Arm::Right => right,
+        Arm::Down => down,
+        Arm::Left => left,
+    };
+
+    // Snap the center so light lines land on whole pixels.
+    let center_x = (width / 2.0 - light / 2.0).round() + light / 2.0;
+    let center_y = (height / 2.0 - light / 2.0).round() + light / 2.0;
+    let double_offset = light;
+    let thickness = |weight| match weight {
+        Weight::Heavy => heavy,
+        _ => light,
+    };
+    // How far a perpendicular arm reaches across this arm's axis.
+    let half_extent = |weight| match weight {
+        Weight::None => 0.0,
+        Weight::Light | Weight::Heavy => thickness(weight) / 2.0,
+        Weight::Double => double_offset + light / 2.0,
+    };
+
+    let mut rects = Vec::with_capacity(8);
+    for arm in ARMS {
+        let weight = weights(arm);
+        if weight == Weight::None {
+            continue;
+        }
+
+        let (negative_side, positive_side) = arm.sides();
+        let (negative, positive) = (weights(negative_side), weights(positive_side));
+        let arm_rect = |start: f32, cross_center: f32, cross_thickness: f32| {
+            let cross = (
+                cross_center - cross_thickness / 2.0,
+                cross_center + cross_thickness / 2.0,
+            );
+            match arm {
+                Arm::Right => GlyphRect::solid(
+                    center_x + start,
+                    center_y + cross.0,
+                    width,
+                    center_y + cross.1,
+                ),
+                Arm::Left => GlyphRect::solid(
+                    0.0,
+                    center_y + cross.0,
+                    center_x - start,
+                    center_y + cross.1,
+                ),
+                Arm::Down => GlyphRect::solid(
+                    center_x + cross.0,
+                    center_y + start,
+                    center_x + cross.1,
+                    height,
+                ),
+                Arm::Up => GlyphRect::solid(
+                    center_x + cross.0,
+                    0.0,
+                    center_x + cross.1,
+                    center_y - start,
+                ),
+            }
+        };
+
+        if weight == Weight::Double {
+            for (side_weight, other_weight, cross_center) in [
+                (negative, positive, -double_offset),
+                (positive, negative, double_offset),
+            ] {
+                let start = if side_weight == Weight::Double {
+                    // Leave the inside of a double corner open.
+                    double_offset - light / 2.0
+                } else if other_weight == Weight::Double {
+                    -half_extent(Weight::Double)
+                } else {
+                    -half_extent(side_weight).max(half_extent(other_weight))
+                };
+                rects.push(arm_rect(start, cross_center, light));
+            }
+        } else {
+            let start = -half_extent(negative).max(half_extent(positive));
+            rects.push(arm_rect(start, 0.0, thickness(weight)));
+        }
+    }
+
+    Some(rects)
+}
+
+fn dashed_rects(
+    width: f32,
+    height: f32,
+    thickness: f32,
+    vertical: bool,
+    dashes: usize,
+) -> Vec<GlyphRect> {
+    let length = if vertical { height } else { width };
+    let segment = length / dashes as f32;
+    let gap = (segment * 0.4).max(1.0).min(segment - 1.0).max(0.0);
+    let cross_start = ((if vertical { width } else { height }) / 2.0 - thickness / 2.0).round();
+
+    (0..dashes)
+        .map(|index| {
+            // Center each dash in its segment so the pattern tiles across cells.
+            let start = segment * index as f32 + gap / 2.0;
+            let end = segment * (index + 1) as f32 - gap / 2.0;
+            if vertical {
+                GlyphRect::solid(cross_start, start, cross_start + thickness, end)
+            } else {
+                GlyphRect::solid(start, cross_start, end, cross_start + thickness)
+            }
+        })
+        .collect()
+}
+
+fn dashed_line(c: char) -> Option<(Weight, bool, usize)> {
+    use Weight::{Heavy, Light};
+
+    Some(match c {
+        '┄' => (Light, false, 3),
+        '┅' => (Heavy, false, 3),
+        '┆' => (Light, true, 3),
+        '┇' => (Heavy, true, 3),
+        '┈' => (Light, false, 4),
+        '┉' => (Heavy, false, 4),
+        '┊' => (Light, true, 4),
+        '┋' => (Heavy, true, 4),
+        '╌' => (Light, false, 2),
+        '╍' => (Heavy, false, 2),
+        '╎' => (Light, true, 2),
+        '╏' => (Heavy, true, 2),
+        _ => return None,
+    })
+}
+
+/// Arm weights as `[up, right, down, left]`.
+fn line_weights(c: char) -> Option<[Weight; 4]> {
+    use Weight::{Double as D, Heavy as H, Light as L, None as N};
+
+    Some(match c {
+        '─' => [N, L, N, L],
+        '━' => [N, H, N, H],
+        '│' => [L, N, L, N],
+        '┃' => [H, N, H, N],
+        '┌' => [N, L, L, N],
+        '┍' => [N, H, L, N],
+        '┎' => [N, L, H, N],
+        '┏' => [N, H, H, N],
+        '┐' => [N, N, L, L],
+        '┑' => [N, N, L, H],
+        '┒' => [N, N, H, L],
+        '┓' => [N, N, H, H],
+        '└' => [L, L, N, N],
+        '┕' => [L, H, N, N],
+        '┖' => [H, L, N, N],
+        '┗' => [H, H, N, N],
+        '┘' => [L, N, N, L],
+        '┙' => [L, N, N, H],
+        '┚' => [H, N, N, L],
+        '┛' => [H, N, N, H],
+        '├' => [L, L, L, N],
+        '┝' => [L, H, L, N],
+        '┞' => [H, L, L, N],
+        '┟' => [L, L, H, N],
+        '┠' => [H, L, H, N],
+        '┡' => [H, H, L, N],
+        '┢' => [L, H, H, N],
+        '┣' => [H, H, H, N],
+        '┤' => [L, N, L, L],
+        '┥' => [L, N, L, H],
+        '┦' => [H, N, L, L],
+        '┧' => [L, N, H, L],
+        '┨' => [H, N, H, L],
+        '┩' => [H, N, L, H],
+        '┪' => [L, N, H, H],
+        '┫' => [H, N, H, H],
+        '┬' => [N, L, L, L],
+        '┭' => [N, L, L, H],
+        '┮' => [N, H, L, L],
+        '┯' => [N, H, L, H],
+        '┰' => [N, L, H, L],
+        '┱' => [N, L, H, H],
+        '┲' => [N, H, H, L],
+        '┳' => [N, H, H, H],
+        '┴' => [L, L, N, L],
+        '┵' => [L, L, N, H],
+        '┶' => [L, H, N, L],
+        '┷' => [L, H, N, H],
+        '┸' => [H, L, N, L],
+        '┹' => [H, L, N, H],
+        '┺' => [H, H, N, L],
+        '┻' => [H, H, N, H],
+        '┼' => [L, L, L, L],
+        '┽' => [L, L, L, H],
+        '┾' => [L, H, L, L],
+        '┿' => [L, H, L, H],
+        '╀' => [H, L, L, L],
+        '╁' => [L, L, H, L],
+        '╂' => [H, L, H, L],
+        '╃' => [H, L, L, H],
+        '╄' => [H, H, L, L],
+        '╅' => [L, L, H, H],
+        '╆' => [L, H, H, L],
+        '╇' => [H, H, L, H],
+        '╈' => [L, H, H, H],
+        '╉' => [H, L, H, H],
+        '╊' => [H, H, H, L],
+        '╋' => [H, H, H, H],
+        '═' => [N, D, N, D],
+        '║' => [D, N, D, N],
+        '╒' => [N, D, L, N],
+        '╓' => [N, L, D, N],
+        '╔' => [N, D, D, N],
+        '╕' => [N, N, L, D],
+        '╖' => [N, N, D, L],
+        '╗' => [N, N, D, D],
+        '╘' => [L, D, N, N],
+        '╙' => [D, L, N, N],
+        '╚' => [D, D, N, N],
+        '╛' => [L, N, N, D],
+        '╜' => [D, N, N, L],
+        '╝' => [D, N, N, D],
+        '╞' => [L, D, L, N],
+        '╟' => [D, L, D, N],
+        '╠' => [D, D, D, N],
+        '╡' => [L, N, L, D],
+        '╢' => [D, N, D, L],
+        '╣' => [D, N, D, D],
+        '╤' => [N, D, L, D],
+        '╥' => [N, L, D, L],
+        '╦' => [N, D, D, D],
+        '╧' => [L, D, N, D],
+        '╨' => [D, L, N, L],
+        '╩' => [D, D, N, D],
+        '╪' => [L, D, L, D],
+        '╫' => [D, L, D, L],
+        '╬' => [D, D, D, D],
+        '╴' => [N, N, N, L],
+        '╵' => [L, N, N, N],
+        '╶' => [N, L, N, N],
+        '╷' => [N, N, L, N],
+        '╸' => [N, N, N, H],
+        '╹' => [H, N, N, N],
+        '╺' => [N, H, N, N],
+        '╻' => [N, N, H, N],
+        '╼' => [N, H, N, L],
+        '╽' => [L, N, H, N],
+        '╾' => [N, L, N, H],
+        '╿' => [H, N, L, N],
+        _ => return None,
+    })
+}
+
+fn block_rects(c: char, width: f32, height: f32) -> Vec<GlyphRect> {
+    let eighth_x = |n: f32| (width * n / 8.0).round();
+    let eighth_y = |n: f32| (height * n / 8.0).round();
+    let half_x = eighth_x(4.0);
+    let half_y = eighth_y(4.0);
+    let shade = |alpha| GlyphRect {
+        alpha,
+        ..GlyphRect::solid(0.0, 0.0, width, height)
+    };
+
+    let code = c as u32;
+    match c {
+        '▀' => vec![GlyphRect::solid(0.0, 0.0, width, half_y)],
+        '▁'..='▇' => {
+            let eighths = (code - '▁' as u32 + 1) as f32;
+            vec![GlyphRect::solid(
+                0.0,
+                height - eighth_y(eighths),
+                width,
+                height,
+            )]
+        }
+        '█' => vec![GlyphRect::solid(0.0, 0.0, width, height)],
+        '▉'..='▏' => {
+            let eighths = (7 - (code - '▉' as u32)) as f32;
+            vec![GlyphRect::solid(0.0, 0.0, eighth_x(eighths), height)]
+        }
+        '▐' => vec![GlyphRect::solid(half_x, 0.0, width, height)],
+        '░' => vec![shade(0.25)],
+        '▒' => vec![shade(0.5)],
+        '▓' => vec![shade(0.75)],
+        '▔' => vec![GlyphRect::solid(0.0, 0.0, width, eighth_y(1.0))],
+        '▕' => vec![GlyphRect::solid(width - eighth_x(1.0), 0.0, width, height)],
+        _ => {
+            // Quadrants, as [upper left, upper right, lower left, lower right].
+            let quadrants: [bool; 4] = match c {
+                '▖' => [false, false, true, false],
+                '▗' => [false, false, false, true],
+                '▘' => [true, false, false, false],
+                '▙' => [true, false, true, true],
+                '▚' => [true, false, false, true],
+                '▛' => [true, true, true, false],
+                '▜' => [true, true, false, true],
+                '▝' => [false, true, false, false],
+                '▞' => [false, true, true, false],
+                '▟' => [false, true, true, true],
+                _ => [false; 4],
+            };
+            let bounds = [
+                (0.0, 0.0, half_x, half_y),
+                (half_x, 0.0, width, half_y),
+                (0.0, half_y, half_x, height),
+                (half_x, half_y, width, height),
+            ];
+            quadrants
+                .into_iter()
+                .zip(bounds)
+                .filter(|(filled, _)| *filled)
+                .map(|(_, (x0, y0, x1, y1))| GlyphRect::solid(x0, y0, x1, y1))
+                .collect()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn covers(rects: &[GlyphRect], x: f32, y: f32) -> bool {
+        rects.iter().any(|rect| {
+            x >= rect.x && x < rect.x + rect.width && y >= rect.y && y < rect.y + rect.height
+        })
+    }
+
+    #[test]
+    fn lines_reach_the_cell_edges_they_connect_to() {
+        let cross = glyph_rects('┼', 8.0, 16.0).unwrap();
+        assert!(covers(&cross, 0.0, 8.0));
+        assert!(covers(&cross, 7.5, 8.0));
+        assert!(covers(&cross, 4.0, 0.0));
+        assert!(covers(&cross, 4.0, 15.5));
+
+        let corner = glyph_rects('┌', 8.0, 16.0).unwrap();
+        assert!(covers(&corner, 7.5, 8.0));
+        assert!(covers(&corner, 4.0, 15.5));
+        assert!(!covers(&corner, 0.0, 8.0));
+        assert!(!covers(&corner, 4.0, 0.0));
+    }
+
+    #[test]
+    fn double_corner_keeps_its_inside_open() {
+        let corner = glyph_rects('╔', 8.0, 16.0).unwrap();
+        // Outer and inner strokes both reach the right and bottom edges.
+        assert_eq!(corner.len(), 4);
+        assert!(covers(&corner, 7.5, 7.0));
+        assert!(covers(&corner, 7.5, 9.0));
+        assert!(!covers(&corner, 7.5, 8.0));
+        assert!(!covers(&corner, 0.0, 7.0));
+    }
+
+    #[test]
+    fn blocks_fill_their_fraction_of_the_cell() {
+        assert_eq!(
+            glyph_rects('▄', 8.0, 16.0).unwrap(),
+            vec![GlyphRect::solid(0.0, 8.0, 8.0, 16.0)]
+        );
+        assert_eq!(
+            glyph_rects('▏', 8.0, 16.0).unwrap(),
+            vec![GlyphRect::solid(0.0, 0.0, 1.0, 16.0)]
+        );
+        assert_eq!(glyph_rects('▒', 8.0, 16.0).unwrap()[0].alpha, 0.5);
+        assert_eq!(glyph_rects('▚', 8.0, 16.0).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn rounded_corners_diagonals_and_text_use_the_font() {
+        assert!(glyph_rects('╭', 8.0, 16.0).is_none());
+        assert!(glyph_rects('╳', 8.0, 16.0).is_none());
+        assert!(glyph_rects('a', 8.0, 16.0).is_none());
+    }
+}
@@ -1,5 +1,6 @@
+use crate::box_drawing;
 use gpui::{
-    App, Bounds, Element, Font, FontWeight, Hsla, IntoElement, Pixels, SharedString, Size,
+    App, Bounds, Element, Font, FontWeight, Hsla, IntoElement, Pixels, Rgba, SharedString, Size,
     TextAlign, TextRun, UnderlineStyle, Window, point, px, quad,
 };
 
@@ -19,12 +20,23 @@ pub struct CellRenderInfo {
     pub search_current: bool,
     /// Part of any search match (but not current)
     pub search_match: bool,
+    /// Part of the focused error/warning match
+    pub diagnostic_current: bool,
+    /// Part of any error/warning match (but not current)
+    pub diagnostic_match: bool,
+    /// Last cell of a row whose line soft-wraps onto the next row
+    pub wrapped: bool,
+    /// Id of the OSC 8 hyperlink covering this cell
+    pub hyperlink: Option<u64>,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum TerminalCursorStyle {
     Line,
     Block,
+    Underline,
+    /// Outline of a block, drawn with a 1px stroke.
+    HollowBlock,
 }
 
 /// Custom element for rendering the terminal grid.
@@ -41,10 +53,23 @@ pub struct TerminalGrid {
     pub selection_fg: Hsla,
     pub search_match_bg: Hsla,
     pub search_current_bg: Hsla,
+    pub diagnostic_match_bg: Hsla,
+    pub diagnostic_current_bg: Hsla,
     pub hovered_link_range: Option<(usize, usize, usize)>,
+    /// OSC 8 hyperlink under the pointer; all of its cells are underlined
+    pub hovered_hyperlink: Option<u64>,
     pub font_family: SharedString,
     pub font_size: Pixels,
     pub cursor_style: TerminalCursorStyle,
+    /// Derive cursor and cursor glyph colors from the cell beneath the cursor
+    pub cursor_contrast: bool,
+    /// Marker color for soft-wrapped rows; `None` disables the marker.
+    pub wrap_indicator: Option<Hsla>,
+    /// Draw `·` in spaces that sit before the last glyph on their row and `→` in
+    /// tab cells, in a dimmed foreground. Only painted; the cells are unchanged.
+    pub show_whitespace: bool,
+    /// Draw box-drawing and block element characters as cell-sized shapes instead of font glyphs
+    pub builtin_box_drawing: bool,
 }
 
 impl IntoElement for TerminalGrid {
@@ -68,6 +93,141 @@ fn colors_approximately_equal(a: &Hsla, b: &Hsla) -> bool {
         && (a.a - b.a).abs() < EPSILON
 }
 
+// Below this ratio the cursor is treated as blending into the cell beneath it.
+const CURSOR_MIN_CONTRAST: f32 = 1.5;
+// Glyphs under a block cursor fall back to black or white below this ratio.
+const CURSOR_GLYPH_MIN_CONTRAST: f32 = 3.0;
+
+fn contrast_ratio(a: Hsla, b: Hsla) -> f32 {
+    termy_themes::contrast_ratio(Rgba::from(a), Rgba::from(b))
+}
+
+/// Black or white, whichever reads better on `bg`.
+fn contrasting_mono(bg: Hsla) -> Hsla {
+    let black = Hsla::black();
+    let white = Hsla::white();
+    if contrast_ratio(black, bg) >= contrast_ratio(white, bg) {
+        black
+    } else {
+        white
+    }
+}
+
+/// Cursor fill and block-cursor glyph colors for a cell with background `cell_bg`
+/// and text color `cell_fg`. The cursor falls back to the text color when it would
+/// blend into the background, and the glyph is drawn in the cell background.
+fn contrast_cursor_colors(cursor: Hsla, cell_fg: Hsla, cell_bg: Hsla) -> (Hsla, Hsla) {
+    let cursor = if contrast_ratio(cursor, cell_bg) >= CURSOR_MIN_CONTRAST {
+        cursor
+    } else if contrast_ratio(cell_fg, cell_bg) >= CURSOR_MIN_CONTRAST {
+        cell_fg
+    } else {
+        contrasting_mono(cell_bg)
+    };
+
+    let glyph = if contrast_ratio(cell_bg, cursor) >= CURSOR_GLYPH_MIN_CONTRAST {
+        Hsla { a: 1.0, ..cell_bg }
+    } else {
+        contrasting_mono(cursor)
+    };
+
+    (cursor, glyph)
+}
+
+// Alpha applied to a cell's foreground for its whitespace marker.
+const WHITESPACE_MARKER_ALPHA: f32 = 0.3;
+// Tab stops assumed when skipping the blank cells a tab advanced over.
+const TAB_WIDTH: usize = 8;
+
+fn is_blank_char(c: char) -> bool {
+    c == ' ' || c == '\0'
+}
+
+/// Column of the last non-blank cell in each row. Cleared cells are stored as
+/// spaces, so whitespace past this column can't be told apart from empty space.
+fn row_content_ends(cells: &[CellRenderInfo], rows: usize) -> Vec<Option<usize>> {
+    let mut ends = vec![None; rows];
+    for cell in cells {
+        if cell.row >= rows || is_blank_char(cell.char) {
+            continue;
+        }
+        let end = &mut ends[cell.row];
+        *end = Some(end.map_or(cell.col, |col: usize| col.max(cell.col)));
+    }
+    ends
+}
+
+/// Marker glyph for each whitespace cell shown by `show_whitespace`, keyed by
+/// index into `cells`. The terminal keeps a tab as `\t` in its first cell and
+/// blanks up to the next stop; only the first cell gets a marker. Wide-char
+/// spacers, hidden cells and the cursor cell are never marked.
+fn whitespace_markers(cells: &[CellRenderInfo], rows: usize) -> Vec<(usize, char)> {
+    let row_ends = row_content_ends(cells, rows);
+    let mut markers = Vec::new();
+    let mut tab_span: Option<(usize, usize)> = None;
+
+    for (index, cell) in cells.iter().enumerate() {
+        if !cell.render_text || cell.is_cursor {
+            continue;
+        }
+        if cell.char == '\t' {
+            markers.push((index, '→'));
+            tab_span = Some((cell.row, (cell.col / TAB_WIDTH + 1) * TAB_WIDTH));
+            continue;
+        }
+
+        let in_tab = tab_span.is_some_and(|(row, end)| cell.row == row && cell.col < end);
+        let before_end = row_ends
+            .get(cell.row)
+            .copied()
+            .flatten()
+            .is_some_and(|end| cell.col < end);
+        if cell.char == ' ' && before_end && !in_tab {
+            markers.push((index, '·'));
+        }
+    }
+    markers
+}
+
+impl TerminalGrid {
+    /// Background actually painted behind `cell`, including highlight overlays.
+    fn painted_cell_background(&self, cell: &CellRenderInfo) -> Hsla {
+        if cell.selected {
+            self.selection_bg
+        } else if cell.search_current {
+            self.search_current_bg
+        } else if cell.search_match {
+            self.search_match_bg
+        } else if cell.diagnostic_current {
+            self.diagnostic_current_bg
+        } else if cell.diagnostic_match {
+            self.diagnostic_match_bg
+        } else if cell.bg.a > 0.01 && !colors_approximately_equal(&cell.bg, &self.default_bg) {
+            cell.bg
+        } else {
+            self.default_bg
+        }
+    }
+
+    /// Cursor fill and block-cursor glyph colors for the cursor cell.
+    fn cursor_colors(&self, cell: &CellRenderInfo) -> (Hsla, Hsla) {
+        if !self.cursor_contrast {
+            return (self.cursor_color, Hsla::black());
+        }
+
+        let cell_fg = if cell.selected {
+            self.selection_fg
+        } else {
+            cell.fg
+        };
+        contrast_cursor_colors(
+            self.cursor_color,
+            cell_fg,
+            self.painted_cell_background(cell),
+        )
+    }
+}
+
 impl Element for TerminalGrid {
     type RequestLayoutState = ();
     type PrepaintState = ();
@@ -184,6 +344,24 @@ impl Element for TerminalGrid {
                     Hsla::transparent_black(),
                     gpui::BorderStyle::default(),
                 ));
+            } else if cell.diagnostic_current {
+                window.paint_quad(quad(
+                    cell_bounds,
+                    px(0.0),
+                    self.diagnostic_current_bg,
+                    gpui::Edges::default(),
+                    Hsla::transparent_black(),
+                    gpui::BorderStyle::default(),
+                ));
+            } else if cell.diagnostic_match {
+                window.paint_quad(quad(
+                    cell_bounds,
+                    px(0.0),
+                    self.diagnostic_match_bg,
+                    gpui::Edges::default(),
+                    Hsla::transparent_black(),
+                    gpui::BorderStyle::default(),
+                ));
             } else if cell.bg.a > 0.01 && !colors_approximately_equal(&cell.bg, &self.default_bg) {
                 window.paint_quad(quad(
                     cell_bounds,
@@ -197,7 +375,7 @@ impl Element for TerminalGrid {
 
             if cell.is_cursor {
                 let cursor_bounds = match self.cursor_style {
-                    TerminalCursorStyle::Block => cell_bounds,
+                    TerminalCursorStyle::Block | TerminalCursorStyle::HollowBlock => cell_bounds,
                     TerminalCursorStyle::Line => {
                         let cell_width: f32 = self.cell_size.width.into();
                         let cursor_width = px(cell_width.clamp(1.0, 2.0));
@@ -209,16 +387,39 @@ impl Element for TerminalGrid {
                             },
                         )
                     }
+                    TerminalCursorStyle::Underline => {
+                        let cell_height: f32 = self.cell_size.height.into();
+                        let thickness = px((cell_height * 0.08).clamp(1.0, 2.0));
+                        Bounds::new(
+                            point(x, y + self.cell_size.height - thickness),
+                            Size {
+                                width: self.cell_size.width,
+                                height: thickness,
+                            },
+                        )
+                    }
                 };
 
-                window.paint_quad(quad(
-                    cursor_bounds,
-                    px(0.0),
-                    self.cursor_color,
-                    gpui::Edges::default(),
-                    Hsla::transparent_black(),
-                    gpui::BorderStyle::default(),
-                ));
+                let (cursor_color, _) = self.cursor_colors(cell);
+                if self.cursor_style == TerminalCursorStyle::HollowBlock {
+                    window.paint_quad(quad(
+                        cursor_bounds,
+                        px(0.0),
+                        Hsla::transparent_black(),
+                        px(1.0),
+                        cursor_color,
+                        gpui::BorderStyle::default(),
+                    ));
+                } else {
+                    window.paint_quad(quad(
+                        cursor_bounds,
+                        px(0.0),
+                        cursor_color,
+                        gpui::Edges::default(),
+                        Hsla::transparent_black(),
+                        gpui::BorderStyle::default(),
+                    ));
+                }
             }
         }
 
@@ -234,13 +435,6 @@ impl Element for TerminalGrid {
             ..Default::default()
         };
 
-        // Pre-compute cursor foreground color (black on cursor block)
-        let cursor_fg = Hsla {
-            h: 0.0,
-            s: 0.0,
-            l: 0.0,
-            a: 1.0,
-        };
         let highlight_fg = Hsla {
             h: 0.0,
             s: 0.0,
@@ -258,16 +452,52 @@ impl Element for TerminalGrid {
             let y = origin.y + self.cell_size.height * cell.row as f32;
 
             let fg_color = if cell.is_cursor && self.cursor_style == TerminalCursorStyle::Block {
-                cursor_fg
+                self.cursor_colors(cell).1
             } else if cell.selected {
                 self.selection_fg
-            } else if cell.search_current || cell.search_match {
+            } else if cell.search_current
+                || cell.search_match
+                || cell.diagnostic_current
+                || cell.diagnostic_match
+            {
                 highlight_fg
             } else {
                 cell.fg
             };
 
+            if self.builtin_box_drawing
+                && let Some(rects) = box_drawing::glyph_rects(
+                    cell.char,
+                    self.cell_size.width.into(),
+                    self.cell_size.height.into(),
+                )
+            {
+                for rect in rects {
+                    let mut color = fg_color;
+                    color.a *= rect.alpha;
+                    window.paint_quad(quad(
+                        Bounds::new(
+                            point(x + px(rect.x), y + px(rect.y)),
+                            Size {
+                                width: px(rect.width),
+                                height: px(rect.height),
+                            },
+                        ),
+                        px(0.0),
+                        color,
+                        gpui::Edges::default(),
+                        Hsla::transparent_black(),
+                        gpui::BorderStyle::default(),
+                    ));
+                }
+                continue;
+            }
+
             let text: SharedString = cell.char.to_string().into();
+            let in_link_range = self.hovered_link_range.is_some_and(|(row, start, end)| {
+                cell.row == row && cell.col >= start && cell.col <= end
+            });
+            let in_hyperlink = cell.hyperlink.is_some() && cell.hyperlink == self.hovered_hyperlink;
             let font = if cell.bold { &font_bold } else { &font_normal };
 
             let run = TextRun {
@@ -275,19 +505,11 @@ impl Element for TerminalGrid {
                 font: font.clone(),
                 color: fg_color,
                 background_color: None,
-                underline: self
-                    .hovered_link_range
-                    .and_then(|(row, start_col, end_col)| {
-                        if cell.row == row && cell.col >= start_col && cell.col <= end_col {
-                            Some(UnderlineStyle {
-                                thickness: px(1.0),
-                                color: Some(fg_color),
-                                wavy: false,
-                            })
-                        } else {
-                            None
-                        }
-                    }),
+                underline: (in_link_range || in_hyperlink).then_some(UnderlineStyle {
+                    thickness: px(1.0),
+                    color: Some(fg_color),
+                    wavy: false,
+                }),
                 strikethrough: None,
             };
 
@@ -303,5 +525,163 @@ impl Element for TerminalGrid {
                 cx,
             );
         }
+
+        // Mark spaces and tabs inside each row's text so indentation can be read.
+        if self.show_whitespace {
+            for (index, marker) in whitespace_markers(&self.cells, self.rows) {
+                let cell = &self.cells[index];
+                let mut color = if cell.selected {
+                    self.selection_fg
+                } else {
+                    cell.fg
+                };
+                color.a *= WHITESPACE_MARKER_ALPHA;
+
+                let text: SharedString = marker.to_string().into();
+                let run = TextRun {
+                    len: text.len(),
+                    font: font_normal.clone(),
+                    color,
+                    background_color: None,
+                    underline: None,
+                    strikethrough: None,
+                };
+                let line = window
+                    .text_system()
+                    .shape_line(text, self.font_size, &[run], None);
+                let _ = line.paint(
+                    point(
+                        origin.x + self.cell_size.width * cell.col as f32,
+                        origin.y + self.cell_size.height * cell.row as f32,
+                    ),
+                    self.cell_size.height,
+                    TextAlign::Left,
+                    None,
+                    window,
+                    cx,
+                );
+            }
+        }
+
+        // Mark soft-wrapped rows with a short bar on the right edge of their last cell.
+        if let Some(wrap_color) = self.wrap_indicator {
+            let cell_width: f32 = self.cell_size.width.into();
+            let cell_height: f32 = self.cell_size.height.into();
+            let marker_width = px((cell_width * 0.15).clamp(1.0, 2.0));
+            let marker_height = px(cell_height * 0.5);
+
+            for cell in self.cells.iter().filter(|cell| cell.wrapped) {
+                let x = origin.x + self.cell_size.width * (cell.col + 1) as f32 - marker_width;
+                let y = origin.y + self.cell_size.height * cell.row as f32 + px(cell_height * 0.25);
+
+                window.paint_quad(quad(
+                    Bounds::new(
+                        point(x, y),
+                        Size {
+                            width: marker_width,
+                            height: marker_height,
+                        },
+                    ),
+                    px(0.0),
+                    wrap_color,
+                    gpui::Edges::default(),
+                    Hsla::transparent_black(),
+                    gpui::BorderStyle::default(),
+                ));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cell(col: usize, row: usize, char: char) -> CellRenderInfo {
+        CellRenderInfo {
+            col,
+            row,
+            char,
+            fg: Hsla::white(),
+            bg: Hsla::black(),
+            bold: false,
+            render_text: true,
+            is_cursor: false,
+            selected: false,
+            search_current: false,
+            search_match: false,
+            diagnostic_current: false,
+            diagnostic_match: false,
+            wrapped: false,
+            hyperlink: None,
+        }
+    }
+
+    #[test]
+    fn row_content_ends_track_last_non_blank_cell_per_row() {
+        let cells: Vec<_> = "a b  "
+            .chars()
+            .enumerate()
+            .map(|(col, c)| cell(col, 0, c))
+            .chain("    ".chars().enumerate().map(|(col, c)| cell(col, 1, c)))
+            .chain([cell(3, 2, 'x'), cell(0, 2, '\0')])
+            .collect();
+
+        assert_eq!(row_content_ends(&cells, 3), vec![Some(2), None, Some(3)]);
+    }
+
+    #[test]
+    fn whitespace_markers_cover_inner_spaces_and_tabs_only() {
+        // `a\t` then blanks to column 8, `b c`, then trailing blanks.
+        let mut cells: Vec<_> = "a\t      b c   "
+            .chars()
+            .enumerate()
+            .map(|(col, c)| cell(col, 0, c))
+            .collect();
+        // A wide glyph's spacer and a hidden cell are stored as spaces too.
+        cells.extend([
+            cell(0, 1, '漢'),
+            cell(1, 1, ' '),
+            cell(2, 1, ' '),
+            cell(3, 1, 'x'),
+        ]);
+        cells[cells.len() - 3].render_text = false;
+        cells[cells.len() - 2].render_text = false;
+
+        assert_eq!(whitespace_markers(&cells, 2), vec![(1, '→'), (9, '·')]);
+    }
+
+    #[test]
+    fn whitespace_markers_skip_the_cursor_cell() {
+        let mut cells: Vec<_> = "a  b"
+            .chars()
+            .enumerate()
+            .map(|(col, c)| cell(col, 0, c))
+            .collect();
+        cells[1].is_cursor = true;
+
+        assert_eq!(whitespace_markers(&cells, 1), vec![(2, '·')]);
+    }
+
+    #[test]
+    fn contrast_cursor_keeps_visible_cursor_and_draws_glyph_in_cell_background() {
+        let cursor = Hsla::white();
+        let fg = Hsla::white();
+        let bg = Hsla::black();
+
+        let (cursor_color, glyph) = contrast_cursor_colors(cursor, fg, bg);
+        assert_eq!(cursor_color, cursor);
+        assert_eq!(glyph, bg);
+    }
+
+    #[test]
+    fn contrast_cursor_inverts_when_cursor_matches_cell_background() {
+        let cursor = Hsla::white();
+        let fg = Hsla::black();
+        let bg = Hsla::white();
+
+        let (cursor_color, glyph) = contrast_cursor_colors(cursor, fg, bg);
+        assert_eq!(cursor_color, fg);
+        assert_eq!(glyph, bg);
     }
 }
@@ -1,10 +1,17 @@
+mod box_drawing;
 mod grid;
 mod links;
 mod runtime;
+mod scrollback;
 
 pub use grid::{CellRenderInfo, TerminalCursorStyle, TerminalGrid};
-pub use links::{DetectedLink, classify_link_token, find_link_in_line};
+pub use links::{
+    CellHyperlink, DetectedLink, HyperlinkIds, HyperlinkTarget, LinkKind, UrlScheme,
+    cell_hyperlink, classify_link_token, find_all_links_in_line, find_link_in_line,
+    hyperlink_target, parse_file_location, word_bounds_in_line,
+};
 pub use runtime::{
     TabTitleShellIntegration, Terminal, TerminalEvent, TerminalRuntimeConfig, TerminalSize,
     WorkingDirFallback, keystroke_to_input,
 };
+pub use scrollback::ScrollbackFormat;
@@ -1,11 +1,238 @@
+use alacritty_terminal::term::cell::{Cell, Hyperlink};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DetectedLink {
     pub start_col: usize,
     pub end_col: usize,
     pub target: String,
+    pub kind: LinkKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkKind {
+    /// `target` is a URL to hand to the OS.
+    Url,
+    /// `target` is a file path from compiler-style output; `line` and `column`
+    /// are 1-based.
+    FileLocation { line: u32, column: Option<u32> },
+    /// `target` is the URI of an OSC 8 hyperlink; every cell carrying `id` is
+    /// part of the same link, across rows too.
+    Hyperlink { id: u64 },
+}
+
+/// An OSC 8 hyperlink attached to a cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CellHyperlink {
+    pub id: u64,
+    pub uri: String,
+}
+
+/// How a click may open an OSC 8 target. The URI comes from program output and
+/// hides behind whatever text the program printed, so only web and mail links
+/// open directly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HyperlinkTarget {
+    /// `http`, `https` or `mailto`.
+    Web,
+    /// A `file:` URI, which could launch an app and so needs a confirmation.
+    File,
+    /// Anything else, e.g. `javascript:`, `smb://` or an app's own scheme.
+    Blocked,
+}
+
+pub fn hyperlink_target(uri: &str) -> HyperlinkTarget {
+    if uri.chars().any(|c| c.is_control() || c.is_whitespace()) {
+        return HyperlinkTarget::Blocked;
+    }
+    let Some((scheme, _)) = uri.split_once(':') else {
+        return HyperlinkTarget::Blocked;
+    };
+    match scheme.to_ascii_lowercase().as_str() {
+        "http" | "https" | "mailto" => HyperlinkTarget::Web,
+        "file" => HyperlinkTarget::File,
+        _ => HyperlinkTarget::Blocked,
+    }
+}
+
+/// The OSC 8 hyperlink on `cell`, unless its target is [`HyperlinkTarget::Blocked`].
+/// The escape sequence's id and URI are hashed into one number, so cells of one
+/// link compare equal without string copies.
+pub fn cell_hyperlink(cell: &Cell) -> Option<CellHyperlink> {
+    let hyperlink = cell
+        .hyperlink()
+        .filter(|hyperlink| hyperlink_target(hyperlink.uri()) != HyperlinkTarget::Blocked)?;
+    Some(CellHyperlink {
+        id: hyperlink_id(hyperlink.id(), hyperlink.uri()),
+        uri: hyperlink.uri().to_string(),
+    })
+}
+
+/// Just the [`CellHyperlink::id`] of each cell, for per-frame rendering. A
+/// link's cells come in runs, so the id is only hashed again when a cell's
+/// link differs from the one before it.
+#[derive(Debug, Default)]
+pub struct HyperlinkIds {
+    last: Option<(Hyperlink, u64)>,
+}
+
+impl HyperlinkIds {
+    pub fn id(&mut self, cell: &Cell) -> Option<u64> {
+        let hyperlink = cell.hyperlink()?;
+        if let Some((last, id)) = &self.last
+            && *last == hyperlink
+        {
+            return Some(*id);
+        }
+        let id = hyperlink_id(hyperlink.id(), hyperlink.uri());
+        self.last = Some((hyperlink, id));
+        Some(id)
+    }
+}
+
+fn hyperlink_id(id: &str, uri: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    id.hash(&mut hasher);
+    uri.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Which text counts as a URL when detecting links.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UrlScheme {
+    /// Bare `www.`, domain and IPv4 tokens, email addresses and git remotes
+    /// count too.
+    #[default]
+    Optional,
+    /// Only tokens that spell out `http://` or `https://`.
+    Required,
+}
+
+/// Common source and config file extensions, which let a bare `main.rs:42` count
+/// as a file location without a `/` in it.
+const FILE_LOCATION_EXTENSIONS: &[&str] = &[
+    "c", "cc", "cjs", "cpp", "cs", "css", "cxx", "dart", "ex", "exs", "go", "h", "hpp", "hs",
+    "html", "java", "js", "json", "jsx", "kt", "kts", "lua", "m", "md", "mjs", "ml", "mm", "php",
+    "pl", "py", "rb", "rs", "scala", "scss", "sh", "sql", "svelte", "swift", "toml", "ts", "tsx",
+    "txt", "vue", "xml", "yaml", "yml", "zig",
+];
+
+/// Find the link covering `col`. `hyperlinks` holds each cell's OSC 8 link (it
+/// may be empty when none are known); an explicit link wins over anything
+/// detected in the text.
+pub fn find_link_in_line(
+    line: &[char],
+    hyperlinks: &[Option<CellHyperlink>],
+    col: usize,
+    url_scheme: UrlScheme,
+) -> Option<DetectedLink> {
+    if let Some(Some(hyperlink)) = hyperlinks.get(col) {
+        let same_link = |index: usize| {
+            hyperlinks
+                .get(index)
+                .and_then(Option::as_ref)
+                .is_some_and(|other| other.id == hyperlink.id)
+        };
+        let mut start = col;
+        while start > 0 && same_link(start - 1) {
+            start -= 1;
+        }
+        let mut end = col;
+        while same_link(end + 1) {
+            end += 1;
+        }
+        return Some(DetectedLink {
+            start_col: start,
+            end_col: end,
+            target: hyperlink.uri.clone(),
+            kind: LinkKind::Hyperlink { id: hyperlink.id },
+        });
+    }
+
+    find_all_links_in_line(line, url_scheme)
+        .into_iter()
+        .find(|link| link.start_col <= col && col <= link.end_col)
+}
+
+/// Every link detected in the text of a row, left to right. Columns count
+/// chars, matching a row with one char per cell.
+pub fn find_all_links_in_line(line: &[char], url_scheme: UrlScheme) -> Vec<DetectedLink> {
+    let mut links = Vec::new();
+    let mut col = 0;
+    while col < line.len() {
+        if line[col].is_whitespace() {
+            col += 1;
+            continue;
+        }
+
+        if let Some((start, end)) = word_bounds_in_line(line, col, true)
+            && let Some((target, kind)) = classify_token(&line[start..=end], url_scheme)
+        {
+            links.push(DetectedLink {
+                start_col: start,
+                end_col: end,
+                target,
+                kind,
+            });
+        }
+        while col < line.len() && !line[col].is_whitespace() {
+            col += 1;
+        }
+    }
+    links
+}
+
+fn classify_token(token: &[char], url_scheme: UrlScheme) -> Option<(String, LinkKind)> {
+    let token: String = token.iter().collect();
+    let token = token.trim_end_matches(':');
+
+    // Before URLs: `main.rs:42` would otherwise pass for a host and port.
+    if let Some((path, line, column)) = parse_file_location(token) {
+        return Some((path.to_string(), LinkKind::FileLocation { line, column }));
+    }
+    Some((classify_link_token(token, url_scheme)?, LinkKind::Url))
+}
+
+/// Split a compiler-style `path:line` or `path:line:col` token, e.g.
+/// `src/main.rs:42:10`. The path must look like one, containing a `/` or `\`
+/// or ending in a known extension, so times like `12:30:45` and `host:8080`
+/// are left alone.
+pub fn parse_file_location(token: &str) -> Option<(&str, u32, Option<u32>)> {
+    let (rest, last) = token.rsplit_once(':')?;
+    let last = parse_location_number(last)?;
+    let (path, line, column) = match rest
+        .rsplit_once(':')
+        .and_then(|(path, line)| Some((path, parse_location_number(line)?)))
+    {
+        Some((path, line)) => (path, line, Some(last)),
+        None => (rest, last, None),
+    };
+
+    if path.is_empty() || path.contains("://") {
+        return None;
+    }
+    let has_separator = path.contains('/') || path.contains('\\');
+    let has_known_extension = path.rsplit_once('.').is_some_and(|(stem, extension)| {
+        !stem.is_empty()
+            && FILE_LOCATION_EXTENSIONS
+                .iter()
+                .any(|known| extension.eq_ignore_ascii_case(known))
+    });
+    (has_separator || has_known_extension).then_some((path, line, column))
 }
 
-pub fn find_link_in_line(line: &[char], col: usize) -> Option<DetectedLink> {
+fn parse_location_number(value: &str) -> Option<u32> {
+    if value.is_empty() || !value.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    value.parse().ok().filter(|number| *number > 0)
+}
+
+/// Inclusive column range of the whitespace-delimited word at `col`. With `trim_edges`,
+/// surrounding quotes, brackets and punctuation are dropped, leaving the token link
+/// detection would look at.
+pub fn word_bounds_in_line(line: &[char], col: usize, trim_edges: bool) -> Option<(usize, usize)> {
     if col >= line.len() || line[col].is_whitespace() {
         return None;
     }
@@ -20,6 +247,10 @@ pub fn find_link_in_line(line: &[char], col: usize) -> Option<DetectedLink> {
         end += 1;
     }
 
+    if !trim_edges {
+        return Some((start, end));
+    }
+
     while start <= end && edge_trim_char(line[start]) {
         start += 1;
     }
@@ -34,17 +265,14 @@ pub fn find_link_in_line(line: &[char], col: usize) -> Option<DetectedLink> {
         return None;
     }
 
-    let token: String = line[start..=end].iter().collect();
-    let target = classify_link_token(token.trim_end_matches(':'))?;
-
-    Some(DetectedLink {
-        start_col: start,
-        end_col: end,
-        target,
-    })
+    Some((start, end))
 }
 
-pub fn classify_link_token(token: &str) -> Option<String> {
+/// Resolve `token` to an openable URL. With [`UrlScheme::Required`], only tokens that
+/// spell out `http://` or `https://` qualify; otherwise bare `www.`, domain and IPv4
+/// tokens are accepted too, as are email addresses (opened as `mailto:`) and SCP-style
+/// git remotes like `git@github.com:user/repo.git` (opened as their https page).
+pub fn classify_link_token(token: &str, url_scheme: UrlScheme) -> Option<String> {
     if token.is_empty() {
         return None;
     }
@@ -54,6 +282,23 @@ pub fn classify_link_token(token: &str) -> Option<String> {
         return Some(token.to_string());
     }
 
+    if url_scheme == UrlScheme::Required {
+        return None;
+    }
+
+    let email = if lower.starts_with("mailto:") {
+        &token["mailto:".len()..]
+    } else {
+        token
+    };
+    if is_email_address(email) {
+        return Some(format!("mailto:{}", email));
+    }
+
+    if let Some(url) = git_remote_to_https(token) {
+        return Some(url);
+    }
+
     if lower.starts_with("www.") {
         return Some(format!("https://{}", token));
     }
@@ -65,6 +310,54 @@ pub fn classify_link_token(token: &str) -> Option<String> {
     None
 }
 
+/// `local@example.com`, with a dotted domain whose last label is at least two
+/// letters, so `@mention`, `user@host` and `user@10.0.0.1` don't qualify.
+fn is_email_address(input: &str) -> bool {
+    let Some((local, domain)) = input.split_once('@') else {
+        return false;
+    };
+    if local.is_empty()
+        || !local
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '%' | '+' | '-'))
+    {
+        return false;
+    }
+
+    let has_tld = domain
+        .rsplit_once('.')
+        .is_some_and(|(_, tld)| tld.len() >= 2 && tld.chars().all(|c| c.is_ascii_alphabetic()));
+    has_tld && !domain.contains(['/', ':']) && looks_like_domain(domain)
+}
+
+/// `https://host/owner/repo` for an SCP-style remote like `git@host:owner/repo.git`.
+/// The path has to be relative with at least an owner and a repository, which
+/// keeps `user@server:/var/log` style logs out.
+fn git_remote_to_https(input: &str) -> Option<String> {
+    let (user, rest) = input.split_once('@')?;
+    let (host, path) = rest.split_once(':')?;
+    if user.is_empty()
+        || !user
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-'))
+        || !host.contains('.')
+        || !looks_like_domain(host)
+    {
+        return None;
+    }
+
+    let path = path.trim_end_matches('/');
+    let path = path.strip_suffix(".git").unwrap_or(path);
+    let valid = path.contains('/')
+        && path.split('/').all(|segment| {
+            !segment.is_empty()
+                && segment
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-'))
+        });
+    valid.then(|| format!("https://{}/{}", host, path))
+}
+
 fn edge_trim_char(c: char) -> bool {
     matches!(
         c,
@@ -156,3 +449,237 @@ fn looks_like_domain(input: &str) -> bool {
 
     true
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TerminalSize;
+    use alacritty_terminal::{
+        event::VoidListener,
+        grid::Dimensions,
+        index::{Column, Line},
+        term::{Config, Term},
+        vte::ansi::{Processor, StdSyncHandler},
+    };
+
+    #[test]
+    fn word_bounds_trim_surrounding_punctuation_only_when_asked() {
+        let line: Vec<char> = "see (\"src/main.rs\"), ok".chars().collect();
+        assert_eq!(word_bounds_in_line(&line, 8, false), Some((4, 19)));
+        assert_eq!(word_bounds_in_line(&line, 8, true), Some((6, 16)));
+        assert_eq!(word_bounds_in_line(&line, 3, true), None);
+    }
+
+    #[test]
+    fn finds_every_link_on_a_line_without_surrounding_punctuation() {
+        let line: Vec<char> =
+            "see https://termy.dev/docs, (www.example.org) or \"dev@termy.dev\". 12:30"
+                .chars()
+                .collect();
+        let links = find_all_links_in_line(&line, UrlScheme::Optional);
+        let found: Vec<_> = links
+            .iter()
+            .map(|link| (link.start_col, link.end_col, link.target.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (4, 25, "https://termy.dev/docs"),
+                (29, 43, "https://www.example.org"),
+                (50, 62, "mailto:dev@termy.dev"),
+            ]
+        );
+
+        let line: Vec<char> = "a.dev https://b.dev c.dev".chars().collect();
+        let with_scheme = find_all_links_in_line(&line, UrlScheme::Required);
+        assert_eq!(with_scheme.len(), 1);
+        assert_eq!(with_scheme[0].target, "https://b.dev");
+        assert!(find_all_links_in_line(&[' '; 3], UrlScheme::Optional).is_empty());
+    }
+
+    #[test]
+    fn find_link_picks_the_link_under_the_column() {
+        let line: Vec<char> = "(https://a.dev)".chars().collect();
+        let link = find_link_in_line(&line, &[], 3, UrlScheme::Optional).unwrap();
+        assert_eq!((link.start_col, link.end_col), (1, 13));
+        assert_eq!(find_link_in_line(&line, &[], 0, UrlScheme::Optional), None);
+
+        let line: Vec<char> = "https://a.dev, https://b.dev".chars().collect();
+        assert_eq!(
+            find_link_in_line(&line, &[], 20, UrlScheme::Optional)
+                .unwrap()
+                .target,
+            "https://b.dev"
+        );
+        assert_eq!(find_link_in_line(&line, &[], 14, UrlScheme::Optional), None);
+    }
+
+    #[test]
+    fn email_addresses_open_as_mailto_and_need_a_tld() {
+        assert_eq!(
+            classify_link_token("dev@termy.dev", UrlScheme::Optional),
+            Some("mailto:dev@termy.dev".to_string())
+        );
+        assert_eq!(
+            classify_link_token("mailto:first.last+tag@mail.example.co", UrlScheme::Optional),
+            Some("mailto:first.last+tag@mail.example.co".to_string())
+        );
+        assert_eq!(
+            classify_link_token("dev@termy.dev", UrlScheme::Required),
+            None
+        );
+        assert_eq!(classify_link_token("@mention", UrlScheme::Optional), None);
+        assert_eq!(
+            classify_link_token("root@localhost", UrlScheme::Optional),
+            None
+        );
+        assert_eq!(
+            classify_link_token("user@10.0.0.1", UrlScheme::Optional),
+            None
+        );
+        assert_eq!(
+            classify_link_token("user@host.x", UrlScheme::Optional),
+            None
+        );
+    }
+
+    #[test]
+    fn scp_style_git_remotes_open_their_https_page() {
+        assert_eq!(
+            classify_link_token("git@github.com:user/repo.git", UrlScheme::Optional),
+            Some("https://github.com/user/repo".to_string())
+        );
+        assert_eq!(
+            classify_link_token("git@gitlab.com:group/sub/project", UrlScheme::Optional),
+            Some("https://gitlab.com/group/sub/project".to_string())
+        );
+        assert_eq!(
+            classify_link_token("git@github.com:repo.git", UrlScheme::Optional),
+            None
+        );
+        assert_eq!(
+            classify_link_token("root@server.example.com:/var/log", UrlScheme::Optional),
+            None
+        );
+        assert_eq!(
+            classify_link_token("git@github.com:user/repo.git", UrlScheme::Required),
+            None
+        );
+
+        let line: Vec<char> = "origin  git@github.com:u/r.git (fetch)".chars().collect();
+        let link = find_link_in_line(&line, &[], 10, UrlScheme::Optional).unwrap();
+        assert_eq!(link.target, "https://github.com/u/r");
+        assert_eq!(link.kind, LinkKind::Url);
+    }
+
+    #[test]
+    fn file_locations_need_a_plausible_path() {
+        assert_eq!(
+            parse_file_location("src/main.rs:42:10"),
+            Some(("src/main.rs", 42, Some(10)))
+        );
+        assert_eq!(parse_file_location("main.rs:7"), Some(("main.rs", 7, None)));
+        assert_eq!(
+            parse_file_location("C:\\src\\lib.rs:3:1"),
+            Some(("C:\\src\\lib.rs", 3, Some(1)))
+        );
+        assert_eq!(parse_file_location("12:30:45"), None);
+        assert_eq!(parse_file_location("example.com:8080"), None);
+        assert_eq!(parse_file_location("http://localhost/a.rs:8080"), None);
+        assert_eq!(parse_file_location("src/main.rs:0"), None);
+        assert_eq!(parse_file_location("src/main.rs"), None);
+    }
+
+    #[test]
+    fn find_link_reports_file_locations_before_urls() {
+        let line: Vec<char> = "  --> src/main.rs:42:10: error".chars().collect();
+        let link = find_link_in_line(&line, &[], 8, UrlScheme::Optional).unwrap();
+        assert_eq!(link.target, "src/main.rs");
+        assert_eq!(
+            link.kind,
+            LinkKind::FileLocation {
+                line: 42,
+                column: Some(10)
+            }
+        );
+        assert_eq!((link.start_col, link.end_col), (6, 23));
+
+        let line: Vec<char> = "at 12:30:45 see example.com:8080".chars().collect();
+        assert_eq!(find_link_in_line(&line, &[], 4, UrlScheme::Optional), None);
+        let link = find_link_in_line(&line, &[], 20, UrlScheme::Optional).unwrap();
+        assert_eq!(link.kind, LinkKind::Url);
+        assert_eq!(link.target, "http://example.com:8080");
+    }
+
+    #[test]
+    fn osc8_hyperlinks_are_stored_on_cells_and_win_over_detection() {
+        let mut term = Term::new(Config::default(), &TerminalSize::default(), VoidListener);
+        let mut processor = Processor::<StdSyncHandler>::new();
+        processor.advance(
+            &mut term,
+            b"see \x1b]8;;https://example.com/docs\x1b\\click here\x1b]8;;\x1b\\ or www.termy.dev",
+        );
+
+        let grid = term.grid();
+        let row = &grid[Line(0)];
+        let hyperlinks: Vec<_> = (0..grid.columns())
+            .map(|col| cell_hyperlink(&row[Column(col)]))
+            .collect();
+        let line: Vec<char> = (0..grid.columns()).map(|col| row[Column(col)].c).collect();
+
+        let first = hyperlinks[4].clone().unwrap();
+        assert_eq!(first.uri, "https://example.com/docs");
+        assert_eq!(hyperlinks[13], Some(first.clone()));
+        let mut ids = HyperlinkIds::default();
+        assert_eq!(ids.id(&row[Column(8)]), Some(first.id));
+        assert_eq!(ids.id(&row[Column(9)]), Some(first.id));
+        assert_eq!(ids.id(&row[Column(14)]), None);
+        assert_eq!(hyperlinks[3], None);
+        assert_eq!(hyperlinks[14], None);
+
+        let link = find_link_in_line(&line, &hyperlinks, 9, UrlScheme::Optional).unwrap();
+        assert_eq!((link.start_col, link.end_col), (4, 13));
+        assert_eq!(link.target, "https://example.com/docs");
+        assert_eq!(link.kind, LinkKind::Hyperlink { id: first.id });
+
+        let link = find_link_in_line(&line, &hyperlinks, 20, UrlScheme::Optional).unwrap();
+        assert_eq!(link.kind, LinkKind::Url);
+        assert_eq!(link.target, "https://www.termy.dev");
+    }
+
+    #[test]
+    fn osc8_hyperlinks_outside_the_web_are_confirmed_or_dropped() {
+        assert_eq!(hyperlink_target("HTTPS://termy.dev"), HyperlinkTarget::Web);
+        assert_eq!(
+            hyperlink_target("mailto:dev@termy.dev"),
+            HyperlinkTarget::Web
+        );
+        assert_eq!(
+            hyperlink_target("file:///Applications/Calculator.app"),
+            HyperlinkTarget::File
+        );
+        assert_eq!(
+            hyperlink_target("javascript:alert(1)"),
+            HyperlinkTarget::Blocked
+        );
+        assert_eq!(
+            hyperlink_target("smb://host/share"),
+            HyperlinkTarget::Blocked
+        );
+        assert_eq!(
+            hyperlink_target("https://a.dev/&calc.exe x"),
+            HyperlinkTarget::Blocked
+        );
+
+        let mut term = Term::new(Config::default(), &TerminalSize::default(), VoidListener);
+        let mut processor = Processor::<StdSyncHandler>::new();
+        processor.advance(
+            &mut term,
+            b"\x1b]8;;javascript:alert(1)\x1b\\click\x1b]8;;\x1b\\ \x1b]8;;file:///etc/passwd\x1b\\here\x1b]8;;\x1b\\",
+        );
+        let row = &term.grid()[Line(0)];
+        assert_eq!(cell_hyperlink(&row[Column(2)]), None);
+        let file = cell_hyperlink(&row[Column(7)]).unwrap();
+        assert_eq!(hyperlink_target(&file.uri), HyperlinkTarget::File);
+    }
+}
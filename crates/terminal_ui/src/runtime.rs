@@ -1,10 +1,17 @@
+use crate::grid::TerminalCursorStyle;
+use crate::scrollback::{self, ScrollbackFormat};
 use alacritty_terminal::{
     event::{Event as AlacEvent, EventListener, WindowSize},
     event_loop::{EventLoop, Msg, Notifier},
-    grid::{Dimensions, Scroll},
+    grid::{Dimensions, Grid, Row, Scroll},
+    index::{Column, Line},
     sync::FairMutex,
-    term::{Config as TermConfig, Term, TermMode},
+    term::{
+        Config as TermConfig, Term, TermMode,
+        cell::{Cell, Flags},
+    },
     tty::{self, Options as PtyOptions, Shell},
+    vte::ansi::{Color, CursorShape, CursorStyle, Processor, Rgb, StdSyncHandler},
 };
 use flume::{Receiver, Sender, unbounded};
 use gpui::{Keystroke, Pixels, px};
@@ -13,9 +20,10 @@ use std::path::Path;
 use std::{
     collections::HashMap,
     env,
+    io::{self, Write},
     path::PathBuf,
     sync::{
-        Arc,
+        Arc, Mutex,
         atomic::{AtomicBool, Ordering},
     },
 };
@@ -29,6 +37,33 @@ pub struct TabTitleShellIntegration {
 const DEFAULT_TERM: &str = "xterm-256color";
 const DEFAULT_COLORTERM: &str = "truecolor";
 
+// DECSCUSR can only request block, underline or beam shapes, so a hollow block
+// default means no program has set the cursor shape.
+const UNSET_CURSOR_STYLE: CursorStyle = CursorStyle {
+    shape: CursorShape::HollowBlock,
+    blinking: false,
+};
+
+fn app_cursor_shape(style: CursorStyle) -> Option<TerminalCursorStyle> {
+    match style.shape {
+        CursorShape::Block => Some(TerminalCursorStyle::Block),
+        CursorShape::Underline => Some(TerminalCursorStyle::Underline),
+        CursorShape::Beam => Some(TerminalCursorStyle::Line),
+        CursorShape::HollowBlock | CursorShape::Hidden => None,
+    }
+}
+
+fn app_cursor_blinking(style: CursorStyle) -> Option<bool> {
+    app_cursor_shape(style).map(|_| style.blinking)
+}
+
+fn term_config(scrollback_history: usize) -> TermConfig {
+    let mut config = TermConfig::default();
+    config.scrolling_history = scrollback_history;
+    config.default_cursor_style = UNSET_CURSOR_STYLE;
+    config
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum WorkingDirFallback {
     Home,
@@ -58,6 +93,10 @@ pub struct TerminalRuntimeConfig {
     pub colorterm: Option<String>,
     pub working_dir_fallback: WorkingDirFallback,
     pub scrollback_history: usize,
+    /// Typed into the shell right after spawn, followed by Enter.
+    pub startup_command: Option<String>,
+    /// Leave XON/XOFF flow control (Ctrl-S/Ctrl-Q) enabled on the PTY.
+    pub flow_control: bool,
 }
 
 impl Default for TerminalRuntimeConfig {
@@ -68,6 +107,8 @@ impl Default for TerminalRuntimeConfig {
             colorterm: Some(DEFAULT_COLORTERM.to_string()),
             working_dir_fallback: WorkingDirFallback::default(),
             scrollback_history: DEFAULT_SCROLLBACK_HISTORY,
+            startup_command: None,
+            flow_control: true,
         }
     }
 }
@@ -94,6 +135,19 @@ fn quote_shell_program_if_needed(shell_path: &str) -> String {
     format!("\"{}\"", escaped)
 }
 
+/// Bytes typed for a startup command: the text as-is with every line ending
+/// normalized to `\r` (what Enter sends), plus a final `\r` if missing.
+fn startup_command_input(command: &str) -> Option<Vec<u8>> {
+    let command = command.trim_end_matches(['\r', '\n']);
+    if command.trim().is_empty() {
+        return None;
+    }
+
+    let mut input = command.replace("\r\n", "\r").replace('\n', "\r");
+    input.push('\r');
+    Some(input.into_bytes())
+}
+
 fn login_shell_args(shell_path: &str) -> Vec<String> {
     #[cfg(target_os = "windows")]
     {
@@ -277,9 +331,9 @@ fn default_working_directory_with_fallback(fallback: WorkingDirFallback) -> Opti
 pub enum TerminalEvent {
     /// Terminal content has changed, needs redraw
     Wakeup,
-    /// Terminal title changed
-    #[allow(dead_code)]
-    Title(String),
+    /// Terminal title changed. `cursor_line` is the absolute line of the cursor
+    /// when the change was drained, in [`Terminal::screen_top_line`] numbering.
+    Title { title: String, cursor_line: u64 },
     /// Terminal title reset
     ResetTitle,
     /// Bell character received
@@ -332,6 +386,63 @@ impl EventListener for JsonEventListener {
     }
 }
 
+/// Numbers lines across scrollback eviction and clears. The grid only indexes lines
+/// relative to the screen, so once history is full an index no longer tells how
+/// much output came before a line.
+#[derive(Default)]
+struct LineOrigin {
+    /// Absolute number of the oldest scrollback line.
+    oldest: u64,
+    history_size: usize,
+    /// Newest scrollback line at the last sync. Finding it again tells how far the
+    /// grid scrolled even when eviction kept `history_size` unchanged.
+    anchor: Option<Row<Cell>>,
+    /// Anchors stamped so far; each stamp is unique among the lines in history.
+    stamps: u32,
+}
+
+impl LineOrigin {
+    /// Catch up with `grid` and return the absolute number of its top screen line.
+    fn sync(&mut self, grid: &mut Grid<Cell>) -> u64 {
+        let history_size = grid.history_size();
+        let scrolled = match &self.anchor {
+            // History was empty, so nothing could have been evicted yet.
+            None => history_size.saturating_sub(self.history_size),
+            // Cleared or shrunk: the dropped lines are gone, the screen stays put.
+            Some(_) if history_size < self.history_size => 0,
+            Some(anchor) => {
+                let nearest = history_size - self.history_size + 1;
+                (nearest..=history_size)
+                    .find(|&distance| grid[Line(-(distance as i32))] == *anchor)
+                    .map_or(history_size, |distance| distance - 1)
+            }
+        };
+        self.oldest = self.oldest + (self.history_size + scrolled) as u64 - history_size as u64;
+        self.history_size = history_size;
+        self.anchor = (history_size > 0).then(|| {
+            let row = &mut grid[Line(-1)];
+            self.stamp(row);
+            row.clone()
+        });
+        self.oldest + history_size as u64
+    }
+
+    /// Tags `row` so it can't be mistaken for an identical line, such as one of
+    /// many blank lines. The tag is an underline color on a cell that draws no
+    /// underline, which changes nothing on screen. A row that is underlined from
+    /// end to end stays untagged and is matched by its text alone.
+    fn stamp(&mut self, row: &mut Row<Cell>) {
+        let Some(column) =
+            (0..row.len()).find(|&col| !row[Column(col)].flags.intersects(Flags::ALL_UNDERLINES))
+        else {
+            return;
+        };
+        self.stamps = self.stamps.wrapping_add(1);
+        let [_, r, g, b] = self.stamps.to_be_bytes();
+        row[Column(column)].set_underline_color(Some(Color::Spec(Rgb { r, g, b })));
+    }
+}
+
 /// Terminal dimensions in cells and pixels
 #[derive(Debug, Clone, Copy)]
 pub struct TerminalSize {
@@ -404,6 +515,11 @@ pub struct Terminal {
     size: TerminalSize,
     /// Tracks whether a wakeup event is already queued.
     wakeup_queued: Arc<AtomicBool>,
+    /// Absolute line numbering, caught up whenever events are drained.
+    line_origin: Mutex<LineOrigin>,
+    /// Duplicate of the PTY master, kept to inspect the line discipline.
+    #[cfg(unix)]
+    pty_file: Option<std::fs::File>,
 }
 
 impl Terminal {
@@ -450,8 +566,7 @@ impl Terminal {
         };
 
         // Create terminal config with configurable scrollback history
-        let mut term_config = TermConfig::default();
-        term_config.scrolling_history = runtime_config.scrollback_history;
+        let term_config = term_config(runtime_config.scrollback_history);
 
         // Create the terminal emulator
         let listener =
@@ -462,18 +577,39 @@ impl Terminal {
         // Create PTY
         let window_id = 0;
         let pty = tty::new(&pty_options, size.into(), window_id)?;
+        #[cfg(unix)]
+        let pty_file = pty.file().try_clone().ok();
+        #[cfg(unix)]
+        if !runtime_config.flow_control
+            && let Some(file) = &pty_file
+        {
+            disable_flow_control(file);
+        }
 
         // Create and spawn the event loop
         let event_loop = EventLoop::new(term.clone(), listener, pty, false, false)?;
         let pty_tx = Notifier(event_loop.channel());
         let _io_thread = event_loop.spawn();
 
+        // The kernel buffers this until the shell starts reading, so it runs as soon
+        // as the first prompt is up without having to detect readiness.
+        if let Some(input) = runtime_config
+            .startup_command
+            .as_deref()
+            .and_then(startup_command_input)
+        {
+            let _ = pty_tx.0.send(Msg::Input(input.into()));
+        }
+
         Ok(Self {
             term,
             pty_tx,
             events_rx,
             size,
             wakeup_queued,
+            line_origin: Mutex::new(LineOrigin::default()),
+            #[cfg(unix)]
+            pty_file,
         })
     }
 
@@ -488,6 +624,14 @@ impl Terminal {
         self.write(input.as_bytes());
     }
 
+    /// Feed bytes straight into the emulator as if the child had printed them.
+    /// Used for local status lines once the PTY is gone.
+    pub fn write_output(&self, output: &[u8]) {
+        let mut processor = Processor::<StdSyncHandler>::new();
+        let mut term = self.term.lock();
+        processor.advance(&mut *term, output);
+    }
+
     /// Resize the terminal
     pub fn resize(&mut self, new_size: TerminalSize) {
         self.size = new_size;
@@ -507,9 +651,20 @@ impl Terminal {
             match event {
                 AlacEvent::Wakeup => {
                     self.wakeup_queued.store(false, Ordering::Release);
+                    self.screen_top_line();
                     events.push(TerminalEvent::Wakeup);
                 }
-                AlacEvent::Title(title) => events.push(TerminalEvent::Title(title)),
+                AlacEvent::Title(title) => {
+                    // The emulator reports titles while it holds the terminal lock, so
+                    // the draining wakeup is the earliest point the cursor can be read.
+                    let mut term = self.term.lock();
+                    let cursor_line = self.sync_line_origin(&mut term) as i64
+                        + i64::from(term.grid().cursor.point.line.0);
+                    events.push(TerminalEvent::Title {
+                        title,
+                        cursor_line: cursor_line.max(0) as u64,
+                    });
+                }
                 AlacEvent::ResetTitle => events.push(TerminalEvent::ResetTitle),
                 AlacEvent::Bell => events.push(TerminalEvent::Bell),
                 AlacEvent::Exit => events.push(TerminalEvent::Exit),
@@ -522,12 +677,73 @@ impl Terminal {
         events
     }
 
+    /// Absolute number of the top screen line (grid `Line(0)`). Lines are numbered in
+    /// output order, so a line keeps its number while it scrolls through history and
+    /// numbers keep growing once history is full. Lines dropped by a clear are skipped.
+    pub fn screen_top_line(&self) -> u64 {
+        let mut term = self.term.lock();
+        self.sync_line_origin(&mut term)
+    }
+
+    fn sync_line_origin(&self, term: &mut Term<JsonEventListener>) -> u64 {
+        let mut line_origin = self.line_origin.lock().expect("Line origin lock poisoned");
+        // The alternate screen has no history; keep numbering the primary grid.
+        if term.mode().contains(TermMode::ALT_SCREEN) {
+            return line_origin.oldest + line_origin.history_size as u64;
+        }
+        line_origin.sync(term.grid_mut())
+    }
+
     /// Access the terminal for reading cell content
     pub fn with_term<R>(&self, f: impl FnOnce(&Term<JsonEventListener>) -> R) -> R {
         let term = self.term.lock();
         f(&term)
     }
 
+    /// Like [`Self::with_term`], also passing [`Self::screen_top_line`] as of the same
+    /// lock so line numbers and grid contents agree.
+    pub fn with_term_at_screen_top<R>(
+        &self,
+        f: impl FnOnce(&Term<JsonEventListener>, u64) -> R,
+    ) -> R {
+        let mut term = self.term.lock();
+        let screen_top_line = self.sync_line_origin(&mut term);
+        f(&term, screen_top_line)
+    }
+
+    /// Write the full scrollback and screen contents to `writer`, line by line.
+    pub fn write_scrollback(
+        &self,
+        writer: &mut impl Write,
+        format: ScrollbackFormat,
+    ) -> io::Result<()> {
+        let term = self.term.lock();
+        scrollback::write_grid(term.grid(), writer, format)
+    }
+
+    /// Plain text of the visible viewport, one line per row with trailing blanks
+    /// trimmed and soft-wrapped rows joined.
+    pub fn viewport_text(&self) -> String {
+        let term = self.term.lock();
+        let mut buffer = Vec::new();
+        let _ = scrollback::write_viewport(term.grid(), &mut buffer, ScrollbackFormat::PlainText);
+        Self::clipboard_text(buffer)
+    }
+
+    /// Plain text of the whole scrollback and screen, formatted like [`Self::viewport_text`].
+    pub fn scrollback_text(&self) -> String {
+        let mut buffer = Vec::new();
+        let _ = self.write_scrollback(&mut buffer, ScrollbackFormat::PlainText);
+        Self::clipboard_text(buffer)
+    }
+
+    fn clipboard_text(buffer: Vec<u8>) -> String {
+        let mut text = String::from_utf8(buffer).unwrap_or_default();
+        let trimmed_len = text.trim_end_matches('\n').len();
+        text.truncate(trimmed_len);
+        text
+    }
+
     /// Scroll the displayed viewport through scrollback history.
     /// Positive deltas move up into history, negative deltas move down toward live output.
     pub fn scroll_display(&self, delta_lines: i32) -> bool {
@@ -555,6 +771,18 @@ impl Terminal {
         (cursor.column.0, cursor.line.0 as usize)
     }
 
+    /// Cursor shape the running program asked for with DECSCUSR (`CSI n q`), or
+    /// `None` while the configured style applies. `CSI 0 q` and RIS reset it.
+    pub fn app_cursor_shape(&self) -> Option<TerminalCursorStyle> {
+        app_cursor_shape(self.term.lock().cursor_style())
+    }
+
+    /// Whether the cursor style the running program requested with DECSCUSR blinks
+    /// (odd `n`) or is steady (even `n`); `None` when it hasn't requested one.
+    pub fn app_cursor_blinking(&self) -> Option<bool> {
+        app_cursor_blinking(self.term.lock().cursor_style())
+    }
+
     /// Check if there are pending events
     #[allow(dead_code)]
     pub fn has_pending_events(&self) -> bool {
@@ -568,9 +796,14 @@ impl Terminal {
         // Create a new config with the updated scrollback history
         // We use default values for other config options since they don't
         // typically change at runtime
-        let mut config = TermConfig::default();
-        config.scrolling_history = history_size;
-        term.set_options(config);
+        term.set_options(term_config(history_size));
+    }
+
+    /// Whether the application asked for cursor keys in application mode (DECCKM),
+    /// so arrows are sent as `ESC O` sequences.
+    pub fn app_cursor_mode(&self) -> bool {
+        let term = self.term.lock();
+        term.mode().contains(TermMode::APP_CURSOR)
     }
 
     /// Check if bracketed paste mode is enabled
@@ -579,6 +812,39 @@ impl Terminal {
         term.mode().contains(TermMode::BRACKETED_PASTE)
     }
 
+    /// Whether the PTY currently honors XON/XOFF, so Ctrl-S pauses output.
+    /// Full-screen apps usually turn this off while they run.
+    pub fn flow_control_enabled(&self) -> bool {
+        #[cfg(unix)]
+        {
+            self.pty_file
+                .as_ref()
+                .and_then(pty_termios)
+                .is_some_and(|termios| termios.c_iflag & libc::IXON != 0)
+        }
+
+        #[cfg(not(unix))]
+        {
+            false
+        }
+    }
+
+    /// Whether any input, not just Ctrl-Q, resumes output stopped by Ctrl-S (IXANY).
+    pub fn flow_control_resumes_on_any_input(&self) -> bool {
+        #[cfg(unix)]
+        {
+            self.pty_file
+                .as_ref()
+                .and_then(pty_termios)
+                .is_some_and(|termios| termios.c_iflag & libc::IXANY != 0)
+        }
+
+        #[cfg(not(unix))]
+        {
+            false
+        }
+    }
+
     /// Check if the terminal is currently in alternate screen mode
     pub fn alternate_screen_mode(&self) -> bool {
         let term = self.term.lock();
@@ -587,10 +853,17 @@ impl Terminal {
 }
 
 /// Convert a GPUI keystroke into bytes for the terminal PTY.
-pub fn keystroke_to_input(keystroke: &Keystroke) -> Option<Vec<u8>> {
+///
+/// With `option_as_meta`, Alt/Option chords send ESC followed by the key's plain input
+/// (Option-b sends `ESC b`) instead of the character the platform composed.
+pub fn keystroke_to_input(keystroke: &Keystroke, option_as_meta: bool) -> Option<Vec<u8>> {
     let key = keystroke.key.as_str();
     let modifiers = keystroke.modifiers;
 
+    if option_as_meta && modifiers.alt && !modifiers.platform && !modifiers.function {
+        return meta_keystroke_input(keystroke);
+    }
+
     // Handle special keys
     let input = match key {
         "enter" => Some(vec![b'\r']),
@@ -650,11 +923,193 @@ pub fn keystroke_to_input(keystroke: &Keystroke) -> Option<Vec<u8>> {
     None
 }
 
+#[cfg(unix)]
+fn pty_termios(file: &std::fs::File) -> Option<libc::termios> {
+    use std::os::fd::AsRawFd;
+
+    let mut termios = std::mem::MaybeUninit::<libc::termios>::uninit();
+    // SAFETY: the descriptor stays open for the duration of the call and tcgetattr
+    // fully initializes `termios` when it succeeds.
+    unsafe {
+        if libc::tcgetattr(file.as_raw_fd(), termios.as_mut_ptr()) != 0 {
+            return None;
+        }
+        Some(termios.assume_init())
+    }
+}
+
+#[cfg(unix)]
+fn disable_flow_control(file: &std::fs::File) {
+    use std::os::fd::AsRawFd;
+
+    let Some(mut termios) = pty_termios(file) else {
+        return;
+    };
+    termios.c_iflag &= !(libc::IXON | libc::IXOFF);
+    // SAFETY: `termios` was read from the same open descriptor.
+    unsafe {
+        libc::tcsetattr(file.as_raw_fd(), libc::TCSANOW, &termios);
+    }
+}
+
+fn meta_keystroke_input(keystroke: &Keystroke) -> Option<Vec<u8>> {
+    let mut base = keystroke.clone();
+    base.modifiers.alt = false;
+    // key_char holds the Option-composed character, so rebuild the text from the key.
+    base.key_char = None;
+    if base.modifiers.shift && !base.modifiers.control && base.key.chars().count() == 1 {
+        base.key_char = Some(base.key.to_uppercase());
+    }
+
+    let input = keystroke_to_input(&base, false)?;
+    // Cursor and editing keys already send escape sequences; leave those unchanged.
+    if input.len() > 1 && input[0] == 0x1b {
+        return Some(input);
+    }
+
+    let mut meta_input = Vec::with_capacity(input.len() + 1);
+    meta_input.push(0x1b);
+    meta_input.extend(input);
+    Some(meta_input)
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{DEFAULT_TERM, TerminalRuntimeConfig, pty_env_overrides, resolve_shell_path};
     #[cfg(target_os = "windows")]
     use super::quote_shell_program_if_needed;
+    use super::{
+        DEFAULT_TERM, LineOrigin, TerminalRuntimeConfig, TerminalSize, app_cursor_blinking,
+        app_cursor_shape, keystroke_to_input, pty_env_overrides, resolve_shell_path,
+        startup_command_input, term_config,
+    };
+    use crate::grid::TerminalCursorStyle;
+    use alacritty_terminal::{
+        event::VoidListener,
+        term::Term,
+        vte::ansi::{Processor, StdSyncHandler},
+    };
+    use gpui::{Keystroke, Modifiers};
+
+    #[test]
+    fn decscusr_overrides_cursor_shape_until_reset() {
+        let mut term = Term::new(term_config(100), &TerminalSize::default(), VoidListener);
+        let mut processor = Processor::<StdSyncHandler>::new();
+        let mut shape_after = |bytes: &[u8]| {
+            processor.advance(&mut term, bytes);
+            app_cursor_shape(term.cursor_style())
+        };
+
+        assert_eq!(shape_after(b""), None);
+        assert_eq!(
+            shape_after(b"\x1b[4 q"),
+            Some(TerminalCursorStyle::Underline)
+        );
+        assert_eq!(shape_after(b"\x1b[6 q"), Some(TerminalCursorStyle::Line));
+        assert_eq!(shape_after(b"\x1b[0 q"), None);
+        assert_eq!(shape_after(b"\x1b[2 q"), Some(TerminalCursorStyle::Block));
+        assert_eq!(shape_after(b"\x1bc"), None);
+    }
+
+    #[test]
+    fn line_origin_keeps_counting_once_history_is_full() {
+        let size = TerminalSize {
+            rows: 2,
+            ..TerminalSize::default()
+        };
+        let mut term = Term::new(term_config(3), &size, VoidListener);
+        let mut processor = Processor::<StdSyncHandler>::new();
+        let mut line_origin = LineOrigin::default();
+
+        processor.advance(&mut term, b"0");
+        for line in 1..6 {
+            processor.advance(&mut term, format!("\r\n{}", line).as_bytes());
+            assert_eq!(line_origin.sync(term.grid_mut()), line - 1);
+        }
+
+        // Several lines between syncs, all while history stays at its cap.
+        processor.advance(&mut term, b"\r\n6\r\n7");
+        assert_eq!(line_origin.sync(term.grid_mut()), 6);
+
+        // Clearing scrollback drops lines without renumbering the screen.
+        processor.advance(&mut term, b"\x1b[3J");
+        assert_eq!(line_origin.sync(term.grid_mut()), 6);
+        processor.advance(&mut term, b"\r\n8");
+        assert_eq!(line_origin.sync(term.grid_mut()), 7);
+    }
+
+    #[test]
+    fn line_origin_counts_identical_blank_lines_once_history_is_full() {
+        let size = TerminalSize {
+            rows: 2,
+            ..TerminalSize::default()
+        };
+        let mut term = Term::new(term_config(3), &size, VoidListener);
+        let mut processor = Processor::<StdSyncHandler>::new();
+        let mut line_origin = LineOrigin::default();
+
+        for line in 1..8 {
+            processor.advance(&mut term, b"\r\n");
+            assert_eq!(line_origin.sync(term.grid_mut()), line - 1);
+        }
+        processor.advance(&mut term, b"\r\n\r\n\r\n");
+        assert_eq!(line_origin.sync(term.grid_mut()), 9);
+    }
+
+    #[test]
+    fn decscusr_odd_values_blink_and_even_values_are_steady() {
+        let mut term = Term::new(term_config(100), &TerminalSize::default(), VoidListener);
+        let mut processor = Processor::<StdSyncHandler>::new();
+        let mut blinking_after = |bytes: &[u8]| {
+            processor.advance(&mut term, bytes);
+            app_cursor_blinking(term.cursor_style())
+        };
+
+        assert_eq!(blinking_after(b""), None);
+        assert_eq!(blinking_after(b"\x1b[1 q"), Some(true));
+        assert_eq!(blinking_after(b"\x1b[2 q"), Some(false));
+        assert_eq!(blinking_after(b"\x1b[5 q"), Some(true));
+        assert_eq!(blinking_after(b"\x1bc"), None);
+    }
+
+    fn option_keystroke(key: &str, key_char: &str, shift: bool) -> Keystroke {
+        Keystroke {
+            modifiers: Modifiers {
+                alt: true,
+                shift,
+                ..Modifiers::default()
+            },
+            key: key.to_string(),
+            key_char: Some(key_char.to_string()),
+        }
+    }
+
+    #[test]
+    fn option_sends_composed_character_unless_meta() {
+        let keystroke = option_keystroke("b", "\u{222B}", false);
+        assert_eq!(
+            keystroke_to_input(&keystroke, false),
+            Some("\u{222B}".as_bytes().to_vec())
+        );
+        assert_eq!(
+            keystroke_to_input(&keystroke, true),
+            Some(b"\x1bb".to_vec())
+        );
+
+        let shifted = option_keystroke("b", "\u{0131}", true);
+        assert_eq!(keystroke_to_input(&shifted, true), Some(b"\x1bB".to_vec()));
+    }
+
+    #[test]
+    fn option_as_meta_prefixes_editing_keys_but_not_cursor_keys() {
+        let backspace = option_keystroke("backspace", "", false);
+        assert_eq!(
+            keystroke_to_input(&backspace, true),
+            Some(b"\x1b\x7f".to_vec())
+        );
+
+        let left = option_keystroke("left", "", false);
+        assert_eq!(keystroke_to_input(&left, true), Some(b"\x1b[D".to_vec()));
+    }
 
     #[test]
     fn env_overrides_set_term_by_default() {
@@ -672,6 +1127,19 @@ mod tests {
         assert!(!env.contains_key("COLORTERM"));
     }
 
+    #[test]
+    fn startup_command_is_submitted_with_enter() {
+        assert_eq!(
+            startup_command_input("tmux attach || tmux"),
+            Some(b"tmux attach || tmux\r".to_vec())
+        );
+        assert_eq!(
+            startup_command_input("echo hi\r\nclear\n"),
+            Some(b"echo hi\rclear\r".to_vec())
+        );
+        assert_eq!(startup_command_input("  \n"), None);
+    }
+
     #[test]
     fn explicit_shell_path_wins() {
         assert_eq!(resolve_shell_path(Some("/bin/custom")), "/bin/custom");
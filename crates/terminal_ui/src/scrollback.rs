@@ -0,0 +1,270 @@
+use alacritty_terminal::{
+    grid::{Dimensions, Grid, Row},
+    index::{Column, Line},
+    term::cell::{Cell, Flags},
+    vte::ansi::{Color, NamedColor},
+};
+use std::io::{self, Write};
+
+/// Output encoding for a scrollback dump.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ScrollbackFormat {
+    #[default]
+    PlainText,
+    /// Plain text with SGR escape sequences preserving colors and text attributes
+    Ansi,
+}
+
+const SGR_RESET: &str = "\x1b[0m";
+
+// Attributes that change how a cell is encoded as SGR.
+const SGR_FLAGS: Flags = Flags::BOLD
+    .union(Flags::DIM)
+    .union(Flags::ITALIC)
+    .union(Flags::ALL_UNDERLINES)
+    .union(Flags::INVERSE)
+    .union(Flags::HIDDEN)
+    .union(Flags::STRIKEOUT);
+
+#[derive(Clone, Copy, PartialEq)]
+struct CellStyle {
+    fg: Color,
+    bg: Color,
+    flags: Flags,
+}
+
+impl CellStyle {
+    const DEFAULT: Self = Self {
+        fg: Color::Named(NamedColor::Foreground),
+        bg: Color::Named(NamedColor::Background),
+        flags: Flags::empty(),
+    };
+
+    fn of(cell: &Cell) -> Self {
+        Self {
+            fg: cell.fg,
+            bg: cell.bg,
+            flags: cell.flags & SGR_FLAGS,
+        }
+    }
+
+    fn write_sgr(&self, out: &mut String) {
+        out.push_str("\x1b[0");
+        for (flag, code) in [
+            (Flags::BOLD, "1"),
+            (Flags::DIM, "2"),
+            (Flags::ITALIC, "3"),
+            (Flags::INVERSE, "7"),
+            (Flags::HIDDEN, "8"),
+            (Flags::STRIKEOUT, "9"),
+        ] {
+            if self.flags.contains(flag) {
+                out.push(';');
+                out.push_str(code);
+            }
+        }
+        if self.flags.intersects(Flags::ALL_UNDERLINES) {
+            out.push_str(";4");
+        }
+        push_color_sgr(out, self.fg, false);
+        push_color_sgr(out, self.bg, true);
+        out.push('m');
+    }
+}
+
+fn push_color_sgr(out: &mut String, color: Color, background: bool) {
+    let (base, bright_base, extended) = if background {
+        (40, 100, 48)
+    } else {
+        (30, 90, 38)
+    };
+
+    match color {
+        Color::Named(named) => {
+            let index = match named {
+                NamedColor::Foreground | NamedColor::Background => return,
+                NamedColor::DimBlack => 0,
+                NamedColor::DimRed => 1,
+                NamedColor::DimGreen => 2,
+                NamedColor::DimYellow => 3,
+                NamedColor::DimBlue => 4,
+                NamedColor::DimMagenta => 5,
+                NamedColor::DimCyan => 6,
+                NamedColor::DimWhite => 7,
+                named if (named as usize) < 16 => named as usize,
+                _ => return,
+            };
+            let code = if index < 8 {
+                base + index
+            } else {
+                bright_base + index - 8
+            };
+            out.push_str(&format!(";{}", code));
+        }
+        Color::Indexed(index) => out.push_str(&format!(";{};5;{}", extended, index)),
+        Color::Spec(rgb) => out.push_str(&format!(";{};2;{};{};{}", extended, rgb.r, rgb.g, rgb.b)),
+    }
+}
+
+fn cell_is_blank(cell: &Cell) -> bool {
+    (cell.c == ' ' || cell.c == '\0')
+        && cell.bg == Color::Named(NamedColor::Background)
+        && !cell.flags.contains(Flags::INVERSE)
+}
+
+/// Encode one grid row, without a line terminator. Trailing blank cells are dropped
+/// unless the row soft-wraps, where they are part of the logical line.
+fn encode_row(
+    row: &Row<Cell>,
+    columns: usize,
+    wraps: bool,
+    format: ScrollbackFormat,
+    out: &mut String,
+) {
+    let last = if wraps {
+        columns.checked_sub(1)
+    } else {
+        (0..columns)
+            .rev()
+            .find(|&col| !cell_is_blank(&row[Column(col)]))
+    };
+    let Some(last) = last else {
+        return;
+    };
+
+    let mut style = CellStyle::DEFAULT;
+    for col in 0..=last {
+        let cell = &row[Column(col)];
+        if cell
+            .flags
+            .intersects(Flags::WIDE_CHAR_SPACER | Flags::LEADING_WIDE_CHAR_SPACER)
+        {
+            continue;
+        }
+
+        if format == ScrollbackFormat::Ansi {
+            let cell_style = CellStyle::of(cell);
+            if cell_style != style {
+                cell_style.write_sgr(out);
+                style = cell_style;
+            }
+        }
+
+        out.push(if cell.c == '\0' || cell.c.is_control() {
+            ' '
+        } else {
+            cell.c
+        });
+        if let Some(zerowidth) = cell.zerowidth() {
+            out.extend(zerowidth);
+        }
+    }
+
+    if style != CellStyle::DEFAULT {
+        out.push_str(SGR_RESET);
+    }
+}
+
+/// Stream every line of `grid`, oldest scrollback first, into `writer`.
+/// Soft-wrapped rows are joined and trailing blank lines are omitted.
+pub(crate) fn write_grid(
+    grid: &Grid<Cell>,
+    writer: &mut impl Write,
+    format: ScrollbackFormat,
+) -> io::Result<()> {
+    let topmost = -(grid.history_size() as i32);
+    let bottommost = grid.screen_lines() as i32 - 1;
+    write_grid_lines(grid, topmost, bottommost, writer, format)
+}
+
+/// Stream the rows currently shown in the viewport into `writer`.
+pub(crate) fn write_viewport(
+    grid: &Grid<Cell>,
+    writer: &mut impl Write,
+    format: ScrollbackFormat,
+) -> io::Result<()> {
+    let top = -(grid.display_offset() as i32);
+    let bottom = top + grid.screen_lines() as i32 - 1;
+    write_grid_lines(grid, top, bottom, writer, format)
+}
+
+fn write_grid_lines(
+    grid: &Grid<Cell>,
+    topmost: i32,
+    bottommost: i32,
+    writer: &mut impl Write,
+    format: ScrollbackFormat,
+) -> io::Result<()> {
+    let columns = grid.columns();
+
+    let mut line = String::with_capacity(columns);
+    let mut pending_blank_lines = 0usize;
+    for line_index in topmost..=bottommost {
+        let row = &grid[Line(line_index)];
+        let wraps = line_index < bottommost
+            && columns > 0
+            && row[Column(columns - 1)].flags.contains(Flags::WRAPLINE);
+        encode_row(row, columns, wraps, format, &mut line);
+        if wraps {
+            continue;
+        }
+
+        if line.is_empty() {
+            pending_blank_lines += 1;
+            continue;
+        }
+
+        for _ in 0..pending_blank_lines {
+            writer.write_all(b"\n")?;
+        }
+        pending_blank_lines = 0;
+        writer.write_all(line.as_bytes())?;
+        writer.write_all(b"\n")?;
+        line.clear();
+    }
+
+    writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid_with_lines(lines: &[&str], columns: usize) -> Grid<Cell> {
+        let mut grid = Grid::new(lines.len(), columns, 0);
+        for (index, text) in lines.iter().enumerate() {
+            for (col, c) in text.chars().enumerate() {
+                grid[Line(index as i32)][Column(col)].c = c;
+            }
+        }
+        grid
+    }
+
+    fn dump(grid: &Grid<Cell>, format: ScrollbackFormat) -> String {
+        let mut out = Vec::new();
+        write_grid(grid, &mut out, format).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn plain_text_trims_trailing_blanks_and_joins_wrapped_rows() {
+        let mut grid = grid_with_lines(&["abcd", "ef", "", "gh", "", ""], 4);
+        grid[Line(0)][Column(3)].flags.insert(Flags::WRAPLINE);
+
+        assert_eq!(dump(&grid, ScrollbackFormat::PlainText), "abcdef\n\ngh\n");
+    }
+
+    #[test]
+    fn ansi_output_wraps_styled_runs_in_sgr_sequences() {
+        let mut grid = grid_with_lines(&["ab c"], 4);
+        let cell = &mut grid[Line(0)][Column(1)];
+        cell.fg = Color::Named(NamedColor::Red);
+        cell.flags.insert(Flags::BOLD);
+        grid[Line(0)][Column(3)].fg = Color::Indexed(200);
+
+        assert_eq!(
+            dump(&grid, ScrollbackFormat::Ansi),
+            "a\x1b[0;1;31mb\x1b[0m \x1b[0;38;5;200mc\x1b[0m\n"
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use super::{ThemeColors, rgba};
+use super::{ThemeAppearance, ThemeColors, rgba};
 
 pub fn theme() -> ThemeColors {
     ThemeColors {
@@ -25,5 +25,6 @@ pub fn theme() -> ThemeColors {
         foreground: rgba(0xCD, 0xD6, 0xF4),
         background: rgba(0x1E, 0x1E, 0x2E),
         cursor: rgba(0xCD, 0xD6, 0xF4),
+        appearance: ThemeAppearance::Dark,
     }
 }
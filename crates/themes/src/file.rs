@@ -0,0 +1,265 @@
+use super::{ThemeAppearance, ThemeColors, ThemeProvider, ThemeRefresh, normalize_theme_id, rgba};
+use serde::Deserialize;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Themes read from `*.toml` files in a directory, each registered under its
+/// normalized file stem (`My Theme.toml` is `my-theme`).
+pub struct FileThemeProvider {
+    dir: PathBuf,
+    themes: Vec<(String, ThemeColors)>,
+    errors: Vec<ThemeFileError>,
+}
+
+/// A theme file that was skipped because it could not be read or parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ThemeFileError {
+    pub path: PathBuf,
+    pub message: String,
+}
+
+impl fmt::Display for ThemeFileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path.display(), self.message)
+    }
+}
+
+#[derive(Deserialize)]
+struct ThemeFile {
+    foreground: String,
+    background: String,
+    cursor: String,
+    ansi: Vec<String>,
+}
+
+impl FileThemeProvider {
+    /// A provider for `dir` with no themes yet; [`ThemeProvider::refresh`] reads them.
+    pub fn new(dir: PathBuf) -> Self {
+        Self {
+            dir,
+            themes: Vec::new(),
+            errors: Vec::new(),
+        }
+    }
+
+    /// Loads every `*.toml` file in `dir`. Files that fail to parse are skipped
+    /// and reported through [`FileThemeProvider::errors`]; only an unreadable
+    /// directory is an error.
+    pub fn load_from_dir(dir: &Path) -> std::io::Result<Self> {
+        let mut provider = Self::new(dir.to_path_buf());
+        provider.reload()?;
+        Ok(provider)
+    }
+
+    fn reload(&mut self) -> std::io::Result<()> {
+        let mut paths = Vec::new();
+        for entry in std::fs::read_dir(&self.dir)? {
+            let path = entry?.path();
+            let is_toml = path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("toml"));
+            if is_toml && path.is_file() {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+
+        self.themes.clear();
+        self.errors.clear();
+        for path in paths {
+            let theme_id = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .map(normalize_theme_id)
+                .unwrap_or_default();
+            let result = if theme_id.is_empty() {
+                Err("file name does not make a valid theme id".to_string())
+            } else {
+                std::fs::read_to_string(&path)
+                    .map_err(|error| error.to_string())
+                    .and_then(|contents| parse_theme_file(&contents))
+            };
+
+            match result {
+                Ok(theme) => {
+                    self.themes.retain(|(id, _)| *id != theme_id);
+                    self.themes.push((theme_id, theme));
+                }
+                Err(message) => self.errors.push(ThemeFileError { path, message }),
+            }
+        }
+        Ok(())
+    }
+
+    pub fn errors(&self) -> &[ThemeFileError] {
+        &self.errors
+    }
+}
+
+impl ThemeProvider for FileThemeProvider {
+    fn theme(&self, theme_id: &str) -> Option<ThemeColors> {
+        let theme_id = normalize_theme_id(theme_id);
+        self.themes
+            .iter()
+            .find(|(id, _)| *id == theme_id)
+            .map(|(_, theme)| *theme)
+    }
+
+    fn theme_ids(&self) -> Vec<String> {
+        self.themes.iter().map(|(id, _)| id.clone()).collect()
+    }
+
+    /// A missing directory just means no themes; other read failures keep the
+    /// themes loaded before.
+    fn refresh(&mut self) -> Option<ThemeRefresh> {
+        let errors = match self.reload() {
+            Ok(()) => self
+                .errors
+                .iter()
+                .map(|error| format!("Skipped theme {}", error))
+                .collect(),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+                self.themes.clear();
+                self.errors.clear();
+                Vec::new()
+            }
+            Err(error) => vec![format!(
+                "Failed to read themes from {}: {}",
+                self.dir.display(),
+                error
+            )],
+        };
+        Some(ThemeRefresh {
+            loaded: self.themes.len(),
+            errors,
+        })
+    }
+}
+
+fn parse_theme_file(contents: &str) -> Result<ThemeColors, String> {
+    let file: ThemeFile = toml::from_str(contents).map_err(|error| error.message().to_string())?;
+    if file.ansi.len() != 16 {
+        return Err(format!(
+            "ansi must list 16 colors, found {}",
+            file.ansi.len()
+        ));
+    }
+
+    let mut ansi = [rgba(0, 0, 0); 16];
+    for (slot, value) in ansi.iter_mut().zip(&file.ansi) {
+        *slot = parse_hex_color(value)?;
+    }
+
+    let background = parse_hex_color(&file.background)?;
+    Ok(ThemeColors {
+        ansi,
+        foreground: parse_hex_color(&file.foreground)?,
+        background,
+        cursor: parse_hex_color(&file.cursor)?,
+        appearance: ThemeAppearance::from_background(background),
+    })
+}
+
+fn parse_hex_color(value: &str) -> Result<gpui::Rgba, String> {
+    let hex = value.trim().trim_start_matches('#');
+    let channel = |index: usize| {
+        hex.get(index..index + 2)
+            .and_then(|digits| u8::from_str_radix(digits, 16).ok())
+    };
+    match (hex.len(), channel(0), channel(2), channel(4)) {
+        (6, Some(r), Some(g), Some(b)) => Ok(rgba(r, g, b)),
+        _ => Err(format!("invalid color '{}', expected #RRGGBB", value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALID_THEME: &str = r##"
+foreground = "#c0caf5"
+background = "#1a1b26"
+cursor = "#c0caf5"
+ansi = [
+    "#15161e", "#f7768e", "#9ece6a", "#e0af68", "#7aa2f7", "#bb9af7", "#7dcfff", "#a9b1d6",
+    "#414868", "#f7768e", "#9ece6a", "#e0af68", "#7aa2f7", "#bb9af7", "#7dcfff", "#c0caf5",
+]
+"##;
+
+    const SHORT_ANSI_THEME: &str = r##"
+foreground = "#ffffff"
+background = "#000000"
+cursor = "#ffffff"
+ansi = ["#000000"]
+"##;
+
+    fn theme_dir(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("termy-themes-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        for (file_name, contents) in files {
+            std::fs::write(dir.join(file_name), contents).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn loads_themes_under_their_file_stem_and_reports_bad_files() {
+        let dir = theme_dir(
+            "load",
+            &[
+                ("My Night.toml", VALID_THEME),
+                ("short.toml", SHORT_ANSI_THEME),
+                ("broken.toml", "foreground = "),
+                ("notes.txt", "not a theme"),
+            ],
+        );
+
+        let provider = FileThemeProvider::load_from_dir(&dir).unwrap();
+        assert_eq!(provider.theme_ids(), ["my-night"]);
+
+        let theme = provider.theme("My_Night").unwrap();
+        assert_eq!(theme.background, rgba(0x1a, 0x1b, 0x26));
+        assert_eq!(theme.ansi[15], rgba(0xc0, 0xca, 0xf5));
+
+        let failed: Vec<_> = provider
+            .errors()
+            .iter()
+            .map(|error| error.path.file_name().unwrap().to_str().unwrap())
+            .collect();
+        assert_eq!(failed, ["broken.toml", "short.toml"]);
+        assert!(provider.errors()[1].message.contains("16 colors"));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn refresh_picks_up_edited_and_removed_theme_files() {
+        let dir = theme_dir("refresh", &[("night.toml", VALID_THEME)]);
+        let mut provider = FileThemeProvider::new(dir.clone());
+        assert!(provider.theme_ids().is_empty());
+
+        let refresh = provider.refresh().unwrap();
+        assert_eq!(refresh.loaded, 1);
+        assert!(refresh.errors.is_empty());
+
+        std::fs::write(dir.join("night.toml"), "foreground = ").unwrap();
+        let refresh = provider.refresh().unwrap();
+        assert_eq!(refresh.loaded, 0);
+        assert_eq!(refresh.errors.len(), 1);
+        assert!(provider.theme("night").is_none());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(provider.refresh(), Some(ThemeRefresh::default()));
+    }
+
+    #[test]
+    fn parse_hex_color_rejects_malformed_values() {
+        assert_eq!(parse_hex_color("#FF8000"), Ok(rgba(0xFF, 0x80, 0x00)));
+        assert_eq!(parse_hex_color("ff8000"), Ok(rgba(0xFF, 0x80, 0x00)));
+        assert!(parse_hex_color("#ff80").is_err());
+        assert!(parse_hex_color("#gg8000").is_err());
+    }
+}
@@ -0,0 +1,30 @@
+use super::{ThemeAppearance, ThemeColors, rgba};
+
+pub fn theme() -> ThemeColors {
+    ThemeColors {
+        ansi: [
+            // Normal colors (0-7)
+            rgba(0x24, 0x29, 0x2F), // Black
+            rgba(0xCF, 0x22, 0x2E), // Red
+            rgba(0x11, 0x63, 0x29), // Green
+            rgba(0x4D, 0x2D, 0x00), // Yellow
+            rgba(0x09, 0x69, 0xDA), // Blue
+            rgba(0x82, 0x50, 0xDF), // Magenta
+            rgba(0x1B, 0x7C, 0x83), // Cyan
+            rgba(0x6E, 0x77, 0x81), // White
+            // Bright colors (8-15)
+            rgba(0x57, 0x60, 0x6A), // Bright Black
+            rgba(0xA4, 0x0E, 0x26), // Bright Red
+            rgba(0x1A, 0x7F, 0x37), // Bright Green
+            rgba(0x63, 0x3C, 0x01), // Bright Yellow
+            rgba(0x21, 0x8B, 0xFF), // Bright Blue
+            rgba(0xA4, 0x75, 0xF9), // Bright Magenta
+            rgba(0x31, 0x92, 0xAA), // Bright Cyan
+            rgba(0x8C, 0x95, 0x9F), // Bright White
+        ],
+        foreground: rgba(0x1F, 0x23, 0x28),
+        background: rgba(0xFF, 0xFF, 0xFF),
+        cursor: rgba(0x09, 0x69, 0xDA),
+        appearance: ThemeAppearance::Light,
+    }
+}
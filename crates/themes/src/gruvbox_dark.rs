@@ -1,4 +1,4 @@
-use super::{ThemeColors, rgba};
+use super::{ThemeAppearance, ThemeColors, rgba};
 
 pub fn theme() -> ThemeColors {
     ThemeColors {
@@ -25,5 +25,6 @@ pub fn theme() -> ThemeColors {
         foreground: rgba(0xEB, 0xDB, 0xB2),
         background: rgba(0x28, 0x28, 0x28),
         cursor: rgba(0xEB, 0xDB, 0xB2),
+        appearance: ThemeAppearance::Dark,
     }
 }
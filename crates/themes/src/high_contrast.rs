@@ -0,0 +1,30 @@
+use super::{ThemeAppearance, ThemeColors, rgba};
+
+pub fn theme() -> ThemeColors {
+    ThemeColors {
+        ansi: [
+            // Normal colors (0-7)
+            rgba(0x00, 0x00, 0x00), // Black
+            rgba(0xFF, 0x6B, 0x6B), // Red
+            rgba(0x5F, 0xFF, 0x5F), // Green
+            rgba(0xFF, 0xFF, 0x00), // Yellow
+            rgba(0x6C, 0xB6, 0xFF), // Blue
+            rgba(0xFF, 0x7B, 0xFF), // Magenta
+            rgba(0x00, 0xFF, 0xFF), // Cyan
+            rgba(0xE0, 0xE0, 0xE0), // White
+            // Bright colors (8-15)
+            rgba(0x9A, 0x9A, 0x9A), // Bright Black
+            rgba(0xFF, 0x8F, 0x8F), // Bright Red
+            rgba(0x9B, 0xFF, 0x9B), // Bright Green
+            rgba(0xFF, 0xFF, 0x80), // Bright Yellow
+            rgba(0x9E, 0xCF, 0xFF), // Bright Blue
+            rgba(0xFF, 0xA8, 0xFF), // Bright Magenta
+            rgba(0x80, 0xFF, 0xFF), // Bright Cyan
+            rgba(0xFF, 0xFF, 0xFF), // Bright White
+        ],
+        foreground: rgba(0xFF, 0xFF, 0xFF),
+        background: rgba(0x00, 0x00, 0x00),
+        cursor: rgba(0xFF, 0xFF, 0x00),
+        appearance: ThemeAppearance::Dark,
+    }
+}
@@ -1,6 +1,9 @@
 mod catppuccin_mocha;
 mod dracula;
+mod file;
+mod github_light;
 mod gruvbox_dark;
+mod high_contrast;
 mod material_dark;
 mod monokai;
 mod nord;
@@ -8,13 +11,19 @@ mod oceanic_next;
 mod one_dark;
 mod palenight;
 mod solarized_dark;
+mod solarized_light;
 mod termy;
 mod tokyo_night;
 mod tomorrow_night;
 
+pub use file::{FileThemeProvider, ThemeFileError};
+
 use gpui::Rgba;
 use std::collections::HashSet;
-use std::sync::{OnceLock, RwLock};
+use std::sync::{Mutex, OnceLock, RwLock};
+
+/// Number of resolved themes a registry keeps before evicting the least recently used.
+pub const DEFAULT_THEME_CACHE_CAPACITY: usize = 32;
 
 pub const BUILTIN_THEME_IDS: &[&str] = &[
     "termy",
@@ -24,33 +33,177 @@ pub const BUILTIN_THEME_IDS: &[&str] = &[
     "gruvbox-dark",
     "nord",
     "solarized-dark",
+    "solarized-light",
+    "github-light",
     "one-dark",
     "monokai",
     "material-dark",
     "palenight",
     "tomorrow-night",
     "oceanic-next",
+    "high-contrast",
 ];
 
+/// Whether a theme is dark text on light or the other way around, so UI drawn
+/// over the terminal can pick contrasting overlays.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ThemeAppearance {
+    #[default]
+    Dark,
+    Light,
+}
+
+impl ThemeAppearance {
+    /// Guesses the appearance from a background color, for themes that don't say.
+    pub fn from_background(background: Rgba) -> Self {
+        if is_dark(background) {
+            Self::Dark
+        } else {
+            Self::Light
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct ThemeColors {
     pub ansi: [Rgba; 16],
     pub foreground: Rgba,
     pub background: Rgba,
     pub cursor: Rgba,
+    pub appearance: ThemeAppearance,
+}
+
+/// How far text colors are pulled toward white (or black on light themes) by
+/// [`ThemeColors::for_transparency`].
+const TRANSPARENCY_TEXT_MIX: f32 = 0.25;
+
+/// Lowest foreground/background contrast [`ThemeColors::is_legible`] accepts;
+/// WCAG's minimum for large text.
+pub const MIN_LEGIBLE_CONTRAST: f32 = 3.0;
+
+impl ThemeColors {
+    /// Derive a variant that stays readable over a translucent background, where
+    /// whatever sits behind the window eats into text contrast. Foreground, ANSI
+    /// and cursor colors are pushed away from the background; the background
+    /// itself is left alone.
+    pub fn for_transparency(&self) -> ThemeColors {
+        let target = if is_dark(self.background) {
+            rgba(0xFF, 0xFF, 0xFF)
+        } else {
+            rgba(0x00, 0x00, 0x00)
+        };
+        let lift = |color: Rgba| mix(color, target, TRANSPARENCY_TEXT_MIX);
+
+        ThemeColors {
+            ansi: self.ansi.map(lift),
+            foreground: lift(self.foreground),
+            background: self.background,
+            cursor: lift(self.cursor),
+            appearance: self.appearance,
+        }
+    }
+
+    /// Blend toward `other`; `t` is clamped to `0.0..=1.0`. The appearance
+    /// switches over halfway.
+    pub fn lerp(&self, other: &ThemeColors, t: f32) -> ThemeColors {
+        let t = t.clamp(0.0, 1.0);
+        ThemeColors {
+            ansi: std::array::from_fn(|index| lerp_rgba(self.ansi[index], other.ansi[index], t)),
+            foreground: lerp_rgba(self.foreground, other.foreground, t),
+            background: lerp_rgba(self.background, other.background, t),
+            cursor: lerp_rgba(self.cursor, other.cursor, t),
+            appearance: if t < 0.5 {
+                self.appearance
+            } else {
+                other.appearance
+            },
+        }
+    }
+
+    /// WCAG contrast ratio between foreground and background, from 1.0 (none)
+    /// to 21.0 (black on white).
+    pub fn contrast_ratio(&self) -> f32 {
+        contrast_ratio(self.foreground, self.background)
+    }
+
+    /// Whether plain text stays readable, i.e. the contrast ratio reaches
+    /// [`MIN_LEGIBLE_CONTRAST`].
+    pub fn is_legible(&self) -> bool {
+        self.contrast_ratio() >= MIN_LEGIBLE_CONTRAST
+    }
 }
 
 pub trait ThemeProvider: Send + Sync {
     fn theme(&self, theme_id: &str) -> Option<ThemeColors>;
 
-    fn theme_ids(&self) -> &'static [&'static str] {
-        &[]
+    fn theme_ids(&self) -> Vec<String> {
+        Vec::new()
     }
+
+    /// Re-reads themes from wherever the provider loads them. `None` for providers
+    /// with nothing to re-read, such as the built-in themes.
+    fn refresh(&mut self) -> Option<ThemeRefresh> {
+        None
+    }
+}
+
+/// What a provider found when it re-read its themes.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ThemeRefresh {
+    /// Themes the provider offers after the refresh.
+    pub loaded: usize,
+    /// One message per theme that could not be loaded.
+    pub errors: Vec<String>,
 }
 
 #[derive(Default)]
 pub struct ThemeRegistry {
     providers: Vec<Box<dyn ThemeProvider>>,
+    cache: Mutex<ThemeCache>,
+}
+
+/// Resolved themes keyed by the id they were requested with, least recently used first.
+struct ThemeCache {
+    capacity: usize,
+    entries: Vec<(String, ThemeColors)>,
+}
+
+impl Default for ThemeCache {
+    fn default() -> Self {
+        Self {
+            capacity: DEFAULT_THEME_CACHE_CAPACITY,
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl ThemeCache {
+    fn get(&mut self, theme_id: &str) -> Option<ThemeColors> {
+        let index = self.entries.iter().position(|(id, _)| id == theme_id)?;
+        let entry = self.entries.remove(index);
+        let theme = entry.1;
+        self.entries.push(entry);
+        Some(theme)
+    }
+
+    fn insert(&mut self, theme_id: &str, theme: ThemeColors) {
+        if self.capacity == 0 {
+            return;
+        }
+        self.entries.retain(|(id, _)| id != theme_id);
+        self.entries.push((theme_id.to_string(), theme));
+        self.evict();
+    }
+
+    fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        self.evict();
+    }
+
+    fn evict(&mut self) {
+        let excess = self.entries.len().saturating_sub(self.capacity);
+        self.entries.drain(..excess);
+    }
 }
 
 impl ThemeRegistry {
@@ -69,24 +222,58 @@ impl ThemeRegistry {
         P: ThemeProvider + 'static,
     {
         self.providers.push(Box::new(provider));
+        // A new provider can shadow themes that were already resolved.
+        self.cache_mut().entries.clear();
+    }
+
+    /// Sets how many resolved themes are memoized; 0 disables caching.
+    pub fn set_cache_capacity(&mut self, capacity: usize) {
+        self.cache_mut().set_capacity(capacity);
     }
 
     pub fn resolve(&self, theme_id: &str) -> Option<ThemeColors> {
-        for provider in self.providers.iter().rev() {
-            if let Some(theme) = provider.theme(theme_id) {
-                return Some(theme);
-            }
+        let mut cache = self.cache.lock().expect("Theme cache lock poisoned");
+        if let Some(theme) = cache.get(theme_id) {
+            return Some(theme);
         }
-        None
+
+        // Misses are not cached so a provider can pick up a theme added later.
+        let theme = self
+            .providers
+            .iter()
+            .rev()
+            .find_map(|provider| provider.theme(theme_id))?;
+        cache.insert(theme_id, theme);
+        Some(theme)
     }
 
-    pub fn theme_ids(&self) -> Vec<&'static str> {
+    /// Re-reads every provider that loads themes from somewhere and forgets resolved
+    /// themes, so edited theme files apply on the next resolve.
+    pub fn refresh(&mut self) -> ThemeRefresh {
+        let mut total = ThemeRefresh::default();
+        for refresh in self
+            .providers
+            .iter_mut()
+            .filter_map(|provider| provider.refresh())
+        {
+            total.loaded += refresh.loaded;
+            total.errors.extend(refresh.errors);
+        }
+        self.cache_mut().entries.clear();
+        total
+    }
+
+    fn cache_mut(&mut self) -> &mut ThemeCache {
+        self.cache.get_mut().expect("Theme cache lock poisoned")
+    }
+
+    pub fn theme_ids(&self) -> Vec<String> {
         let mut seen = HashSet::new();
         let mut ids = Vec::new();
         for provider in &self.providers {
             for id in provider.theme_ids() {
-                if seen.insert(*id) {
-                    ids.push(*id);
+                if seen.insert(id.clone()) {
+                    ids.push(id);
                 }
             }
         }
@@ -101,8 +288,8 @@ impl ThemeProvider for BuiltinThemeProvider {
         builtin_theme(theme_id)
     }
 
-    fn theme_ids(&self) -> &'static [&'static str] {
-        BUILTIN_THEME_IDS
+    fn theme_ids(&self) -> Vec<String> {
+        BUILTIN_THEME_IDS.iter().map(|id| id.to_string()).collect()
     }
 }
 
@@ -122,6 +309,20 @@ where
         .register_provider(provider);
 }
 
+pub fn set_theme_cache_capacity(capacity: usize) {
+    global_theme_registry()
+        .write()
+        .expect("Theme registry lock poisoned")
+        .set_cache_capacity(capacity);
+}
+
+pub fn refresh_themes() -> ThemeRefresh {
+    global_theme_registry()
+        .write()
+        .expect("Theme registry lock poisoned")
+        .refresh()
+}
+
 pub fn resolve_theme(theme_id: &str) -> Option<ThemeColors> {
     global_theme_registry()
         .read()
@@ -129,7 +330,7 @@ pub fn resolve_theme(theme_id: &str) -> Option<ThemeColors> {
         .resolve(theme_id)
 }
 
-pub fn available_theme_ids() -> Vec<&'static str> {
+pub fn available_theme_ids() -> Vec<String> {
     global_theme_registry()
         .read()
         .expect("Theme registry lock poisoned")
@@ -145,12 +346,15 @@ pub fn builtin_theme(theme_id: &str) -> Option<ThemeColors> {
         "gruvbox-dark" => Some(gruvbox_dark()),
         "nord" => Some(nord()),
         "solarized-dark" => Some(solarized_dark()),
+        "solarized-light" => Some(solarized_light()),
+        "github-light" => Some(github_light()),
         "one-dark" => Some(one_dark()),
         "monokai" => Some(monokai()),
         "material-dark" => Some(material_dark()),
         "palenight" => Some(palenight()),
         "tomorrow-night" => Some(tomorrow_night()),
         "oceanic-next" => Some(oceanic_next()),
+        "high-contrast" => Some(high_contrast()),
         _ => None,
     }
 }
@@ -165,12 +369,15 @@ pub fn canonical_builtin_theme_id(theme_id: &str) -> Option<&'static str> {
         "gruvbox" | "gruvboxdark" => Some("gruvbox-dark"),
         "nord" => Some("nord"),
         "solarized" | "solarizeddark" => Some("solarized-dark"),
+        "solarizedlight" => Some("solarized-light"),
+        "github" | "githublight" => Some("github-light"),
         "one" | "onedark" => Some("one-dark"),
         "monokai" => Some("monokai"),
         "material" | "materialdark" => Some("material-dark"),
         "palenight" => Some("palenight"),
         "tomorrow" | "tomorrownight" => Some("tomorrow-night"),
         "oceanic" | "oceanicnext" => Some("oceanic-next"),
+        "highcontrast" | "contrast" => Some("high-contrast"),
         _ => None,
     }
 }
@@ -237,6 +444,14 @@ pub fn solarized_dark() -> ThemeColors {
     solarized_dark::theme()
 }
 
+pub fn solarized_light() -> ThemeColors {
+    solarized_light::theme()
+}
+
+pub fn github_light() -> ThemeColors {
+    github_light::theme()
+}
+
 pub fn one_dark() -> ThemeColors {
     one_dark::theme()
 }
@@ -261,6 +476,10 @@ pub fn oceanic_next() -> ThemeColors {
     oceanic_next::theme()
 }
 
+pub fn high_contrast() -> ThemeColors {
+    high_contrast::theme()
+}
+
 fn rgba(r: u8, g: u8, b: u8) -> Rgba {
     Rgba {
         r: r as f32 / 255.0,
@@ -269,3 +488,191 @@ fn rgba(r: u8, g: u8, b: u8) -> Rgba {
         a: 1.0,
     }
 }
+
+/// Linear interpolation between two colors, alpha included, with `t` clamped to
+/// `0.0..=1.0`.
+pub fn lerp_rgba(from: Rgba, to: Rgba, t: f32) -> Rgba {
+    let t = t.clamp(0.0, 1.0);
+    // Weighted this way both ends come out exact.
+    let channel = |from: f32, to: f32| from * (1.0 - t) + to * t;
+    Rgba {
+        r: channel(from.r, to.r),
+        g: channel(from.g, to.g),
+        b: channel(from.b, to.b),
+        a: channel(from.a, to.a),
+    }
+}
+
+fn mix(from: Rgba, to: Rgba, amount: f32) -> Rgba {
+    Rgba {
+        r: from.r + (to.r - from.r) * amount,
+        g: from.g + (to.g - from.g) * amount,
+        b: from.b + (to.b - from.b) * amount,
+        a: from.a,
+    }
+}
+
+/// WCAG relative luminance of an sRGB color, from 0.0 (black) to 1.0 (white).
+/// Alpha is ignored.
+pub fn relative_luminance(color: Rgba) -> f32 {
+    let channel = |c: f32| {
+        if c <= 0.039_28 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    0.2126 * channel(color.r) + 0.7152 * channel(color.g) + 0.0722 * channel(color.b)
+}
+
+/// WCAG contrast ratio between two colors, from 1.0 (identical) to 21.0 (black
+/// on white). Alpha is ignored.
+pub fn contrast_ratio(a: Rgba, b: Rgba) -> f32 {
+    let (la, lb) = (relative_luminance(a), relative_luminance(b));
+    (la.max(lb) + 0.05) / (la.min(lb) + 0.05)
+}
+
+/// Whether white text reads better than black on `background`.
+fn is_dark(background: Rgba) -> bool {
+    contrast_ratio(background, rgba(0xFF, 0xFF, 0xFF)) > contrast_ratio(background, rgba(0, 0, 0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct CountingProvider {
+        lookups: Arc<AtomicUsize>,
+    }
+
+    impl ThemeProvider for CountingProvider {
+        fn theme(&self, theme_id: &str) -> Option<ThemeColors> {
+            self.lookups.fetch_add(1, Ordering::SeqCst);
+            builtin_theme(theme_id)
+        }
+    }
+
+    #[test]
+    fn resolve_memoizes_themes_and_evicts_least_recently_used() {
+        let lookups = Arc::new(AtomicUsize::new(0));
+        let mut registry = ThemeRegistry::new();
+        registry.register_provider(CountingProvider {
+            lookups: lookups.clone(),
+        });
+        registry.set_cache_capacity(2);
+
+        registry.resolve("nord").unwrap();
+        registry.resolve("nord").unwrap();
+        assert_eq!(lookups.load(Ordering::SeqCst), 1);
+
+        registry.resolve("dracula").unwrap();
+        registry.resolve("nord").unwrap();
+        registry.resolve("monokai").unwrap();
+        assert_eq!(lookups.load(Ordering::SeqCst), 3);
+
+        // dracula was least recently used when monokai was added.
+        registry.resolve("nord").unwrap();
+        assert_eq!(lookups.load(Ordering::SeqCst), 3);
+        registry.resolve("dracula").unwrap();
+        assert_eq!(lookups.load(Ordering::SeqCst), 4);
+
+        assert!(registry.resolve("missing").is_none());
+        assert!(registry.resolve("missing").is_none());
+        assert_eq!(lookups.load(Ordering::SeqCst), 6);
+    }
+
+    #[test]
+    fn transparency_variant_pushes_text_away_from_background() {
+        let dark = termy();
+        let derived = dark.for_transparency();
+        assert_eq!(derived.background, dark.background);
+        assert!(relative_luminance(derived.foreground) > relative_luminance(dark.foreground));
+        for (base, lifted) in dark.ansi.iter().zip(derived.ansi.iter()) {
+            assert!(relative_luminance(*lifted) >= relative_luminance(*base));
+        }
+
+        let light = ThemeColors {
+            background: rgba(0xFA, 0xFA, 0xFA),
+            ..dark
+        };
+        let derived = light.for_transparency();
+        assert!(relative_luminance(derived.foreground) < relative_luminance(light.foreground));
+    }
+
+    #[test]
+    fn builtin_themes_declare_an_appearance_matching_their_background() {
+        for id in BUILTIN_THEME_IDS {
+            let theme = builtin_theme(id).unwrap();
+            assert_eq!(
+                theme.appearance,
+                ThemeAppearance::from_background(theme.background),
+                "{}",
+                id
+            );
+        }
+        assert_eq!(solarized_light().appearance, ThemeAppearance::Light);
+        assert_eq!(github_light().appearance, ThemeAppearance::Light);
+    }
+
+    fn assert_same_colors(actual: &ThemeColors, expected: &ThemeColors) {
+        assert_eq!(actual.foreground, expected.foreground);
+        assert_eq!(actual.background, expected.background);
+        assert_eq!(actual.cursor, expected.cursor);
+        assert_eq!(actual.ansi, expected.ansi);
+        assert_eq!(actual.appearance, expected.appearance);
+    }
+
+    #[test]
+    fn lerp_hits_both_ends_and_clamps() {
+        let dark = nord();
+        let light = solarized_light();
+
+        assert_same_colors(&dark.lerp(&light, 0.0), &dark);
+        assert_same_colors(&dark.lerp(&light, 1.0), &light);
+        assert_same_colors(&dark.lerp(&light, -2.0), &dark);
+        assert_same_colors(&dark.lerp(&light, 3.0), &light);
+
+        let halfway = dark.lerp(&light, 0.5);
+        assert!(
+            (halfway.background.r - (dark.background.r + light.background.r) / 2.0).abs() < 1e-6
+        );
+        assert_eq!(halfway.appearance, ThemeAppearance::Light);
+    }
+
+    #[test]
+    fn contrast_ratio_follows_wcag_and_flags_illegible_themes() {
+        let mut theme = high_contrast();
+        theme.foreground = rgba(0xFF, 0xFF, 0xFF);
+        theme.background = rgba(0x00, 0x00, 0x00);
+        assert!((theme.contrast_ratio() - 21.0).abs() < 1e-3);
+        assert!(theme.is_legible());
+
+        theme.foreground = rgba(0x30, 0x30, 0x30);
+        assert!(theme.contrast_ratio() < MIN_LEGIBLE_CONTRAST);
+        assert!(!theme.is_legible());
+
+        for theme_id in BUILTIN_THEME_IDS {
+            assert!(
+                builtin_theme(theme_id).unwrap().is_legible(),
+                "{}",
+                theme_id
+            );
+        }
+    }
+
+    #[test]
+    fn zero_capacity_disables_the_cache() {
+        let lookups = Arc::new(AtomicUsize::new(0));
+        let mut registry = ThemeRegistry::new();
+        registry.register_provider(CountingProvider {
+            lookups: lookups.clone(),
+        });
+        registry.set_cache_capacity(0);
+
+        registry.resolve("nord").unwrap();
+        registry.resolve("nord").unwrap();
+        assert_eq!(lookups.load(Ordering::SeqCst), 2);
+    }
+}
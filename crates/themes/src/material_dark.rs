@@ -1,4 +1,4 @@
-use super::{ThemeColors, rgba};
+use super::{ThemeAppearance, ThemeColors, rgba};
 
 pub fn theme() -> ThemeColors {
     ThemeColors {
@@ -25,5 +25,6 @@ pub fn theme() -> ThemeColors {
         foreground: rgba(0xEE, 0xFF, 0xFF),
         background: rgba(0x26, 0x32, 0x38),
         cursor: rgba(0xFF, 0xCB, 0x6B),
+        appearance: ThemeAppearance::Dark,
     }
 }
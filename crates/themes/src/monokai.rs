@@ -1,4 +1,4 @@
-use super::{ThemeColors, rgba};
+use super::{ThemeAppearance, ThemeColors, rgba};
 
 pub fn theme() -> ThemeColors {
     ThemeColors {
@@ -25,5 +25,6 @@ pub fn theme() -> ThemeColors {
         foreground: rgba(0xF8, 0xF8, 0xF2),
         background: rgba(0x27, 0x28, 0x22),
         cursor: rgba(0xF8, 0xF8, 0xF0),
+        appearance: ThemeAppearance::Dark,
     }
 }
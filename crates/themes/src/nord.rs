@@ -1,4 +1,4 @@
-use super::{ThemeColors, rgba};
+use super::{ThemeAppearance, ThemeColors, rgba};
 
 pub fn theme() -> ThemeColors {
     ThemeColors {
@@ -25,5 +25,6 @@ pub fn theme() -> ThemeColors {
         foreground: rgba(0xD8, 0xDE, 0xE9),
         background: rgba(0x2E, 0x34, 0x40),
         cursor: rgba(0xD8, 0xDE, 0xE9),
+        appearance: ThemeAppearance::Dark,
     }
 }
@@ -1,4 +1,4 @@
-use super::{ThemeColors, rgba};
+use super::{ThemeAppearance, ThemeColors, rgba};
 
 pub fn theme() -> ThemeColors {
     ThemeColors {
@@ -25,5 +25,6 @@ pub fn theme() -> ThemeColors {
         foreground: rgba(0xA7, 0xAD, 0xBA),
         background: rgba(0x1B, 0x2B, 0x34),
         cursor: rgba(0xFA, 0xC8, 0x63),
+        appearance: ThemeAppearance::Dark,
     }
 }
@@ -1,4 +1,4 @@
-use super::{ThemeColors, rgba};
+use super::{ThemeAppearance, ThemeColors, rgba};
 
 pub fn theme() -> ThemeColors {
     ThemeColors {
@@ -25,5 +25,6 @@ pub fn theme() -> ThemeColors {
         foreground: rgba(0xAB, 0xB2, 0xBF),
         background: rgba(0x1E, 0x22, 0x27),
         cursor: rgba(0x52, 0x8B, 0xFF),
+        appearance: ThemeAppearance::Dark,
     }
 }
@@ -1,4 +1,4 @@
-use super::{ThemeColors, rgba};
+use super::{ThemeAppearance, ThemeColors, rgba};
 
 pub fn theme() -> ThemeColors {
     ThemeColors {
@@ -25,5 +25,6 @@ pub fn theme() -> ThemeColors {
         foreground: rgba(0x95, 0x9D, 0xCB),
         background: rgba(0x29, 0x2D, 0x3E),
         cursor: rgba(0xFF, 0xCC, 0x00),
+        appearance: ThemeAppearance::Dark,
     }
 }
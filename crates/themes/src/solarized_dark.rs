@@ -1,4 +1,4 @@
-use super::{ThemeColors, rgba};
+use super::{ThemeAppearance, ThemeColors, rgba};
 
 pub fn theme() -> ThemeColors {
     ThemeColors {
@@ -25,5 +25,6 @@ pub fn theme() -> ThemeColors {
         foreground: rgba(0x83, 0x94, 0x96),
         background: rgba(0x00, 0x2B, 0x36),
         cursor: rgba(0x93, 0xA1, 0xA1),
+        appearance: ThemeAppearance::Dark,
     }
 }
@@ -0,0 +1,30 @@
+use super::{ThemeAppearance, ThemeColors, rgba};
+
+pub fn theme() -> ThemeColors {
+    ThemeColors {
+        ansi: [
+            // Normal colors (0-7)
+            rgba(0x07, 0x36, 0x42), // Black
+            rgba(0xDC, 0x32, 0x2F), // Red
+            rgba(0x85, 0x99, 0x00), // Green
+            rgba(0xB5, 0x89, 0x00), // Yellow
+            rgba(0x26, 0x8B, 0xD2), // Blue
+            rgba(0xD3, 0x36, 0x82), // Magenta
+            rgba(0x2A, 0xA1, 0x98), // Cyan
+            rgba(0xEE, 0xE8, 0xD5), // White
+            // Bright colors (8-15)
+            rgba(0x00, 0x2B, 0x36), // Bright Black
+            rgba(0xCB, 0x4B, 0x16), // Bright Red
+            rgba(0x58, 0x6E, 0x75), // Bright Green
+            rgba(0x65, 0x7B, 0x83), // Bright Yellow
+            rgba(0x83, 0x94, 0x96), // Bright Blue
+            rgba(0x6C, 0x71, 0xC4), // Bright Magenta
+            rgba(0x93, 0xA1, 0xA1), // Bright Cyan
+            rgba(0xFD, 0xF6, 0xE3), // Bright White
+        ],
+        foreground: rgba(0x65, 0x7B, 0x83),
+        background: rgba(0xFD, 0xF6, 0xE3),
+        cursor: rgba(0x58, 0x6E, 0x75),
+        appearance: ThemeAppearance::Light,
+    }
+}
@@ -1,4 +1,4 @@
-use super::{ThemeColors, rgba};
+use super::{ThemeAppearance, ThemeColors, rgba};
 
 pub fn theme() -> ThemeColors {
     ThemeColors {
@@ -25,5 +25,6 @@ pub fn theme() -> ThemeColors {
         foreground: rgba(0xE7, 0xEB, 0xF5),
         background: rgba(0x0B, 0x10, 0x20),
         cursor: rgba(0xA7, 0xE9, 0xA3),
+        appearance: ThemeAppearance::Dark,
     }
 }
@@ -1,4 +1,4 @@
-use super::{ThemeColors, rgba};
+use super::{ThemeAppearance, ThemeColors, rgba};
 
 pub fn theme() -> ThemeColors {
     ThemeColors {
@@ -25,5 +25,6 @@ pub fn theme() -> ThemeColors {
         foreground: rgba(0xC0, 0xCA, 0xF5),
         background: rgba(0x1A, 0x1B, 0x26),
         cursor: rgba(0xC0, 0xCA, 0xF5),
+        appearance: ThemeAppearance::Dark,
     }
 }
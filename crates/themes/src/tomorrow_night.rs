@@ -1,4 +1,4 @@
-use super::{ThemeColors, rgba};
+use super::{ThemeAppearance, ThemeColors, rgba};
 
 pub fn theme() -> ThemeColors {
     ThemeColors {
@@ -25,5 +25,6 @@ pub fn theme() -> ThemeColors {
         foreground: rgba(0xC5, 0xC8, 0xC6),
         background: rgba(0x1D, 0x1F, 0x21),
         cursor: rgba(0xAE, 0xAF, 0xAD),
+        appearance: ThemeAppearance::Dark,
     }
 }
@@ -15,11 +15,103 @@ pub const TOAST_FADE_IN_MS: u64 = 150;
 /// Duration of the fade-out animation in milliseconds
 pub const TOAST_FADE_OUT_MS: u64 = 200;
 
+/// Default display time for each toast kind when a toast is queued without an
+/// explicit duration. Loading toasts ignore these and stay until updated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ToastDurations {
+    pub info: Duration,
+    pub success: Duration,
+    pub warning: Duration,
+    pub error: Duration,
+}
+
+impl Default for ToastDurations {
+    fn default() -> Self {
+        Self {
+            info: DEFAULT_TOAST_DURATION,
+            success: DEFAULT_TOAST_DURATION,
+            warning: DEFAULT_TOAST_DURATION,
+            error: DEFAULT_TOAST_DURATION,
+        }
+    }
+}
+
+impl ToastDurations {
+    pub fn for_kind(&self, kind: ToastKind) -> Duration {
+        match kind {
+            ToastKind::Info => self.info,
+            ToastKind::Success => self.success,
+            ToastKind::Warning => self.warning,
+            ToastKind::Error => self.error,
+            ToastKind::Loading => LOADING_TOAST_DURATION,
+        }
+    }
+
+    /// Display time of the `_long` variants: 6s, or 8s for errors, while `kind`
+    /// keeps the 3s default, scaled by however much longer or shorter it is set.
+    pub fn long_for_kind(&self, kind: ToastKind) -> Duration {
+        let long = match kind {
+            ToastKind::Error => LONG_ERROR_TOAST_DURATION,
+            ToastKind::Loading => return LOADING_TOAST_DURATION,
+            _ => LONG_TOAST_DURATION,
+        };
+        long.mul_f64(self.for_kind(kind).as_secs_f64() / DEFAULT_TOAST_DURATION.as_secs_f64())
+    }
+
+    fn resolve(&self, kind: ToastKind, duration: ToastDuration) -> Duration {
+        match duration {
+            ToastDuration::Default => self.for_kind(kind),
+            ToastDuration::Long => self.long_for_kind(kind),
+            ToastDuration::Fixed(duration) => duration,
+        }
+    }
+}
+
+/// How long a queued toast stays up. `Default` and `Long` are looked up in the
+/// durations of the manager that shows the toast.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ToastDuration {
+    Default,
+    Long,
+    Fixed(Duration),
+}
+
+/// Where a toast sits in the stack. Higher priorities are listed first and High
+/// toasts are never collapsed into the overflow summary.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ToastPriority {
+    Low,
+    #[default]
+    Normal,
+    High,
+}
+
+/// A button drawn on a toast. Clicking it queues `id` for the app to read back
+/// through `drain_pending_toast_actions`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ToastAction {
+    pub label: String,
+    pub id: String,
+}
+
+impl ToastAction {
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            id: id.into(),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Toast {
     pub id: u64,
     pub kind: ToastKind,
     pub message: String,
+    pub actions: Vec<ToastAction>,
+    /// Keep the toast up after one of its actions is clicked.
+    pub sticky: bool,
+    pub priority: ToastPriority,
     pub created_at: Instant,
     pub paused_at: Option<Instant>,
     pub paused_total: Duration,
@@ -59,6 +151,48 @@ impl Toast {
         1.0
     }
 
+    /// Wraps the message into lines of at most `max_chars_per_line` characters,
+    /// breaking at spaces where possible and mid-word only for words that don't
+    /// fit on a line of their own. Newlines in the message are kept.
+    pub fn wrapped_lines(&self, max_chars_per_line: usize) -> Vec<String> {
+        let max_chars = max_chars_per_line.max(1);
+        let mut lines = Vec::new();
+        for paragraph in self.message.lines() {
+            let mut line = String::new();
+            let mut line_chars = 0;
+            for word in paragraph.split_whitespace() {
+                let mut word_chars = word.chars().count();
+                if line_chars > 0 && line_chars + 1 + word_chars <= max_chars {
+                    line.push(' ');
+                    line.push_str(word);
+                    line_chars += 1 + word_chars;
+                    continue;
+                }
+                if line_chars > 0 {
+                    lines.push(std::mem::take(&mut line));
+                }
+
+                let mut rest = word;
+                while word_chars > max_chars {
+                    let split = rest
+                        .char_indices()
+                        .nth(max_chars)
+                        .map_or(rest.len(), |(index, _)| index);
+                    lines.push(rest[..split].to_string());
+                    rest = &rest[split..];
+                    word_chars -= max_chars;
+                }
+                line.push_str(rest);
+                line_chars = word_chars;
+            }
+            lines.push(line);
+        }
+        if lines.is_empty() {
+            lines.push(String::new());
+        }
+        lines
+    }
+
     /// Returns vertical offset for slide-in animation (0.0 = final position)
     pub fn slide_offset(&self) -> f32 {
         let elapsed_ms = self.elapsed().as_millis() as u64;
@@ -78,13 +212,43 @@ impl Toast {
 pub struct ToastRequest {
     pub kind: ToastKind,
     pub message: String,
-    pub duration: Duration,
+    pub duration: ToastDuration,
+    pub actions: Vec<ToastAction>,
+    pub sticky: bool,
+    pub priority: ToastPriority,
 }
 
-#[derive(Default)]
+/// Toasts shown at once before the oldest collapse into a "+N more" summary.
+pub const DEFAULT_MAX_VISIBLE_TOASTS: usize = 5;
+/// Stands in for the "+N more" summary wherever a toast ID is expected, e.g. as the
+/// hovered ID passed to `tick_with_hovered`.
+pub const OVERFLOW_SUMMARY_ID: u64 = u64::MAX;
+/// Lines a toast message wraps onto before the rest is cut with an ellipsis.
+pub const DEFAULT_TOAST_MAX_LINES: usize = 4;
+
 pub struct ToastManager {
     next_id: u64,
     active: Vec<Toast>,
+    on_expire: Option<Box<dyn FnMut(u64)>>,
+    max_visible: usize,
+    max_lines: usize,
+    durations: ToastDurations,
+    /// Set while the pointer is over the stack after hovering the summary.
+    overflow_expanded: bool,
+}
+
+impl Default for ToastManager {
+    fn default() -> Self {
+        Self {
+            next_id: 0,
+            active: Vec::new(),
+            on_expire: None,
+            max_visible: DEFAULT_MAX_VISIBLE_TOASTS,
+            max_lines: DEFAULT_TOAST_MAX_LINES,
+            durations: ToastDurations::default(),
+            overflow_expanded: false,
+        }
+    }
 }
 
 impl ToastManager {
@@ -96,17 +260,113 @@ impl ToastManager {
         &self.active
     }
 
+    /// Sets the per-kind durations this manager gives toasts queued without a
+    /// fixed duration, such as those from `info` or `error_long`.
+    pub fn set_durations(&mut self, durations: ToastDurations) {
+        self.durations = durations;
+    }
+
+    /// Wrap messages onto at most `max_lines` lines (at least one).
+    pub fn set_max_lines(&mut self, max_lines: usize) {
+        self.max_lines = max_lines.max(1);
+    }
+
+    /// The toast's message wrapped to `max_chars_per_line` and capped at the
+    /// manager's line limit, with an ellipsis ending the last line when cut.
+    pub fn message_lines(&self, toast: &Toast, max_chars_per_line: usize) -> Vec<String> {
+        let mut lines = toast.wrapped_lines(max_chars_per_line);
+        if lines.len() > self.max_lines {
+            lines.truncate(self.max_lines);
+            if let Some(last) = lines.last_mut() {
+                let keep = max_chars_per_line.max(1) - 1;
+                if last.chars().count() > keep {
+                    let end = last
+                        .char_indices()
+                        .nth(keep)
+                        .map_or(last.len(), |(index, _)| index);
+                    last.truncate(end);
+                }
+                last.truncate(last.trim_end().len());
+                last.push('\u{2026}');
+            }
+        }
+        lines
+    }
+
+    /// Show at most `max_visible` toasts (at least one); older ones collapse into a
+    /// summary until it is hovered.
+    pub fn set_max_visible(&mut self, max_visible: usize) {
+        self.max_visible = max_visible.max(1);
+    }
+
+    /// Toasts that fit under the limit in stack order, or every toast while the
+    /// summary is expanded.
+    pub fn visible(&self) -> Vec<&Toast> {
+        let collapsed = self.collapsed();
+        self.active
+            .iter()
+            .zip(collapsed)
+            .filter(|(_, collapsed)| self.overflow_expanded || !collapsed)
+            .map(|(toast, _)| toast)
+            .collect()
+    }
+
+    /// Toasts collapsed into the "+N more" summary; 0 means no summary is shown.
+    pub fn overflow_count(&self) -> usize {
+        if self.overflow_expanded {
+            0
+        } else {
+            self.collapsed()
+                .into_iter()
+                .filter(|&collapsed| collapsed)
+                .count()
+        }
+    }
+
+    /// Which toasts go into the summary when it isn't expanded: the oldest Low
+    /// toasts first, then the oldest Normal ones. High toasts always stay visible.
+    fn collapsed(&self) -> Vec<bool> {
+        let mut collapsed = vec![false; self.active.len()];
+        let mut excess = self.active.len().saturating_sub(self.max_visible);
+        for priority in [ToastPriority::Low, ToastPriority::Normal] {
+            for (index, toast) in self.active.iter().enumerate() {
+                if excess == 0 {
+                    return collapsed;
+                }
+                if toast.priority == priority {
+                    collapsed[index] = true;
+                    excess -= 1;
+                }
+            }
+        }
+        collapsed
+    }
+
+    /// Adds `toast` after every toast of the same or higher priority, so `active`
+    /// stays ordered by priority, then insertion.
+    fn insert_by_priority(&mut self, toast: Toast) {
+        let index = self
+            .active
+            .iter()
+            .position(|existing| existing.priority < toast.priority)
+            .unwrap_or(self.active.len());
+        self.active.insert(index, toast);
+    }
+
     pub fn push(&mut self, request: ToastRequest) -> u64 {
         let id = self.next_id;
         self.next_id = self.next_id.wrapping_add(1);
-        self.active.push(Toast {
+        self.insert_by_priority(Toast {
             id,
             kind: request.kind,
             message: request.message,
+            actions: request.actions,
+            sticky: request.sticky,
+            priority: request.priority,
             created_at: Instant::now(),
             paused_at: None,
             paused_total: Duration::ZERO,
-            duration: request.duration,
+            duration: self.durations.resolve(request.kind, request.duration),
         });
         id
     }
@@ -115,11 +375,54 @@ impl ToastManager {
         self.active.retain(|toast| toast.id != id);
     }
 
-    /// Tick with optional hovered toast ID - hovered toasts don't expire
-    pub fn tick_with_hovered(&mut self, hovered_id: Option<u64>) {
+    /// Queue `action_id` of toast `id` for `drain_pending_toast_actions`, then dismiss
+    /// the toast unless it is sticky. Unknown toasts and actions are ignored.
+    pub fn activate_action(&mut self, id: u64, action_id: &str) {
+        let Some(toast) = self.active.iter().find(|toast| toast.id == id) else {
+            return;
+        };
+        if !toast.actions.iter().any(|action| action.id == action_id) {
+            return;
+        }
+
+        let sticky = toast.sticky;
+        pending_toast_actions()
+            .lock()
+            .expect("toast action queue lock poisoned")
+            .push(ToastActionEvent {
+                toast_id: id,
+                action_id: action_id.to_string(),
+            });
+        if !sticky {
+            self.dismiss(id);
+        }
+    }
+
+    /// Register a callback run with the ID of each toast whose duration runs out.
+    /// Replaces any previously registered callback; dismissed toasts don't trigger it.
+    pub fn on_expire(&mut self, callback: Box<dyn FnMut(u64)>) {
+        self.on_expire = Some(callback);
+    }
+
+    /// Tick with optional hovered toast ID - hovered toasts don't expire.
+    /// Returns the IDs of toasts that expired during this tick.
+    pub fn tick_with_hovered(&mut self, hovered_id: Option<u64>) -> Vec<u64> {
+        // Hovering the summary expands it; it stays open until the pointer leaves
+        // the stack, and the toasts it held are paused meanwhile.
+        if hovered_id == Some(OVERFLOW_SUMMARY_ID) {
+            self.overflow_expanded = true;
+        } else if hovered_id.is_none() {
+            self.overflow_expanded = false;
+        }
+        let collapsed = if self.overflow_expanded {
+            self.collapsed()
+        } else {
+            vec![false; self.active.len()]
+        };
+
         let now = Instant::now();
-        for toast in self.active.iter_mut() {
-            let is_hovered = hovered_id == Some(toast.id);
+        for (toast, collapsed) in self.active.iter_mut().zip(collapsed) {
+            let is_hovered = hovered_id == Some(toast.id) || collapsed;
             match (is_hovered, toast.paused_at) {
                 (true, None) => {
                     // Refresh lifetime when hovering begins so repeated hover keeps the toast alive.
@@ -135,11 +438,27 @@ impl ToastManager {
             }
         }
 
-        self.active.retain(|toast| toast.elapsed() < toast.duration);
+        let expired: Vec<u64> = self
+            .active
+            .iter()
+            .filter(|toast| toast.elapsed() >= toast.duration)
+            .map(|toast| toast.id)
+            .collect();
+        if expired.is_empty() {
+            return expired;
+        }
+
+        self.active.retain(|toast| !expired.contains(&toast.id));
+        if let Some(on_expire) = self.on_expire.as_mut() {
+            for &id in &expired {
+                on_expire(id);
+            }
+        }
+        expired
     }
 
-    pub fn tick(&mut self) {
-        self.tick_with_hovered(None);
+    pub fn tick(&mut self) -> Vec<u64> {
+        self.tick_with_hovered(None)
     }
 
     /// Pause a toast's timer.
@@ -176,14 +495,17 @@ impl ToastManager {
     }
 
     pub fn push_with_id(&mut self, request: ToastRequestWithId) {
-        self.active.push(Toast {
+        self.insert_by_priority(Toast {
             id: request.id,
             kind: request.kind,
             message: request.message,
+            actions: Vec::new(),
+            sticky: false,
+            priority: ToastPriority::Normal,
             created_at: Instant::now(),
             paused_at: None,
             paused_total: Duration::ZERO,
-            duration: request.duration,
+            duration: self.durations.resolve(request.kind, request.duration),
         });
     }
 
@@ -191,9 +513,9 @@ impl ToastManager {
         if let Some(toast) = self.active.iter_mut().find(|t| t.id == update.id) {
             toast.kind = update.kind;
             toast.message = update.message;
-            // Reset duration to default for non-loading toasts
+            // Reset duration to the kind's default for non-loading toasts
             if update.kind != ToastKind::Loading {
-                toast.duration = DEFAULT_TOAST_DURATION;
+                toast.duration = self.durations.for_kind(update.kind);
                 // Reset created_at so it starts fresh timing from now
                 toast.created_at = Instant::now();
                 toast.paused_at = None;
@@ -221,6 +543,9 @@ impl ToastManager {
 }
 
 const DEFAULT_TOAST_DURATION: Duration = Duration::from_millis(3000);
+const LONG_TOAST_DURATION: Duration = Duration::from_millis(6000);
+const LONG_ERROR_TOAST_DURATION: Duration = Duration::from_millis(8000);
+const LOADING_TOAST_DURATION: Duration = Duration::from_secs(60 * 60); // effectively indefinite
 
 static TOAST_QUEUE: OnceLock<Mutex<Vec<ToastRequest>>> = OnceLock::new();
 
@@ -229,12 +554,29 @@ fn queue() -> &'static Mutex<Vec<ToastRequest>> {
 }
 
 pub fn enqueue_toast(kind: ToastKind, message: impl Into<String>, duration: Option<Duration>) {
-    let request = ToastRequest {
+    enqueue_toast_with_actions(kind, message, duration, Vec::new(), false);
+}
+
+/// Enqueue a toast with action buttons. Clicking one dismisses the toast unless
+/// `sticky` is set.
+pub fn enqueue_toast_with_actions(
+    kind: ToastKind,
+    message: impl Into<String>,
+    duration: Option<Duration>,
+    actions: Vec<ToastAction>,
+    sticky: bool,
+) {
+    enqueue_toast_request(ToastRequest {
         kind,
         message: message.into(),
-        duration: duration.unwrap_or(DEFAULT_TOAST_DURATION),
-    };
+        duration: duration.map_or(ToastDuration::Default, ToastDuration::Fixed),
+        actions,
+        sticky,
+        priority: ToastPriority::Normal,
+    });
+}
 
+pub fn enqueue_toast_request(request: ToastRequest) {
     let mut queue = queue().lock().expect("toast queue lock poisoned");
     queue.push(request);
 }
@@ -260,32 +602,41 @@ pub fn error(message: impl Into<String>) {
     enqueue_toast(ToastKind::Error, message, None);
 }
 
-/// Show an info toast that stays longer (6 seconds)
+fn enqueue_long_toast(kind: ToastKind, message: impl Into<String>, priority: ToastPriority) {
+    enqueue_toast_request(ToastRequest {
+        kind,
+        message: message.into(),
+        duration: ToastDuration::Long,
+        actions: Vec::new(),
+        sticky: false,
+        priority,
+    });
+}
+
+/// Show an info toast that stays longer (6 seconds by default)
 pub fn info_long(message: impl Into<String>) {
-    enqueue_toast(ToastKind::Info, message, Some(Duration::from_millis(6000)));
+    enqueue_long_toast(ToastKind::Info, message, ToastPriority::Normal);
 }
 
-/// Show a success toast that stays longer (6 seconds)
+/// Show a success toast that stays longer (6 seconds by default)
 pub fn success_long(message: impl Into<String>) {
-    enqueue_toast(
-        ToastKind::Success,
-        message,
-        Some(Duration::from_millis(6000)),
-    );
+    enqueue_long_toast(ToastKind::Success, message, ToastPriority::Normal);
 }
 
-/// Show an error toast that stays longer (8 seconds)
+/// Show an error toast that stays longer (8 seconds by default)
 pub fn error_long(message: impl Into<String>) {
-    enqueue_toast(ToastKind::Error, message, Some(Duration::from_millis(8000)));
+    enqueue_long_toast(ToastKind::Error, message, ToastPriority::Normal);
+}
+
+/// Show a long-lived error toast that is listed above other toasts and never
+/// collapsed, for failures that must not be missed.
+pub fn error_high(message: impl Into<String>) {
+    enqueue_long_toast(ToastKind::Error, message, ToastPriority::High);
 }
 
 /// Show a loading toast (stays indefinitely until updated or dismissed)
 pub fn loading(message: impl Into<String>) -> u64 {
-    enqueue_toast_with_id(
-        ToastKind::Loading,
-        message,
-        Some(Duration::from_secs(60 * 60)), // 1 hour (effectively indefinite)
-    )
+    enqueue_toast_with_id(ToastKind::Loading, message, Some(LOADING_TOAST_DURATION))
 }
 
 /// Enqueue a toast and return its ID for later updates
@@ -301,7 +652,7 @@ pub fn enqueue_toast_with_id(
         id,
         kind,
         message: message.into(),
-        duration: duration.unwrap_or(DEFAULT_TOAST_DURATION),
+        duration: duration.map_or(ToastDuration::Default, ToastDuration::Fixed),
     };
 
     let mut queue = pending_with_id().lock().expect("toast queue lock poisoned");
@@ -327,7 +678,7 @@ pub struct ToastRequestWithId {
     pub id: u64,
     pub kind: ToastKind,
     pub message: String,
-    pub duration: Duration,
+    pub duration: ToastDuration,
 }
 
 #[derive(Clone, Debug)]
@@ -342,9 +693,17 @@ pub struct ToastDismiss {
     pub id: u64,
 }
 
+/// A click on one of a toast's action buttons.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ToastActionEvent {
+    pub toast_id: u64,
+    pub action_id: String,
+}
+
 static TOAST_QUEUE_WITH_ID: OnceLock<Mutex<Vec<ToastRequestWithId>>> = OnceLock::new();
 static TOAST_UPDATE_QUEUE: OnceLock<Mutex<Vec<ToastUpdate>>> = OnceLock::new();
 static TOAST_DISMISS_QUEUE: OnceLock<Mutex<Vec<ToastDismiss>>> = OnceLock::new();
+static TOAST_ACTION_QUEUE: OnceLock<Mutex<Vec<ToastActionEvent>>> = OnceLock::new();
 
 fn pending_with_id() -> &'static Mutex<Vec<ToastRequestWithId>> {
     TOAST_QUEUE_WITH_ID.get_or_init(|| Mutex::new(Vec::new()))
@@ -358,6 +717,10 @@ fn pending_dismisses() -> &'static Mutex<Vec<ToastDismiss>> {
     TOAST_DISMISS_QUEUE.get_or_init(|| Mutex::new(Vec::new()))
 }
 
+fn pending_toast_actions() -> &'static Mutex<Vec<ToastActionEvent>> {
+    TOAST_ACTION_QUEUE.get_or_init(|| Mutex::new(Vec::new()))
+}
+
 pub fn drain_pending_with_id() -> Vec<ToastRequestWithId> {
     let mut queue = pending_with_id().lock().expect("toast queue lock poisoned");
     std::mem::take(&mut *queue)
@@ -383,3 +746,207 @@ pub fn drain_pending_dismisses() -> Vec<ToastDismiss> {
         .expect("toast dismiss queue lock poisoned");
     std::mem::take(&mut *queue)
 }
+
+pub fn drain_pending_toast_actions() -> Vec<ToastActionEvent> {
+    let mut queue = pending_toast_actions()
+        .lock()
+        .expect("toast action queue lock poisoned");
+    std::mem::take(&mut *queue)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(message: &str) -> ToastRequest {
+        ToastRequest {
+            kind: ToastKind::Info,
+            message: message.to_string(),
+            duration: ToastDuration::Default,
+            actions: Vec::new(),
+            sticky: false,
+            priority: ToastPriority::Normal,
+        }
+    }
+
+    #[test]
+    fn toasts_past_the_limit_collapse_into_a_summary() {
+        let mut manager = ToastManager::new();
+        manager.set_max_visible(3);
+        for index in 0..8 {
+            manager.push(request(&format!("toast {}", index)));
+        }
+
+        let visible: Vec<&str> = manager
+            .visible()
+            .iter()
+            .map(|toast| toast.message.as_str())
+            .collect();
+        assert_eq!(visible, ["toast 5", "toast 6", "toast 7"]);
+        assert_eq!(manager.overflow_count(), 5);
+    }
+
+    #[test]
+    fn high_priority_toasts_lead_the_stack_and_never_collapse() {
+        let mut manager = ToastManager::new();
+        manager.set_max_visible(3);
+        manager.push(ToastRequest {
+            priority: ToastPriority::Low,
+            ..request("low")
+        });
+        for index in 0..4 {
+            manager.push(request(&format!("info {}", index)));
+        }
+        for message in ["error 0", "error 1"] {
+            manager.push(ToastRequest {
+                priority: ToastPriority::High,
+                ..request(message)
+            });
+        }
+
+        let order: Vec<&str> = manager
+            .active()
+            .iter()
+            .map(|toast| toast.message.as_str())
+            .collect();
+        assert_eq!(
+            order,
+            [
+                "error 0", "error 1", "info 0", "info 1", "info 2", "info 3", "low"
+            ]
+        );
+
+        let visible: Vec<&str> = manager
+            .visible()
+            .iter()
+            .map(|toast| toast.message.as_str())
+            .collect();
+        assert_eq!(visible, ["error 0", "error 1", "info 3"]);
+        assert_eq!(manager.overflow_count(), 4);
+
+        manager.set_max_visible(1);
+        assert_eq!(manager.visible().len(), 2);
+        assert_eq!(manager.overflow_count(), 5);
+    }
+
+    #[test]
+    fn hovering_the_summary_expands_and_pauses_collapsed_toasts() {
+        let mut manager = ToastManager::new();
+        manager.set_max_visible(3);
+        for index in 0..8 {
+            manager.push(request(&format!("toast {}", index)));
+        }
+
+        manager.tick_with_hovered(Some(OVERFLOW_SUMMARY_ID));
+        assert_eq!(manager.visible().len(), 8);
+        assert_eq!(manager.overflow_count(), 0);
+        assert!(
+            manager.active()[..5]
+                .iter()
+                .all(|toast| toast.paused_at.is_some())
+        );
+        assert!(
+            manager.active()[5..]
+                .iter()
+                .all(|toast| toast.paused_at.is_none())
+        );
+
+        // Moving onto one of the expanded toasts keeps the stack open.
+        let first = manager.active()[0].id;
+        manager.tick_with_hovered(Some(first));
+        assert_eq!(manager.visible().len(), 8);
+
+        manager.tick_with_hovered(None);
+        assert_eq!(manager.visible().len(), 3);
+        assert_eq!(manager.overflow_count(), 5);
+    }
+
+    #[test]
+    fn configured_durations_apply_to_updates_and_queued_toasts() {
+        let durations = ToastDurations {
+            error: Duration::from_millis(6000),
+            ..ToastDurations::default()
+        };
+        let mut manager = ToastManager::new();
+        manager.set_durations(durations);
+
+        manager.push_with_id(ToastRequestWithId {
+            id: 7,
+            kind: ToastKind::Loading,
+            message: "Checking".to_string(),
+            duration: ToastDuration::Fixed(LOADING_TOAST_DURATION),
+        });
+        manager.apply_update(ToastUpdate {
+            id: 7,
+            kind: ToastKind::Error,
+            message: "Failed".to_string(),
+        });
+        assert_eq!(manager.active()[0].duration, Duration::from_millis(6000));
+
+        manager.push(ToastRequest {
+            kind: ToastKind::Error,
+            duration: ToastDuration::Long,
+            ..request("Failed for longer")
+        });
+        manager.push(ToastRequest {
+            duration: ToastDuration::Long,
+            ..request("Done")
+        });
+        let durations: Vec<Duration> = manager.active()[1..]
+            .iter()
+            .map(|toast| toast.duration)
+            .collect();
+        assert_eq!(
+            durations,
+            vec![Duration::from_millis(16000), LONG_TOAST_DURATION]
+        );
+    }
+
+    fn toast_with_message(message: &str) -> Toast {
+        let mut manager = ToastManager::new();
+        manager.push(request(message));
+        manager.active()[0].clone()
+    }
+
+    #[test]
+    fn wrapping_breaks_at_spaces_and_keeps_newlines() {
+        let toast = toast_with_message("Configuration reloaded from disk\nsee logs");
+
+        assert_eq!(
+            toast.wrapped_lines(14),
+            ["Configuration", "reloaded from", "disk", "see logs"]
+        );
+        assert_eq!(toast.wrapped_lines(80).len(), 2);
+    }
+
+    #[test]
+    fn wrapping_splits_a_word_longer_than_a_line() {
+        let toast = toast_with_message("Failed to open /usr/local/share/termy/themes.json now");
+
+        assert_eq!(
+            toast.wrapped_lines(12),
+            [
+                "Failed to",
+                "open",
+                "/usr/local/s",
+                "hare/termy/t",
+                "hemes.json",
+                "now"
+            ]
+        );
+    }
+
+    #[test]
+    fn message_lines_cap_with_an_ellipsis() {
+        let mut manager = ToastManager::new();
+        manager.set_max_lines(2);
+        manager.push(request("one two three four five six"));
+        let toast = &manager.active()[0];
+
+        assert_eq!(
+            manager.message_lines(toast, 9),
+            ["one two", "three\u{2026}"]
+        );
+        assert_eq!(manager.message_lines(toast, 5), ["one", "two\u{2026}"]);
+    }
+}
@@ -1,7 +1,7 @@
 use crate::config::{CustomColors, SHELL_DECIDE_THEME_ID};
 use alacritty_terminal::vte::ansi::{Color as AnsiColor, NamedColor, Rgb as AnsiRgb};
 use gpui::Rgba;
-use termy_themes as themes;
+use termy_themes::{self as themes, contrast_ratio};
 
 #[derive(Clone)]
 pub struct TerminalColors {
@@ -9,6 +9,7 @@ pub struct TerminalColors {
     pub foreground: Rgba,
     pub background: Rgba,
     pub cursor: Rgba,
+    pub appearance: themes::ThemeAppearance,
 }
 
 impl Default for TerminalColors {
@@ -35,17 +36,22 @@ impl Default for TerminalColors {
             foreground: rgba(0xE5, 0xE5, 0xE5),
             background: rgba(0x1E, 0x1E, 0x1E),
             cursor: rgba(0xFF, 0xFF, 0xFF),
+            appearance: themes::ThemeAppearance::Dark,
         }
     }
 }
 
 impl TerminalColors {
-    pub fn from_theme(theme: &str, custom: &CustomColors) -> Self {
+    pub fn from_theme(theme: &str, custom: &CustomColors, for_transparency: bool) -> Self {
         let mut colors = if theme.eq_ignore_ascii_case(SHELL_DECIDE_THEME_ID) {
             Self::default()
         } else {
             let theme_colors = themes::resolve_theme(theme).unwrap_or_else(themes::termy);
-            Self::from_theme_colors(theme_colors)
+            if for_transparency {
+                Self::from_theme_colors(theme_colors.for_transparency())
+            } else {
+                Self::from_theme_colors(theme_colors)
+            }
         };
         colors.apply_custom(custom);
         colors
@@ -57,15 +63,32 @@ impl TerminalColors {
             foreground: theme.foreground,
             background: theme.background,
             cursor: theme.cursor,
+            appearance: theme.appearance,
+        }
+    }
+
+    fn to_theme_colors(&self) -> themes::ThemeColors {
+        themes::ThemeColors {
+            ansi: self.ansi,
+            foreground: self.foreground,
+            background: self.background,
+            cursor: self.cursor,
+            appearance: self.appearance,
         }
     }
 
+    /// Blend toward `other`, for animating theme switches.
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        Self::from_theme_colors(self.to_theme_colors().lerp(&other.to_theme_colors(), t))
+    }
+
     fn apply_custom(&mut self, custom: &CustomColors) {
         if let Some(fg) = custom.foreground {
             self.foreground = fg;
         }
         if let Some(bg) = custom.background {
             self.background = bg;
+            self.appearance = themes::ThemeAppearance::from_background(bg);
         }
         if let Some(cursor) = custom.cursor {
             self.cursor = cursor;
@@ -86,6 +109,16 @@ impl TerminalColors {
         }
     }
 
+    /// Convert a bold foreground color, promoting ANSI colors 0-7 to their
+    /// bright variants (8-15) like classic terminals do.
+    pub fn convert_bold(&self, color: AnsiColor) -> Rgba {
+        match color {
+            AnsiColor::Named(named) if (named as usize) < 8 => self.ansi[named as usize + 8],
+            AnsiColor::Indexed(idx) if idx < 8 => self.ansi[idx as usize + 8],
+            _ => self.convert(color),
+        }
+    }
+
     fn named_color(&self, color: NamedColor) -> Rgba {
         match color {
             NamedColor::Black => self.ansi[0],
@@ -133,6 +166,51 @@ impl TerminalColors {
     }
 }
 
+/// Blend `fg` toward `target` until it reaches `minimum` contrast against `bg`.
+/// When `target` itself falls short, as on a background close to the theme
+/// foreground, black or white takes its place, whichever stands out more.
+/// A `minimum` of 1.0 or less leaves the color untouched.
+pub fn ensure_minimum_contrast(fg: Rgba, bg: Rgba, target: Rgba, minimum: f32) -> Rgba {
+    if minimum <= 1.0 || contrast_ratio(fg, bg) >= minimum {
+        return fg;
+    }
+    let target = if contrast_ratio(target, bg) >= minimum {
+        target
+    } else {
+        let (black, white) = (rgba(0, 0, 0), rgba(0xFF, 0xFF, 0xFF));
+        let extreme = if contrast_ratio(black, bg) >= contrast_ratio(white, bg) {
+            black
+        } else {
+            white
+        };
+        Rgba { a: fg.a, ..extreme }
+    };
+    if contrast_ratio(target, bg) < minimum {
+        return target;
+    }
+
+    // Contrast grows monotonically along the blend, so bisect for the smallest shift.
+    let (mut low, mut high) = (0.0_f32, 1.0_f32);
+    for _ in 0..12 {
+        let mid = (low + high) * 0.5;
+        if contrast_ratio(mix(fg, target, mid), bg) >= minimum {
+            high = mid;
+        } else {
+            low = mid;
+        }
+    }
+    mix(fg, target, high)
+}
+
+fn mix(from: Rgba, to: Rgba, t: f32) -> Rgba {
+    Rgba {
+        r: from.r + (to.r - from.r) * t,
+        g: from.g + (to.g - from.g) * t,
+        b: from.b + (to.b - from.b) * t,
+        a: from.a,
+    }
+}
+
 /// Helper to create Rgba from u8 components
 fn rgba(r: u8, g: u8, b: u8) -> Rgba {
     Rgba {
@@ -142,3 +220,39 @@ fn rgba(r: u8, g: u8, b: u8) -> Rgba {
         a: 1.0,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn minimum_contrast_only_shifts_low_contrast_colors() {
+        let background = rgba(0x1E, 0x1E, 0x1E);
+        let foreground = rgba(0xE5, 0xE5, 0xE5);
+        let faint = rgba(0x2A, 0x2A, 0x2A);
+
+        assert_eq!(
+            ensure_minimum_contrast(faint, background, foreground, 1.0),
+            faint
+        );
+        assert_eq!(
+            ensure_minimum_contrast(foreground, background, foreground, 4.5),
+            foreground
+        );
+
+        let bumped = ensure_minimum_contrast(faint, background, foreground, 4.5);
+        assert!(contrast_ratio(bumped, background) >= 4.5);
+        assert!(bumped.r < foreground.r);
+
+        // A light bar close to the theme foreground shifts toward black instead.
+        let light_bar = rgba(0xD0, 0xD0, 0xD0);
+        let pale = rgba(0xC0, 0xC0, 0xC0);
+        let on_bar = ensure_minimum_contrast(pale, light_bar, foreground, 4.5);
+        assert!(contrast_ratio(on_bar, light_bar) >= 4.5);
+        assert!(on_bar.r < pale.r);
+        assert_eq!(
+            ensure_minimum_contrast(pale, light_bar, foreground, 21.0),
+            rgba(0, 0, 0)
+        );
+    }
+}
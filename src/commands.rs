@@ -3,6 +3,9 @@ use gpui::{FocusHandle, KeyBinding, Window, actions};
 const GLOBAL_CONTEXT: Option<&str> = None;
 const TERMINAL_CONTEXT: Option<&str> = Some("Terminal");
 const INLINE_INPUT_CONTEXT: Option<&str> = Some("InlineInput");
+const SEARCH_CONTEXT: Option<&str> = Some("Search");
+/// Home/End jump between matches in the search bar instead of moving the caret.
+const INLINE_INPUT_OUTSIDE_SEARCH_CONTEXT: Option<&str> = Some("InlineInput && !Search");
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CommandPaletteVisibility {
@@ -326,6 +329,29 @@ define_commands!(
     (CloseSearch, "close_search", TERMINAL_CONTEXT, None),
     (SearchNext, "search_next", TERMINAL_CONTEXT, None),
     (SearchPrevious, "search_previous", TERMINAL_CONTEXT, None),
+    (
+        CopyMatchAndNext,
+        "copy_match_and_next",
+        SEARCH_CONTEXT,
+        None
+    ),
+    (
+        CopySearchMatches,
+        "copy_search_matches",
+        SEARCH_CONTEXT,
+        None
+    ),
+    (SearchFirstMatch, "search_first_match", SEARCH_CONTEXT, None),
+    (SearchLastMatch, "search_last_match", SEARCH_CONTEXT, None),
+    (SearchNextPage, "search_next_page", SEARCH_CONTEXT, None),
+    (
+        SearchPreviousPage,
+        "search_previous_page",
+        SEARCH_CONTEXT,
+        None
+    ),
+    (ReplaceCurrent, "replace_current", SEARCH_CONTEXT, None),
+    (ReplaceAll, "replace_all", SEARCH_CONTEXT, None),
     (
         ToggleSearchCaseSensitive,
         "toggle_search_case_sensitive",
@@ -338,6 +364,12 @@ define_commands!(
         TERMINAL_CONTEXT,
         None
     ),
+    (
+        ToggleSearchWholeWord,
+        "toggle_search_whole_word",
+        TERMINAL_CONTEXT,
+        None
+    ),
     (
         InstallCli,
         "install_cli",
@@ -348,6 +380,276 @@ define_commands!(
             CommandPaletteVisibility::Always
         ))
     ),
+    (
+        CopyWorkingDir,
+        "copy_working_dir",
+        TERMINAL_CONTEXT,
+        Some(palette(
+            "Copy Working Directory",
+            "cwd path folder clipboard pwd",
+            CommandPaletteVisibility::Always
+        ))
+    ),
+    (
+        RevealWorkingDir,
+        "reveal_working_dir",
+        TERMINAL_CONTEXT,
+        Some(palette(
+            "Reveal Working Directory",
+            "cwd path folder finder explorer file manager open",
+            CommandPaletteVisibility::Always
+        ))
+    ),
+    (
+        RunCliCommand,
+        "run_cli_command",
+        TERMINAL_CONTEXT,
+        Some(palette(
+            "Run CLI Command",
+            "termy cli config keybinds actions themes version help output",
+            CommandPaletteVisibility::Always
+        ))
+    ),
+    (
+        ScrollPageUp,
+        "scroll_page_up",
+        TERMINAL_CONTEXT,
+        Some(palette(
+            "Scroll Page Up",
+            "scrollback history pageup back",
+            CommandPaletteVisibility::Always
+        ))
+    ),
+    (
+        ScrollPageDown,
+        "scroll_page_down",
+        TERMINAL_CONTEXT,
+        Some(palette(
+            "Scroll Page Down",
+            "scrollback history pagedown forward",
+            CommandPaletteVisibility::Always
+        ))
+    ),
+    (
+        ScrollHalfPageUp,
+        "scroll_half_page_up",
+        TERMINAL_CONTEXT,
+        Some(palette(
+            "Scroll Half Page Up",
+            "scrollback history half back",
+            CommandPaletteVisibility::Always
+        ))
+    ),
+    (
+        ScrollHalfPageDown,
+        "scroll_half_page_down",
+        TERMINAL_CONTEXT,
+        Some(palette(
+            "Scroll Half Page Down",
+            "scrollback history half forward",
+            CommandPaletteVisibility::Always
+        ))
+    ),
+    (
+        NextDiagnostic,
+        "next_diagnostic",
+        TERMINAL_CONTEXT,
+        Some(palette(
+            "Jump to Next Error/Warning",
+            "diagnostics errors warnings failed build output next",
+            CommandPaletteVisibility::Always
+        ))
+    ),
+    (
+        PreviousDiagnostic,
+        "previous_diagnostic",
+        TERMINAL_CONTEXT,
+        Some(palette(
+            "Jump to Previous Error/Warning",
+            "diagnostics errors warnings failed build output previous",
+            CommandPaletteVisibility::Always
+        ))
+    ),
+    (
+        IncreaseOpacity,
+        "increase_opacity",
+        TERMINAL_CONTEXT,
+        Some(palette(
+            "Increase Background Opacity",
+            "opacity transparency background more opaque",
+            CommandPaletteVisibility::Always
+        ))
+    ),
+    (
+        DecreaseOpacity,
+        "decrease_opacity",
+        TERMINAL_CONTEXT,
+        Some(palette(
+            "Decrease Background Opacity",
+            "opacity transparency background more transparent",
+            CommandPaletteVisibility::Always
+        ))
+    ),
+    (
+        ClearTabTitle,
+        "clear_tab_title",
+        TERMINAL_CONTEXT,
+        Some(palette(
+            "Reset Tab Title",
+            "rename title automatic clear manual",
+            CommandPaletteVisibility::TabsOnly
+        ))
+    ),
+    (
+        ToggleFullscreen,
+        "toggle_fullscreen",
+        TERMINAL_CONTEXT,
+        Some(palette(
+            "Toggle Full Screen",
+            "fullscreen window maximize present",
+            CommandPaletteVisibility::Always
+        ))
+    ),
+    (
+        ToggleSearchOutputOnly,
+        "toggle_search_output_only",
+        TERMINAL_CONTEXT,
+        Some(palette(
+            "Toggle Search in Command Output Only",
+            "search find scope command output prompt shell integration",
+            CommandPaletteVisibility::Always
+        ))
+    ),
+    (
+        InsertCommandOutput,
+        "insert_command_output",
+        TERMINAL_CONTEXT,
+        Some(palette(
+            "Insert Command Output",
+            "run shell command paste output stdout date uuid",
+            CommandPaletteVisibility::Always
+        ))
+    ),
+    (
+        SaveScrollback,
+        "save_scrollback",
+        TERMINAL_CONTEXT,
+        Some(palette(
+            "Save Scrollback to File",
+            "export dump log history buffer ansi plain text file",
+            CommandPaletteVisibility::Always
+        ))
+    ),
+    (
+        CopyScreen,
+        "copy_screen",
+        TERMINAL_CONTEXT,
+        Some(palette(
+            "Copy Screen",
+            "copy visible viewport screen text clipboard",
+            CommandPaletteVisibility::Always
+        ))
+    ),
+    (
+        CopyScrollback,
+        "copy_scrollback",
+        TERMINAL_CONTEXT,
+        Some(palette(
+            "Copy Scrollback",
+            "copy entire buffer history scrollback text clipboard",
+            CommandPaletteVisibility::Always
+        ))
+    ),
+    (
+        SwitchProfile,
+        "switch_profile",
+        TERMINAL_CONTEXT,
+        Some(palette(
+            "Switch Tab Profile",
+            "profile font size presentation tab",
+            CommandPaletteVisibility::Always
+        ))
+    ),
+    (
+        ClearAllHighlights,
+        "clear_all_highlights",
+        TERMINAL_CONTEXT,
+        Some(palette(
+            "Clear Search and Highlights",
+            "clear search highlights diagnostics reset",
+            CommandPaletteVisibility::Always
+        ))
+    ),
+    (
+        ResumeOutput,
+        "resume_output",
+        TERMINAL_CONTEXT,
+        Some(palette(
+            "Resume Output (XON)",
+            "flow control xon ctrl-q unpause paused frozen",
+            CommandPaletteVisibility::Always
+        ))
+    ),
+    (
+        CycleCursorStyle,
+        "cycle_cursor_style",
+        TERMINAL_CONTEXT,
+        Some(palette(
+            "Cycle Cursor Style",
+            "cursor shape block line underline bar beam",
+            CommandPaletteVisibility::Always
+        ))
+    ),
+    (
+        ToggleShellIntegration,
+        "toggle_shell_integration",
+        TERMINAL_CONTEXT,
+        Some(palette(
+            "Toggle Shell Integration",
+            "shell integration termy env prompt title cwd",
+            CommandPaletteVisibility::Always
+        ))
+    ),
+    (
+        ToggleWhitespace,
+        "toggle_whitespace",
+        TERMINAL_CONTEXT,
+        Some(palette(
+            "Toggle Whitespace Markers",
+            "show render whitespace spaces tabs indentation dots arrows",
+            CommandPaletteVisibility::Always
+        ))
+    ),
+    (
+        DuplicateTabRerun,
+        "duplicate_tab_rerun",
+        TERMINAL_CONTEXT,
+        Some(palette(
+            "Duplicate Tab and Rerun Last Command",
+            "duplicate clone tab repeat rerun again last command build",
+            CommandPaletteVisibility::TabsOnly
+        ))
+    ),
+    (
+        ReloadThemes,
+        "reload_themes",
+        TERMINAL_CONTEXT,
+        Some(palette(
+            "Reload Themes",
+            "theme themes reload refresh rescan files disk colors",
+            CommandPaletteVisibility::Always
+        ))
+    ),
+    (
+        FoldLongLines,
+        "fold_long_lines",
+        TERMINAL_CONTEXT,
+        Some(palette(
+            "Fold Long Lines",
+            "fold collapse long lines minified json unfolded",
+            CommandPaletteVisibility::Always
+        ))
+    ),
 );
 
 actions!(
@@ -378,8 +680,12 @@ pub fn inline_input_keybindings() -> Vec<KeyBinding> {
         KeyBinding::new("shift-left", InlineSelectLeft, INLINE_INPUT_CONTEXT),
         KeyBinding::new("shift-right", InlineSelectRight, INLINE_INPUT_CONTEXT),
         KeyBinding::new("secondary-a", InlineSelectAll, INLINE_INPUT_CONTEXT),
-        KeyBinding::new("home", InlineMoveToStart, INLINE_INPUT_CONTEXT),
-        KeyBinding::new("end", InlineMoveToEnd, INLINE_INPUT_CONTEXT),
+        KeyBinding::new(
+            "home",
+            InlineMoveToStart,
+            INLINE_INPUT_OUTSIDE_SEARCH_CONTEXT,
+        ),
+        KeyBinding::new("end", InlineMoveToEnd, INLINE_INPUT_OUTSIDE_SEARCH_CONTEXT),
         KeyBinding::new("secondary-left", InlineMoveToStart, INLINE_INPUT_CONTEXT),
         KeyBinding::new("secondary-right", InlineMoveToEnd, INLINE_INPUT_CONTEXT),
         KeyBinding::new(
@@ -5,6 +5,8 @@ use std::{
     process::Command,
     sync::{LazyLock, Mutex},
 };
+use termy_cli_core::{color_scheme, config::replace_or_insert_section};
+use termy_themes::ThemeProvider;
 
 static CONFIG_CHANGE_SUBSCRIBERS: LazyLock<Mutex<Vec<flume::Sender<()>>>> =
     LazyLock::new(|| Mutex::new(Vec::new()));
@@ -21,19 +23,41 @@ const MAX_SCROLLBACK_HISTORY: usize = 100_000;
 const DEFAULT_INACTIVE_TAB_SCROLLBACK: Option<usize> = None;
 const MIN_MOUSE_SCROLL_MULTIPLIER: f32 = 0.1;
 const MAX_MOUSE_SCROLL_MULTIPLIER: f32 = 1_000.0;
+const DEFAULT_KEY_REPEAT_DELAY_MS: u64 = 400;
+const MAX_KEY_REPEAT_DELAY_MS: u64 = 2_000;
+const DEFAULT_KEY_REPEAT_RATE: u32 = 30;
+const DEFAULT_TOAST_DURATION_MS: u64 = 3_000;
+const MAX_TOAST_DURATION_MS: u64 = 60_000;
+const MAX_KEY_REPEAT_RATE: u32 = 100;
 const DEFAULT_CURSOR_BLINK: bool = true;
+const DEFAULT_LONG_LINE_LIMIT: usize = 10_000;
+const MIN_MINIMUM_CONTRAST: f32 = 1.0;
+const MAX_MINIMUM_CONTRAST: f32 = 21.0;
 const DEFAULT_WARN_ON_QUIT_WITH_RUNNING_PROCESS: bool = true;
+const DEFAULT_DIAGNOSTIC_PATTERNS: &[&str] = &[r"(?i)\berror\b", r"(?i)\bwarning\b", "FAILED"];
 
 const DEFAULT_CONFIG: &str = "# Main settings\n\
 theme = termy\n\
+# Resolved themes kept in memory for quick switching (0 = no cache)\n\
+# theme_cache_size = 32\n\
 # TERM value for child shells and terminal apps\n\
 term = xterm-256color\n\
 # Startup directory for new terminal sessions (~ supported)\n\
 # working_dir = ~/Documents\n\
 # Show compact tab strip (stays visible with one tab)\n\
 # use_tabs = true\n\
+# When the tab strip is shown: always | auto (more than one tab) | hover_reveal\n\
+# tab_bar_visibility = always\n\
+# New tabs keep the active tab's profile and zoom (false = start from the config font)\n\
+# inherit_tab_overrides = true\n\
+# Maximum number of open tabs (0 = unlimited)\n\
+# max_tabs = 0\n\
 # Warn before quitting when tabs are busy (running command/fullscreen TUI)\n\
 # warn_on_quit_with_running_process = true\n\
+# Confirm before quitting while more than one tab (or a busy tab) is open\n\
+# confirm_quit_multiple_tabs = false\n\
+# What a tab does when its shell exits: close | hold | restart\n\
+# on_shell_exit = hold\n\
 # Tab title mode. Supported values: smart, shell, explicit, static\n\
 # smart = manual rename > explicit title > shell/app title > fallback\n\
 tab_title_mode = smart\n\
@@ -49,32 +73,103 @@ tab_title_shell_integration = true\n\
 # Startup window size in pixels\n\
 window_width = 1280\n\
 window_height = 820\n\
+# How the window opens: windowed | maximized | fullscreen\n\
+# startup_window_mode = windowed\n\
 # Terminal font family\n\
 font_family = JetBrains Mono\n\
 # Terminal font size in pixels\n\
 font_size = 14\n\
-# Cursor style shared by terminal and inline inputs (line|block)\n\
+# Cursor style shared by terminal and inline inputs (line|block|underline|hollow_block)\n\
 # cursor_style = block\n\
 # Enable cursor blink for terminal and inline inputs\n\
 # cursor_blink = true\n\
+# Pick cursor colors that stay readable over the text beneath the cursor\n\
+# cursor_contrast = true\n\
 # Terminal background opacity (0.0 = fully transparent, 1.0 = opaque)\n\
 # background_opacity = 1.0\n\
 # Enable/disable platform blur for transparent backgrounds\n\
 # background_blur = false\n\
+# Force an opaque, unblurred background (true|false|auto; auto follows the OS setting)\n\
+# reduce_transparency = auto\n\
+# Brighten theme text colors while the background is translucent\n\
+# transparency_friendly_colors = false\n\
 # Inner terminal padding in pixels\n\
 padding_x = 12\n\
 padding_y = 8\n\
+# Cap the terminal width in columns and center it in wider windows (0 = fill the window)\n\
+# max_content_columns = 0\n\
 # Mouse wheel scroll speed multiplier\n\
 # mouse_scroll_multiplier = 3\n\
+# Per-device overrides (default to mouse_scroll_multiplier)\n\
+# wheel_scroll_multiplier = 3\n\
+# trackpad_scroll_multiplier = 3\n\
+# Reverse scroll direction per device\n\
+# invert_wheel_scroll = false\n\
+# invert_trackpad_scroll = false\n\
+# Jump back to the live prompt when typing while scrolled into history\n\
+# scroll_to_bottom_on_input = true\n\
+# Held search next/previous and page-scroll keys: delay before repeating (ms)\n\
+# and repeats per second (0 = use the OS key repeat)\n\
+# key_repeat_delay = 400\n\
+# key_repeat_rate = 30\n\
+# How long notifications stay on screen, per kind (ms)\n\
+# toast_duration_info = 3000\n\
+# toast_duration_success = 3000\n\
+# toast_duration_warning = 3000\n\
+# toast_duration_error = 3000\n\
+# Mark rows that soft-wrap onto the next row\n\
+# show_wrap_indicator = false\n\
+# Mark the spaces (·) and tabs (→) between text on each row: none | all\n\
+# render_whitespace = none\n\
+# Lines longer than long_line_limit characters: wrap | truncate (cut with an ellipsis) | fold (cut, click to unfold)\n\
+# long_lines = wrap\n\
+# long_line_limit = 10000\n\
+# Only treat text with an explicit http:// or https:// scheme as a clickable link\n\
+# require_url_scheme = false\n\
+# Drop trailing whitespace from each line of copied selections\n\
+# trim_trailing_whitespace_on_copy = true\n\
+# What a double-click selects: token (paths/URLs without surrounding quotes or punctuation) | word\n\
+# double_click_selection = token\n\
+# Strip control characters (ESC, NUL, ...) from pasted text; tab and newlines are kept\n\
+# sanitize_paste = true\n\
+# macOS: Option sends Meta (Option-b = ESC b) instead of composing characters like é\n\
+# macos_option_as_meta = false\n\
+# Draw bold text in the bright ANSI color variant (colors 0-7 become 8-15)\n\
+# bold_is_bright = false\n\
+# Draw box-drawing and block characters as built-in shapes so TUI borders join without gaps\n\
+# builtin_box_drawing = false\n\
+# Minimum text/background contrast ratio (1 = off, 4.5 = WCAG AA, max 21)\n\
+# minimum_contrast = 1\n\
+# Regex for lines reached by next_diagnostic/previous_diagnostic (repeat to add more)\n\
+# diagnostic_pattern = (?i)\\berror\\b\n\
 # Terminal scrollbar visibility: always | on_scroll | off\n\
 # (while scrolled up in history, scrollbar stays visible in all modes)\n\
 # scrollbar_visibility = on_scroll\n\
 # Scrollbar style: neutral | muted_theme | theme\n\
 # scrollbar_style = neutral\n\
+# Mark shell-integration prompt positions on the terminal scrollbar\n\
+# scrollbar_prompt_markers = true\n\
+# Draw search markers brighter where many matches cluster\n\
+# scrollbar_match_density = false\n\
+# Search highlight colors for matches and the focused match (#RRGGBB)\n\
+# search_match_color = #f5d33f\n\
+# search_current_color = #fb8a1c\n\
+# Search bar colors: neutral | accent (tinted with the theme cursor color)\n\
+# search_bar_style = neutral\n\
+# Ignore leading/trailing spaces in the search query\n\
+# search_trim_query = false\n\
+# Center the current match in the viewport when jumping to it\n\
+# search_center_on_match = true\n\
+# Close the search bar when clicking into the terminal (the view stays on the match)\n\
+# close_search_on_click = false\n\
 \n\
 # Advanced runtime settings (usually leave these as defaults)\n\
 # Preferred shell executable path\n\
 # shell = /bin/zsh\n\
+# Command typed into every new tab once the shell starts (sent with Enter)\n\
+# startup_command = tmux attach || tmux\n\
+# XON/XOFF flow control: Ctrl-S pauses output, Ctrl-Q resumes (applies to new tabs)\n\
+# flow_control = true\n\
 # Fallback startup directory when working_dir is unset: home or process\n\
 # working_dir_fallback = home\n\
 # Advertise 24-bit color support to child apps\n\
@@ -83,6 +178,10 @@ padding_y = 8\n\
 # scrollback_history = 2000\n\
 # Scrollback for inactive tabs (saves memory with many tabs)\n\
 # inactive_tab_scrollback = 500\n\
+# Releases offered by the updater: stable | prerelease\n\
+# update_channel = stable\n\
+# Release the updater stops offering (set by the update banner's Skip button)\n\
+# skipped_update_version = 1.2.0\n\
 # Keybindings (Ghostty-style trigger overrides)\n\
 # keybind = cmd-p=toggle_command_palette\n\
 # keybind = cmd-c=copy\n\
@@ -168,56 +267,6 @@ fn upsert_theme_assignment(contents: &str, theme_id: &str) -> String {
     new_config
 }
 
-fn replace_or_insert_section(
-    contents: &str,
-    section_name: &str,
-    section_lines: &[String],
-) -> String {
-    let mut new_config = String::new();
-    let mut in_target_section = false;
-    let mut target_section_found = false;
-    let target_header = format!("[{}]", section_name);
-
-    for line in contents.lines() {
-        let trimmed = line.trim();
-        if trimmed.starts_with('[') && trimmed.ends_with(']') {
-            in_target_section = false;
-            if trimmed.eq_ignore_ascii_case(&target_header) {
-                target_section_found = true;
-                in_target_section = true;
-                new_config.push_str(line);
-                new_config.push('\n');
-                for section_line in section_lines {
-                    new_config.push_str(section_line);
-                    new_config.push('\n');
-                }
-                continue;
-            }
-        }
-
-        if in_target_section {
-            continue;
-        }
-
-        new_config.push_str(line);
-        new_config.push('\n');
-    }
-
-    if !target_section_found {
-        if !new_config.is_empty() {
-            new_config.push('\n');
-        }
-        new_config.push_str(&target_header);
-        new_config.push('\n');
-        for section_line in section_lines {
-            new_config.push_str(section_line);
-            new_config.push('\n');
-        }
-    }
-
-    new_config
-}
-
 fn update_config_contents<R>(
     updater: impl FnOnce(&str) -> Result<(String, R), String>,
 ) -> Result<R, String> {
@@ -337,6 +386,9 @@ impl Default for TabTitleConfig {
 pub enum CursorStyle {
     Line,
     Block,
+    Underline,
+    /// Outline of a block.
+    HollowBlock,
 }
 
 impl CursorStyle {
@@ -344,9 +396,30 @@ impl CursorStyle {
         match value.trim().to_ascii_lowercase().as_str() {
             "line" | "bar" | "beam" | "ibeam" => Some(Self::Line),
             "block" | "box" => Some(Self::Block),
+            "underline" | "underscore" => Some(Self::Underline),
+            "hollow_block" | "hollow" => Some(Self::HollowBlock),
             _ => None,
         }
     }
+
+    pub fn config_value(self) -> &'static str {
+        match self {
+            Self::Line => "line",
+            Self::Block => "block",
+            Self::Underline => "underline",
+            Self::HollowBlock => "hollow_block",
+        }
+    }
+
+    /// Block, then line, then underline, then hollow block.
+    pub fn next(self) -> Self {
+        match self {
+            Self::Block => Self::Line,
+            Self::Line => Self::Underline,
+            Self::Underline => Self::HollowBlock,
+            Self::HollowBlock => Self::Block,
+        }
+    }
 }
 
 impl Default for CursorStyle {
@@ -355,6 +428,32 @@ impl Default for CursorStyle {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TabBarVisibility {
+    /// Show the tab bar only while more than one tab is open.
+    Auto,
+    Always,
+    /// Keep the tab bar hidden until the pointer reaches the top of the window.
+    HoverReveal,
+}
+
+impl TabBarVisibility {
+    fn from_str(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "auto" => Some(Self::Auto),
+            "always" => Some(Self::Always),
+            "hover_reveal" | "hoverreveal" | "hover" => Some(Self::HoverReveal),
+            _ => None,
+        }
+    }
+}
+
+impl Default for TabBarVisibility {
+    fn default() -> Self {
+        Self::Always
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TerminalScrollbarVisibility {
     Off,
@@ -403,6 +502,308 @@ impl Default for TerminalScrollbarStyle {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DoubleClickSelection {
+    /// The token link detection sees: surrounding quotes, brackets and punctuation dropped.
+    Token,
+    /// Everything between the surrounding whitespace.
+    Word,
+}
+
+impl DoubleClickSelection {
+    fn from_str(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "token" => Some(Self::Token),
+            "word" => Some(Self::Word),
+            _ => None,
+        }
+    }
+}
+
+impl Default for DoubleClickSelection {
+    fn default() -> Self {
+        Self::Token
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderWhitespace {
+    None,
+    /// Every space before the last glyph on its row.
+    All,
+}
+
+impl RenderWhitespace {
+    fn from_str(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "none" => Some(Self::None),
+            "all" => Some(Self::All),
+            _ => None,
+        }
+    }
+
+    pub fn config_value(self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::All => "all",
+        }
+    }
+}
+
+impl Default for RenderWhitespace {
+    fn default() -> Self {
+        Self::None
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpdateChannel {
+    Stable,
+    /// Prereleases too, whichever release has the highest version.
+    Prerelease,
+}
+
+impl UpdateChannel {
+    fn from_str(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "stable" => Some(Self::Stable),
+            "prerelease" => Some(Self::Prerelease),
+            _ => None,
+        }
+    }
+}
+
+impl Default for UpdateChannel {
+    fn default() -> Self {
+        Self::Stable
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StartupWindowMode {
+    /// `window_width` x `window_height`, centered.
+    Windowed,
+    Maximized,
+    Fullscreen,
+}
+
+impl StartupWindowMode {
+    fn from_str(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "windowed" => Some(Self::Windowed),
+            "maximized" | "maximised" => Some(Self::Maximized),
+            "fullscreen" => Some(Self::Fullscreen),
+            _ => None,
+        }
+    }
+}
+
+impl Default for StartupWindowMode {
+    fn default() -> Self {
+        Self::Windowed
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LongLines {
+    Wrap,
+    /// Hide everything past `long_line_limit` and mark the cut with an ellipsis.
+    Truncate,
+    /// Like `Truncate`, but a click on the marker unfolds the line.
+    Fold,
+}
+
+impl LongLines {
+    fn from_str(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "wrap" => Some(Self::Wrap),
+            "truncate" => Some(Self::Truncate),
+            "fold" => Some(Self::Fold),
+            _ => None,
+        }
+    }
+}
+
+impl Default for LongLines {
+    fn default() -> Self {
+        Self::Wrap
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchBarStyle {
+    Neutral,
+    Accent,
+}
+
+impl SearchBarStyle {
+    fn from_str(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "neutral" => Some(Self::Neutral),
+            "accent" | "theme" => Some(Self::Accent),
+            _ => None,
+        }
+    }
+}
+
+impl Default for SearchBarStyle {
+    fn default() -> Self {
+        Self::Neutral
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShellExitBehavior {
+    Close,
+    Hold,
+    Restart,
+}
+
+impl ShellExitBehavior {
+    fn from_str(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "close" => Some(Self::Close),
+            "hold" | "keep" => Some(Self::Hold),
+            "restart" | "respawn" => Some(Self::Restart),
+            _ => None,
+        }
+    }
+}
+
+impl Default for ShellExitBehavior {
+    fn default() -> Self {
+        Self::Hold
+    }
+}
+
+/// Named `[profile.<name>]` section. Unset fields fall back to the root config.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProfileConfig {
+    pub name: String,
+    pub font_family: Option<String>,
+    pub font_size: Option<f32>,
+    /// Replaces the root `diagnostic_pattern` list when non-empty.
+    pub diagnostic_patterns: Vec<String>,
+    /// `Some(None)` turns the root `startup_command` off for tabs on this profile.
+    pub startup_command: Option<Option<String>>,
+}
+
+impl ProfileConfig {
+    fn new(name: String) -> Self {
+        Self {
+            name,
+            font_family: None,
+            font_size: None,
+            diagnostic_patterns: Vec::new(),
+            startup_command: None,
+        }
+    }
+
+    fn apply_entry(&mut self, key: &str, value: &str) {
+        if key.eq_ignore_ascii_case("font_family") {
+            self.font_family = parse_string_value(value);
+        }
+
+        if key.eq_ignore_ascii_case("font_size")
+            && let Ok(font_size) = value.parse::<f32>()
+            && font_size > 0.0
+        {
+            self.font_size = Some(font_size);
+        }
+
+        if key.eq_ignore_ascii_case("diagnostic_pattern") && !value.is_empty() {
+            self.diagnostic_patterns.push(value.to_string());
+        }
+
+        if key.eq_ignore_ascii_case("startup_command") {
+            self.startup_command = Some(parse_optional_string_value(value));
+        }
+    }
+}
+
+/// What a `[command.<name>]` palette entry does when it is chosen.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CustomCommandAction {
+    /// Typed into the active tab's shell and submitted with Enter.
+    RunCommand(String),
+    /// Written to the active tab as-is, after decoding escapes such as `\e` and `\x03`.
+    SendKeys(String),
+    OpenUrl(String),
+}
+
+/// Named `[command.<name>]` section, listed in the command palette next to built-ins.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomCommandConfig {
+    pub name: String,
+    pub keywords: String,
+    /// Sections that never set an action are dropped once parsing finishes.
+    pub action: Option<CustomCommandAction>,
+}
+
+impl CustomCommandConfig {
+    fn new(name: String) -> Self {
+        Self {
+            name,
+            keywords: String::new(),
+            action: None,
+        }
+    }
+
+    fn apply_entry(&mut self, key: &str, value: &str) {
+        let Some(value) = parse_string_value(value) else {
+            return;
+        };
+
+        if key.eq_ignore_ascii_case("run") {
+            self.action = Some(CustomCommandAction::RunCommand(value));
+        } else if key.eq_ignore_ascii_case("send_keys") {
+            self.action = Some(CustomCommandAction::SendKeys(decode_key_escapes(&value)));
+        } else if key.eq_ignore_ascii_case("open_url") {
+            self.action = Some(CustomCommandAction::OpenUrl(value));
+        } else if key.eq_ignore_ascii_case("keywords") {
+            self.keywords = value;
+        }
+    }
+}
+
+/// Decodes `\n`, `\r`, `\t`, `\e`, `\\` and `\xNN` in `send_keys` values. Unknown escapes
+/// are kept verbatim.
+fn decode_key_escapes(value: &str) -> String {
+    let mut decoded = String::with_capacity(value.len());
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            decoded.push(c);
+            continue;
+        }
+
+        match chars.peek().copied() {
+            Some('n') => decoded.push('\n'),
+            Some('r') => decoded.push('\r'),
+            Some('t') => decoded.push('\t'),
+            Some('e') => decoded.push('\x1b'),
+            Some('\\') => decoded.push('\\'),
+            Some('x') => {
+                let hex: String = chars.clone().skip(1).take(2).collect();
+                match u8::from_str_radix(&hex, 16) {
+                    Ok(byte) if hex.len() == 2 && byte.is_ascii() => {
+                        decoded.push(char::from(byte));
+                        chars.nth(2);
+                    }
+                    _ => decoded.push('\\'),
+                }
+                continue;
+            }
+            _ => {
+                decoded.push('\\');
+                continue;
+            }
+        }
+        chars.next();
+    }
+    decoded
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct CustomColors {
     pub foreground: Option<Rgba>,
@@ -414,32 +815,84 @@ pub struct CustomColors {
 #[derive(Debug, Clone)]
 pub struct AppConfig {
     pub theme: ThemeId,
+    pub theme_cache_size: usize,
     pub working_dir: Option<String>,
     pub working_dir_fallback: WorkingDirFallback,
     pub use_tabs: bool,
+    pub tab_bar_visibility: TabBarVisibility,
+    pub inherit_tab_overrides: bool,
+    pub max_tabs: usize,
     pub warn_on_quit_with_running_process: bool,
+    pub confirm_quit_multiple_tabs: bool,
+    pub on_shell_exit: ShellExitBehavior,
     pub tab_title: TabTitleConfig,
     pub shell: Option<String>,
+    pub startup_command: Option<String>,
+    pub flow_control: bool,
     pub term: String,
     pub colorterm: Option<String>,
     pub window_width: f32,
     pub window_height: f32,
+    pub startup_window_mode: StartupWindowMode,
     pub font_family: String,
     pub font_size: f32,
     pub cursor_style: CursorStyle,
     pub cursor_blink: bool,
+    pub cursor_contrast: bool,
     pub background_opacity: f32,
     pub background_blur: bool,
+    pub reduce_transparency: Option<bool>,
+    /// The OS "reduce transparency" preference, read once per config load and
+    /// used while `reduce_transparency` is unset.
+    pub system_reduce_transparency: bool,
+    pub transparency_friendly_colors: bool,
     pub padding_x: f32,
     pub padding_y: f32,
+    pub max_content_columns: u16,
     pub mouse_scroll_multiplier: f32,
+    pub wheel_scroll_multiplier: Option<f32>,
+    pub trackpad_scroll_multiplier: Option<f32>,
+    pub invert_wheel_scroll: bool,
+    pub invert_trackpad_scroll: bool,
+    pub scroll_to_bottom_on_input: bool,
+    pub key_repeat_delay: u64,
+    pub key_repeat_rate: u32,
+    pub toast_duration_info: u64,
+    pub toast_duration_success: u64,
+    pub toast_duration_warning: u64,
+    pub toast_duration_error: u64,
+    pub show_wrap_indicator: bool,
+    pub render_whitespace: RenderWhitespace,
+    pub long_lines: LongLines,
+    pub long_line_limit: usize,
+    pub require_url_scheme: bool,
+    pub trim_trailing_whitespace_on_copy: bool,
+    pub double_click_selection: DoubleClickSelection,
+    pub sanitize_paste: bool,
+    pub macos_option_as_meta: bool,
+    pub bold_is_bright: bool,
+    pub builtin_box_drawing: bool,
+    pub minimum_contrast: f32,
+    pub diagnostic_patterns: Vec<String>,
     pub terminal_scrollbar_visibility: TerminalScrollbarVisibility,
     pub terminal_scrollbar_style: TerminalScrollbarStyle,
+    pub scrollbar_prompt_markers: bool,
+    pub scrollbar_match_density: bool,
+    pub search_match_color: Option<Rgba>,
+    pub search_current_color: Option<Rgba>,
+    pub search_bar_style: SearchBarStyle,
+    pub search_trim_query: bool,
+    pub search_center_on_match: bool,
+    pub close_search_on_click: bool,
     pub scrollback_history: usize,
     pub inactive_tab_scrollback: Option<usize>,
+    pub update_channel: UpdateChannel,
+    pub skipped_update_version: Option<String>,
     pub command_palette_show_keybinds: bool,
     pub keybind_lines: Vec<KeybindConfigLine>,
     pub colors: CustomColors,
+    pub profiles: Vec<ProfileConfig>,
+    pub custom_commands: Vec<CustomCommandConfig>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -452,54 +905,108 @@ impl Default for AppConfig {
     fn default() -> Self {
         Self {
             theme: DEFAULT_THEME_ID.to_string(),
+            theme_cache_size: termy_themes::DEFAULT_THEME_CACHE_CAPACITY,
             working_dir: None,
             working_dir_fallback: WorkingDirFallback::default(),
             use_tabs: true,
+            tab_bar_visibility: TabBarVisibility::default(),
+            inherit_tab_overrides: true,
+            max_tabs: 0,
             warn_on_quit_with_running_process: DEFAULT_WARN_ON_QUIT_WITH_RUNNING_PROCESS,
+            confirm_quit_multiple_tabs: false,
+            on_shell_exit: ShellExitBehavior::default(),
             tab_title: TabTitleConfig::default(),
             shell: None,
+            startup_command: None,
+            flow_control: true,
             term: DEFAULT_TERM.to_string(),
             colorterm: Some(DEFAULT_COLORTERM.to_string()),
             window_width: 1280.0,
             window_height: 820.0,
+            startup_window_mode: StartupWindowMode::default(),
             font_family: "JetBrains Mono".to_string(),
             font_size: 14.0,
             cursor_style: CursorStyle::default(),
             cursor_blink: DEFAULT_CURSOR_BLINK,
+            cursor_contrast: true,
             background_opacity: 1.0,
             background_blur: false,
+            reduce_transparency: None,
+            system_reduce_transparency: false,
+            transparency_friendly_colors: false,
             padding_x: 12.0,
             padding_y: 8.0,
+            max_content_columns: 0,
             mouse_scroll_multiplier: DEFAULT_MOUSE_SCROLL_MULTIPLIER,
+            wheel_scroll_multiplier: None,
+            trackpad_scroll_multiplier: None,
+            invert_wheel_scroll: false,
+            invert_trackpad_scroll: false,
+            scroll_to_bottom_on_input: true,
+            key_repeat_delay: DEFAULT_KEY_REPEAT_DELAY_MS,
+            key_repeat_rate: DEFAULT_KEY_REPEAT_RATE,
+            toast_duration_info: DEFAULT_TOAST_DURATION_MS,
+            toast_duration_success: DEFAULT_TOAST_DURATION_MS,
+            toast_duration_warning: DEFAULT_TOAST_DURATION_MS,
+            toast_duration_error: DEFAULT_TOAST_DURATION_MS,
+            show_wrap_indicator: false,
+            render_whitespace: RenderWhitespace::default(),
+            long_lines: LongLines::default(),
+            long_line_limit: DEFAULT_LONG_LINE_LIMIT,
+            require_url_scheme: false,
+            trim_trailing_whitespace_on_copy: true,
+            double_click_selection: DoubleClickSelection::default(),
+            sanitize_paste: true,
+            macos_option_as_meta: false,
+            bold_is_bright: false,
+            builtin_box_drawing: false,
+            minimum_contrast: MIN_MINIMUM_CONTRAST,
+            diagnostic_patterns: DEFAULT_DIAGNOSTIC_PATTERNS
+                .iter()
+                .map(|pattern| pattern.to_string())
+                .collect(),
             terminal_scrollbar_visibility: TerminalScrollbarVisibility::default(),
             terminal_scrollbar_style: TerminalScrollbarStyle::default(),
+            scrollbar_prompt_markers: true,
+            scrollbar_match_density: false,
+            search_match_color: None,
+            search_current_color: None,
+            search_bar_style: SearchBarStyle::default(),
+            search_trim_query: false,
+            search_center_on_match: true,
+            close_search_on_click: false,
             scrollback_history: DEFAULT_SCROLLBACK_HISTORY,
             inactive_tab_scrollback: DEFAULT_INACTIVE_TAB_SCROLLBACK,
+            update_channel: UpdateChannel::default(),
+            skipped_update_version: None,
             command_palette_show_keybinds: true,
             keybind_lines: Vec::new(),
             colors: CustomColors::default(),
+            profiles: Vec::new(),
+            custom_commands: Vec::new(),
         }
     }
 }
 
 impl AppConfig {
     pub fn load_or_create() -> Self {
-        let mut config = Self::default();
-        let Some(path) = ensure_config_file() else {
-            return config;
-        };
-
-        if let Ok(contents) = fs::read_to_string(&path) {
-            config = Self::from_contents(&contents);
+        let mut config = ensure_config_file()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .map(|contents| Self::from_contents(&contents))
+            .unwrap_or_default();
+        if config.reduce_transparency.is_none() {
+            config.system_reduce_transparency = system_prefers_reduced_transparency();
         }
-
         config
     }
 
     fn from_contents(contents: &str) -> Self {
         let mut config = Self::default();
         let mut tab_title_priority_overridden = false;
+        let mut diagnostic_patterns_overridden = false;
         let mut in_colors_section = false;
+        let mut profile_section: Option<usize> = None;
+        let mut command_section: Option<usize> = None;
 
         for (line_number, line) in contents.lines().enumerate() {
             let line = line.trim();
@@ -508,8 +1015,45 @@ impl AppConfig {
             }
 
             if line.starts_with('[') && line.ends_with(']') {
-                let section = &line[1..line.len() - 1].trim().to_ascii_lowercase();
+                let raw_section = line[1..line.len() - 1].trim();
+                let section = &raw_section.to_ascii_lowercase();
                 in_colors_section = section == "colors";
+                profile_section = section
+                    .starts_with("profile.")
+                    .then(|| raw_section["profile.".len()..].trim())
+                    .filter(|name| !name.is_empty())
+                    .map(|name| {
+                        match config
+                            .profiles
+                            .iter()
+                            .position(|profile| profile.name == name)
+                        {
+                            Some(index) => index,
+                            None => {
+                                config.profiles.push(ProfileConfig::new(name.to_string()));
+                                config.profiles.len() - 1
+                            }
+                        }
+                    });
+                command_section = section
+                    .starts_with("command.")
+                    .then(|| raw_section["command.".len()..].trim())
+                    .filter(|name| !name.is_empty())
+                    .map(|name| {
+                        match config
+                            .custom_commands
+                            .iter()
+                            .position(|command| command.name == name)
+                        {
+                            Some(index) => index,
+                            None => {
+                                config
+                                    .custom_commands
+                                    .push(CustomCommandConfig::new(name.to_string()));
+                                config.custom_commands.len() - 1
+                            }
+                        }
+                    });
                 continue;
             }
 
@@ -522,12 +1066,28 @@ impl AppConfig {
                 continue;
             }
 
+            if let Some(index) = profile_section {
+                config.profiles[index].apply_entry(key, value);
+                continue;
+            }
+
+            if let Some(index) = command_section {
+                config.custom_commands[index].apply_entry(key, value);
+                continue;
+            }
+
             if key.eq_ignore_ascii_case("theme") {
                 if let Some(theme) = parse_theme_id(value) {
                     config.theme = theme;
                 }
             }
 
+            if key.eq_ignore_ascii_case("theme_cache_size") {
+                if let Ok(size) = value.parse::<usize>() {
+                    config.theme_cache_size = size;
+                }
+            }
+
             if key.eq_ignore_ascii_case("working_dir") && !value.is_empty() {
                 config.working_dir = Some(value.to_string());
             }
@@ -546,12 +1106,42 @@ impl AppConfig {
                 }
             }
 
+            if key.eq_ignore_ascii_case("tab_bar_visibility") {
+                if let Some(visibility) = TabBarVisibility::from_str(value) {
+                    config.tab_bar_visibility = visibility;
+                }
+            }
+
+            if key.eq_ignore_ascii_case("inherit_tab_overrides") {
+                if let Some(inherit) = parse_bool(value) {
+                    config.inherit_tab_overrides = inherit;
+                }
+            }
+
+            if key.eq_ignore_ascii_case("max_tabs") {
+                if let Ok(max_tabs) = value.parse::<usize>() {
+                    config.max_tabs = max_tabs;
+                }
+            }
+
             if key.eq_ignore_ascii_case("warn_on_quit_with_running_process") {
                 if let Some(warn) = parse_bool(value) {
                     config.warn_on_quit_with_running_process = warn;
                 }
             }
 
+            if key.eq_ignore_ascii_case("confirm_quit_multiple_tabs") {
+                if let Some(confirm) = parse_bool(value) {
+                    config.confirm_quit_multiple_tabs = confirm;
+                }
+            }
+
+            if key.eq_ignore_ascii_case("on_shell_exit") {
+                if let Some(behavior) = ShellExitBehavior::from_str(value) {
+                    config.on_shell_exit = behavior;
+                }
+            }
+
             if key.eq_ignore_ascii_case("tab_title_priority") {
                 if let Some(priority) = parse_tab_title_priority(value) {
                     config.tab_title.priority = priority;
@@ -599,6 +1189,16 @@ impl AppConfig {
                 config.shell = parse_optional_string_value(value);
             }
 
+            if key.eq_ignore_ascii_case("startup_command") {
+                config.startup_command = parse_optional_string_value(value);
+            }
+
+            if key.eq_ignore_ascii_case("flow_control") {
+                if let Some(flow_control) = parse_bool(value) {
+                    config.flow_control = flow_control;
+                }
+            }
+
             if key.eq_ignore_ascii_case("term") {
                 if let Some(term) = parse_string_value(value) {
                     config.term = term;
@@ -625,6 +1225,12 @@ impl AppConfig {
                 }
             }
 
+            if key.eq_ignore_ascii_case("startup_window_mode") {
+                if let Some(mode) = StartupWindowMode::from_str(value) {
+                    config.startup_window_mode = mode;
+                }
+            }
+
             if key.eq_ignore_ascii_case("font_family") {
                 if let Some(font_family) = parse_string_value(value) {
                     config.font_family = font_family;
@@ -651,6 +1257,12 @@ impl AppConfig {
                 }
             }
 
+            if key.eq_ignore_ascii_case("cursor_contrast") {
+                if let Some(cursor_contrast) = parse_bool(value) {
+                    config.cursor_contrast = cursor_contrast;
+                }
+            }
+
             if key.eq_ignore_ascii_case("background_opacity") {
                 if let Ok(opacity) = value.parse::<f32>() {
                     config.background_opacity = opacity.clamp(0.0, 1.0);
@@ -663,6 +1275,19 @@ impl AppConfig {
                 }
             }
 
+            if key.eq_ignore_ascii_case("reduce_transparency") {
+                config.reduce_transparency = match value.trim().to_ascii_lowercase().as_str() {
+                    "auto" | "system" => None,
+                    _ => parse_bool(value).or(config.reduce_transparency),
+                };
+            }
+
+            if key.eq_ignore_ascii_case("transparency_friendly_colors") {
+                if let Some(enabled) = parse_bool(value) {
+                    config.transparency_friendly_colors = enabled;
+                }
+            }
+
             if key.eq_ignore_ascii_case("padding_x") {
                 if let Ok(padding_x) = value.parse::<f32>() {
                     if padding_x >= 0.0 {
@@ -679,6 +1304,12 @@ impl AppConfig {
                 }
             }
 
+            if key.eq_ignore_ascii_case("max_content_columns") {
+                if let Ok(columns) = value.parse::<u16>() {
+                    config.max_content_columns = columns;
+                }
+            }
+
             if key.eq_ignore_ascii_case("mouse_scroll_multiplier") {
                 if let Ok(multiplier) = value.parse::<f32>()
                     && multiplier.is_finite()
@@ -688,18 +1319,226 @@ impl AppConfig {
                 }
             }
 
-            if key.eq_ignore_ascii_case("scrollbar_visibility") {
-                if let Some(visibility) = TerminalScrollbarVisibility::from_str(value) {
-                    config.terminal_scrollbar_visibility = visibility;
+            if key.eq_ignore_ascii_case("wheel_scroll_multiplier") {
+                if let Ok(multiplier) = value.parse::<f32>()
+                    && multiplier.is_finite()
+                {
+                    config.wheel_scroll_multiplier = Some(
+                        multiplier.clamp(MIN_MOUSE_SCROLL_MULTIPLIER, MAX_MOUSE_SCROLL_MULTIPLIER),
+                    );
                 }
             }
 
-            if key.eq_ignore_ascii_case("scrollbar_style") {
+            if key.eq_ignore_ascii_case("trackpad_scroll_multiplier") {
+                if let Ok(multiplier) = value.parse::<f32>()
+                    && multiplier.is_finite()
+                {
+                    config.trackpad_scroll_multiplier = Some(
+                        multiplier.clamp(MIN_MOUSE_SCROLL_MULTIPLIER, MAX_MOUSE_SCROLL_MULTIPLIER),
+                    );
+                }
+            }
+
+            if key.eq_ignore_ascii_case("invert_wheel_scroll") {
+                if let Some(invert) = parse_bool(value) {
+                    config.invert_wheel_scroll = invert;
+                }
+            }
+
+            if key.eq_ignore_ascii_case("invert_trackpad_scroll") {
+                if let Some(invert) = parse_bool(value) {
+                    config.invert_trackpad_scroll = invert;
+                }
+            }
+
+            if key.eq_ignore_ascii_case("scroll_to_bottom_on_input") {
+                if let Some(scroll) = parse_bool(value) {
+                    config.scroll_to_bottom_on_input = scroll;
+                }
+            }
+
+            if key.eq_ignore_ascii_case("key_repeat_delay") {
+                if let Ok(delay) = value.parse::<u64>() {
+                    config.key_repeat_delay = delay.min(MAX_KEY_REPEAT_DELAY_MS);
+                }
+            }
+
+            if key.eq_ignore_ascii_case("key_repeat_rate") {
+                if let Ok(rate) = value.parse::<u32>() {
+                    config.key_repeat_rate = rate.min(MAX_KEY_REPEAT_RATE);
+                }
+            }
+
+            if key.eq_ignore_ascii_case("toast_duration_info") {
+                if let Some(ms) = parse_toast_duration(value) {
+                    config.toast_duration_info = ms;
+                }
+            }
+
+            if key.eq_ignore_ascii_case("toast_duration_success") {
+                if let Some(ms) = parse_toast_duration(value) {
+                    config.toast_duration_success = ms;
+                }
+            }
+
+            if key.eq_ignore_ascii_case("toast_duration_warning") {
+                if let Some(ms) = parse_toast_duration(value) {
+                    config.toast_duration_warning = ms;
+                }
+            }
+
+            if key.eq_ignore_ascii_case("toast_duration_error") {
+                if let Some(ms) = parse_toast_duration(value) {
+                    config.toast_duration_error = ms;
+                }
+            }
+
+            if key.eq_ignore_ascii_case("scrollbar_visibility") {
+                if let Some(visibility) = TerminalScrollbarVisibility::from_str(value) {
+                    config.terminal_scrollbar_visibility = visibility;
+                }
+            }
+
+            if key.eq_ignore_ascii_case("scrollbar_style") {
                 if let Some(style) = TerminalScrollbarStyle::from_str(value) {
                     config.terminal_scrollbar_style = style;
                 }
             }
 
+            if key.eq_ignore_ascii_case("scrollbar_prompt_markers") {
+                if let Some(show) = parse_bool(value) {
+                    config.scrollbar_prompt_markers = show;
+                }
+            }
+
+            if key.eq_ignore_ascii_case("scrollbar_match_density") {
+                if let Some(weighted) = parse_bool(value) {
+                    config.scrollbar_match_density = weighted;
+                }
+            }
+
+            if key.eq_ignore_ascii_case("show_wrap_indicator") {
+                if let Some(show) = parse_bool(value) {
+                    config.show_wrap_indicator = show;
+                }
+            }
+
+            if key.eq_ignore_ascii_case("render_whitespace") {
+                if let Some(mode) = RenderWhitespace::from_str(value) {
+                    config.render_whitespace = mode;
+                }
+            }
+
+            if key.eq_ignore_ascii_case("long_lines") {
+                if let Some(mode) = LongLines::from_str(value) {
+                    config.long_lines = mode;
+                }
+            }
+
+            if key.eq_ignore_ascii_case("long_line_limit") {
+                if let Ok(limit) = value.parse::<usize>() {
+                    if limit > 0 {
+                        config.long_line_limit = limit;
+                    }
+                }
+            }
+
+            if key.eq_ignore_ascii_case("require_url_scheme") {
+                if let Some(require) = parse_bool(value) {
+                    config.require_url_scheme = require;
+                }
+            }
+
+            if key.eq_ignore_ascii_case("trim_trailing_whitespace_on_copy") {
+                if let Some(trim) = parse_bool(value) {
+                    config.trim_trailing_whitespace_on_copy = trim;
+                }
+            }
+
+            if key.eq_ignore_ascii_case("double_click_selection") {
+                if let Some(selection) = DoubleClickSelection::from_str(value) {
+                    config.double_click_selection = selection;
+                }
+            }
+
+            if key.eq_ignore_ascii_case("sanitize_paste") {
+                if let Some(sanitize) = parse_bool(value) {
+                    config.sanitize_paste = sanitize;
+                }
+            }
+
+            if key.eq_ignore_ascii_case("macos_option_as_meta") {
+                if let Some(option_as_meta) = parse_bool(value) {
+                    config.macos_option_as_meta = option_as_meta;
+                }
+            }
+
+            if key.eq_ignore_ascii_case("bold_is_bright") {
+                if let Some(enabled) = parse_bool(value) {
+                    config.bold_is_bright = enabled;
+                }
+            }
+
+            if key.eq_ignore_ascii_case("builtin_box_drawing") {
+                if let Some(enabled) = parse_bool(value) {
+                    config.builtin_box_drawing = enabled;
+                }
+            }
+
+            if key.eq_ignore_ascii_case("minimum_contrast") {
+                if let Ok(ratio) = value.parse::<f32>() {
+                    if ratio.is_finite() {
+                        config.minimum_contrast =
+                            ratio.clamp(MIN_MINIMUM_CONTRAST, MAX_MINIMUM_CONTRAST);
+                    }
+                }
+            }
+
+            if key.eq_ignore_ascii_case("diagnostic_pattern") && !value.is_empty() {
+                // The first configured pattern replaces the built-in set.
+                if !diagnostic_patterns_overridden {
+                    config.diagnostic_patterns.clear();
+                    diagnostic_patterns_overridden = true;
+                }
+                config.diagnostic_patterns.push(value.to_string());
+            }
+
+            if key.eq_ignore_ascii_case("search_match_color") {
+                if let Some(color) = parse_hex_color(value) {
+                    config.search_match_color = Some(color);
+                }
+            }
+
+            if key.eq_ignore_ascii_case("search_current_color") {
+                if let Some(color) = parse_hex_color(value) {
+                    config.search_current_color = Some(color);
+                }
+            }
+
+            if key.eq_ignore_ascii_case("search_bar_style") {
+                if let Some(style) = SearchBarStyle::from_str(value) {
+                    config.search_bar_style = style;
+                }
+            }
+
+            if key.eq_ignore_ascii_case("search_trim_query") {
+                if let Some(trim) = parse_bool(value) {
+                    config.search_trim_query = trim;
+                }
+            }
+
+            if key.eq_ignore_ascii_case("search_center_on_match") {
+                if let Some(center) = parse_bool(value) {
+                    config.search_center_on_match = center;
+                }
+            }
+
+            if key.eq_ignore_ascii_case("close_search_on_click") {
+                if let Some(close) = parse_bool(value) {
+                    config.close_search_on_click = close;
+                }
+            }
+
             if key.eq_ignore_ascii_case("scrollback_history")
                 || key.eq_ignore_ascii_case("scrollback")
             {
@@ -714,6 +1553,16 @@ impl AppConfig {
                 }
             }
 
+            if key.eq_ignore_ascii_case("update_channel") {
+                if let Some(channel) = UpdateChannel::from_str(value) {
+                    config.update_channel = channel;
+                }
+            }
+
+            if key.eq_ignore_ascii_case("skipped_update_version") {
+                config.skipped_update_version = parse_optional_string_value(value);
+            }
+
             if key.eq_ignore_ascii_case("command_palette_show_keybinds") {
                 if let Some(show) = parse_bool(value) {
                     config.command_palette_show_keybinds = show;
@@ -733,9 +1582,62 @@ impl AppConfig {
         if !tab_title_priority_overridden {
             config.tab_title.priority = config.tab_title.mode.default_priority();
         }
+        config
+            .custom_commands
+            .retain(|command| command.action.is_some());
 
         config
     }
+
+    /// Whether transparency is suppressed, following the OS accessibility
+    /// preference when `reduce_transparency` is unset.
+    pub fn reduce_transparency_enabled(&self) -> bool {
+        self.reduce_transparency
+            .unwrap_or(self.system_reduce_transparency)
+    }
+
+    pub fn effective_background_opacity(&self) -> f32 {
+        if self.reduce_transparency_enabled() {
+            1.0
+        } else {
+            self.background_opacity
+        }
+    }
+
+    /// Whether theme colors should use their transparency variant, which only
+    /// applies while the window background is actually see-through.
+    pub fn transparency_friendly_colors_active(&self) -> bool {
+        self.transparency_friendly_colors && self.effective_background_opacity() < 1.0
+    }
+
+    pub fn effective_background_blur(&self) -> bool {
+        self.background_blur && !self.reduce_transparency_enabled()
+    }
+
+    pub fn effective_wheel_scroll_multiplier(&self) -> f32 {
+        self.wheel_scroll_multiplier
+            .unwrap_or(self.mouse_scroll_multiplier)
+    }
+
+    pub fn effective_trackpad_scroll_multiplier(&self) -> f32 {
+        self.trackpad_scroll_multiplier
+            .unwrap_or(self.mouse_scroll_multiplier)
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn system_prefers_reduced_transparency() -> bool {
+    Command::new("defaults")
+        .args(["read", "com.apple.universalaccess", "reduceTransparency"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .is_some_and(|output| String::from_utf8_lossy(&output.stdout).trim() == "1")
+}
+
+#[cfg(not(target_os = "macos"))]
+fn system_prefers_reduced_transparency() -> bool {
+    false
 }
 
 fn parse_bool(value: &str) -> Option<bool> {
@@ -746,6 +1648,13 @@ fn parse_bool(value: &str) -> Option<bool> {
     }
 }
 
+fn parse_toast_duration(value: &str) -> Option<u64> {
+    match value.trim().parse::<u64>() {
+        Ok(0) | Err(_) => None,
+        Ok(ms) => Some(ms.min(MAX_TOAST_DURATION_MS)),
+    }
+}
+
 fn parse_string_value(value: &str) -> Option<String> {
     let trimmed = value.trim();
     if trimmed.is_empty() {
@@ -824,12 +1733,62 @@ fn parse_color_entry(colors: &mut CustomColors, key: &str, value: &str) {
     }
 }
 
-pub fn import_colors_from_json(json_path: &Path) -> Result<String, String> {
-    let contents =
-        fs::read_to_string(json_path).map_err(|e| format!("Failed to read file: {}", e))?;
+/// Registers the `*.toml` themes in the `themes` directory next to the config
+/// file. Returns a message for each theme file that was skipped.
+pub fn load_user_themes() -> Vec<String> {
+    let Some(dir) = config_path().and_then(|path| path.parent().map(|dir| dir.join("themes")))
+    else {
+        return Vec::new();
+    };
 
+    // Registered even while the directory is missing, so reloading themes finds it later.
+    let mut provider = termy_themes::FileThemeProvider::new(dir);
+    let mut warnings = provider.refresh().unwrap_or_default().errors;
+    for theme_id in provider.theme_ids() {
+        if let Some(theme) = provider.theme(&theme_id)
+            && !theme.is_legible()
+        {
+            warnings.push(format!(
+                "Theme {} has low contrast ({:.1}:1)",
+                theme_id,
+                theme.contrast_ratio()
+            ));
+        }
+    }
+    termy_themes::register_theme_provider(provider);
+    warnings
+}
+
+/// Replaces the `[colors]` section with the scheme in `path`: a JSON object of
+/// color names to hex strings, an iTerm `.itermcolors` file or Xresources.
+pub fn import_colors_from_file(path: &Path) -> Result<String, String> {
+    let contents = fs::read_to_string(path).map_err(|e| format!("Failed to read file: {}", e))?;
+
+    let color_lines = if contents.trim_start().starts_with('{') {
+        json_color_lines(&contents)?
+    } else {
+        let scheme = color_scheme::parse_color_scheme(&contents).ok_or_else(|| {
+            "No colors found; expected JSON, an iTerm color scheme or Xresources".to_string()
+        })?;
+        color_scheme::config_color_entries(&scheme)
+            .into_iter()
+            .map(|(key, hex)| format!("{} = {}", key, hex))
+            .collect()
+    };
+
+    let color_count = color_lines.len();
+    update_config_contents(|existing| {
+        Ok((
+            replace_or_insert_section(existing, "colors", &color_lines),
+            (),
+        ))
+    })?;
+    Ok(format!("Imported {} colors", color_count))
+}
+
+fn json_color_lines(contents: &str) -> Result<Vec<String>, String> {
     let json: serde_json::Value =
-        serde_json::from_str(&contents).map_err(|e| format!("Invalid JSON: {}", e))?;
+        serde_json::from_str(contents).map_err(|e| format!("Invalid JSON: {}", e))?;
 
     let colors = json
         .as_object()
@@ -879,14 +1838,7 @@ pub fn import_colors_from_json(json_path: &Path) -> Result<String, String> {
     if color_lines.is_empty() {
         return Err("No valid colors found in JSON".to_string());
     }
-    let color_count = color_lines.len();
-    update_config_contents(|existing| {
-        Ok((
-            replace_or_insert_section(existing, "colors", &color_lines),
-            (),
-        ))
-    })?;
-    Ok(format!("Imported {} colors", color_count))
+    Ok(color_lines)
 }
 
 pub fn set_theme_in_config(theme_id: &str) -> Result<String, String> {
@@ -1065,9 +2017,10 @@ fn config_path() -> Option<PathBuf> {
 #[cfg(test)]
 mod tests {
     use super::{
-        AppConfig, CursorStyle, TabTitleMode, TabTitleSource, TerminalScrollbarStyle,
-        TerminalScrollbarVisibility, WorkingDirFallback, replace_or_insert_section,
-        upsert_theme_assignment,
+        AppConfig, CursorStyle, DoubleClickSelection, LongLines, RenderWhitespace, SearchBarStyle,
+        ShellExitBehavior, StartupWindowMode, TabBarVisibility, TabTitleMode, TabTitleSource,
+        TerminalScrollbarStyle, TerminalScrollbarVisibility, UpdateChannel, WorkingDirFallback,
+        replace_or_insert_section, upsert_theme_assignment,
     };
 
     #[test]
@@ -1128,6 +2081,28 @@ mod tests {
         assert!(config.colorterm.is_none());
     }
 
+    #[test]
+    fn flow_control_parses_and_defaults_on() {
+        assert!(AppConfig::from_contents("").flow_control);
+
+        let disabled = AppConfig::from_contents("flow_control = false\n");
+        assert!(!disabled.flow_control);
+    }
+
+    #[test]
+    fn startup_command_parses_and_can_be_disabled() {
+        assert!(AppConfig::from_contents("").startup_command.is_none());
+
+        let configured = AppConfig::from_contents("startup_command = tmux attach || tmux\n");
+        assert_eq!(
+            configured.startup_command.as_deref(),
+            Some("tmux attach || tmux")
+        );
+
+        let disabled = AppConfig::from_contents("startup_command = none\n");
+        assert!(disabled.startup_command.is_none());
+    }
+
     #[test]
     fn keybind_lines_are_collected_in_order_with_line_numbers() {
         let config = AppConfig::from_contents(
@@ -1161,6 +2136,21 @@ mod tests {
         assert!(!disabled.command_palette_show_keybinds);
     }
 
+    #[test]
+    fn tab_bar_visibility_parses_and_defaults() {
+        let defaults = AppConfig::from_contents("");
+        assert_eq!(defaults.tab_bar_visibility, TabBarVisibility::Always);
+
+        let auto = AppConfig::from_contents("tab_bar_visibility = auto\n");
+        assert_eq!(auto.tab_bar_visibility, TabBarVisibility::Auto);
+
+        let hover = AppConfig::from_contents("tab_bar_visibility = hover_reveal\n");
+        assert_eq!(hover.tab_bar_visibility, TabBarVisibility::HoverReveal);
+
+        let invalid = AppConfig::from_contents("tab_bar_visibility = sometimes\n");
+        assert_eq!(invalid.tab_bar_visibility, TabBarVisibility::Always);
+    }
+
     #[test]
     fn terminal_scrollbar_visibility_parses_and_defaults() {
         let defaults = AppConfig::from_contents("");
@@ -1254,6 +2244,97 @@ mod tests {
         assert_eq!(clamped_high.mouse_scroll_multiplier, 1_000.0);
     }
 
+    #[test]
+    fn per_device_scroll_settings_fall_back_to_mouse_multiplier() {
+        let defaults = AppConfig::from_contents("mouse_scroll_multiplier = 2\n");
+        assert_eq!(defaults.effective_wheel_scroll_multiplier(), 2.0);
+        assert_eq!(defaults.effective_trackpad_scroll_multiplier(), 2.0);
+        assert!(!defaults.invert_wheel_scroll);
+        assert!(!defaults.invert_trackpad_scroll);
+
+        let custom = AppConfig::from_contents(
+            "mouse_scroll_multiplier = 2\n\
+             wheel_scroll_multiplier = 5\n\
+             trackpad_scroll_multiplier = 0\n\
+             invert_wheel_scroll = true\n",
+        );
+        assert_eq!(custom.effective_wheel_scroll_multiplier(), 5.0);
+        assert_eq!(custom.effective_trackpad_scroll_multiplier(), 0.1);
+        assert!(custom.invert_wheel_scroll);
+        assert!(!custom.invert_trackpad_scroll);
+    }
+
+    #[test]
+    fn scroll_to_bottom_on_input_parses_and_defaults_on() {
+        assert!(AppConfig::from_contents("").scroll_to_bottom_on_input);
+
+        let disabled = AppConfig::from_contents("scroll_to_bottom_on_input = false\n");
+        assert!(!disabled.scroll_to_bottom_on_input);
+    }
+
+    #[test]
+    fn key_repeat_parses_and_clamps() {
+        let defaults = AppConfig::from_contents("");
+        assert_eq!(defaults.key_repeat_delay, 400);
+        assert_eq!(defaults.key_repeat_rate, 30);
+
+        let custom = AppConfig::from_contents(
+            "key_repeat_delay = 250\n\
+             key_repeat_rate = 0\n",
+        );
+        assert_eq!(custom.key_repeat_delay, 250);
+        assert_eq!(custom.key_repeat_rate, 0);
+
+        let clamped = AppConfig::from_contents(
+            "key_repeat_delay = 99999\n\
+             key_repeat_rate = 1000\n",
+        );
+        assert_eq!(clamped.key_repeat_delay, 2000);
+        assert_eq!(clamped.key_repeat_rate, 100);
+
+        let invalid = AppConfig::from_contents("key_repeat_rate = fast\n");
+        assert_eq!(invalid.key_repeat_rate, 30);
+    }
+
+    #[test]
+    fn theme_cache_size_parses_and_allows_zero() {
+        let defaults = AppConfig::from_contents("");
+        assert_eq!(
+            defaults.theme_cache_size,
+            termy_themes::DEFAULT_THEME_CACHE_CAPACITY
+        );
+
+        let custom = AppConfig::from_contents("theme_cache_size = 8\n");
+        assert_eq!(custom.theme_cache_size, 8);
+
+        let disabled = AppConfig::from_contents("theme_cache_size = 0\n");
+        assert_eq!(disabled.theme_cache_size, 0);
+
+        let invalid = AppConfig::from_contents("theme_cache_size = many\n");
+        assert_eq!(
+            invalid.theme_cache_size,
+            termy_themes::DEFAULT_THEME_CACHE_CAPACITY
+        );
+    }
+
+    #[test]
+    fn toast_durations_parse_per_kind() {
+        let defaults = AppConfig::from_contents("");
+        assert_eq!(defaults.toast_duration_info, 3000);
+        assert_eq!(defaults.toast_duration_error, 3000);
+
+        let custom = AppConfig::from_contents(
+            "toast_duration_info = 1500\n\
+             toast_duration_error = 999999\n\
+             toast_duration_success = 0\n\
+             toast_duration_warning = soon\n",
+        );
+        assert_eq!(custom.toast_duration_info, 1500);
+        assert_eq!(custom.toast_duration_error, 60_000);
+        assert_eq!(custom.toast_duration_success, 3000);
+        assert_eq!(custom.toast_duration_warning, 3000);
+    }
+
     #[test]
     fn background_opacity_and_blur_parse_and_default() {
         let defaults = AppConfig::from_contents("");
@@ -1283,6 +2364,91 @@ mod tests {
         assert_eq!(old_key_ignored.background_opacity, 1.0);
     }
 
+    #[test]
+    fn reduce_transparency_overrides_opacity_and_blur() {
+        let defaults = AppConfig::from_contents("");
+        assert_eq!(defaults.reduce_transparency, None);
+
+        let reduced = AppConfig::from_contents(
+            "background_opacity = 0.6\n\
+             background_blur = true\n\
+             reduce_transparency = true\n",
+        );
+        assert_eq!(reduced.background_opacity, 0.6);
+        assert_eq!(reduced.effective_background_opacity(), 1.0);
+        assert!(!reduced.effective_background_blur());
+
+        let allowed = AppConfig::from_contents(
+            "background_opacity = 0.6\n\
+             background_blur = true\n\
+             reduce_transparency = false\n",
+        );
+        assert_eq!(allowed.effective_background_opacity(), 0.6);
+        assert!(allowed.effective_background_blur());
+
+        let auto = AppConfig::from_contents(
+            "reduce_transparency = true\n\
+             reduce_transparency = auto\n",
+        );
+        assert_eq!(auto.reduce_transparency, None);
+
+        let system_reduced = AppConfig {
+            system_reduce_transparency: true,
+            ..AppConfig::from_contents("background_opacity = 0.6\n")
+        };
+        assert_eq!(system_reduced.effective_background_opacity(), 1.0);
+        let overridden = AppConfig {
+            reduce_transparency: Some(false),
+            ..system_reduced
+        };
+        assert_eq!(overridden.effective_background_opacity(), 0.6);
+    }
+
+    #[test]
+    fn transparency_friendly_colors_only_apply_when_translucent() {
+        let defaults = AppConfig::from_contents("background_opacity = 0.6\n");
+        assert!(!defaults.transparency_friendly_colors);
+        assert!(!defaults.transparency_friendly_colors_active());
+
+        let opaque = AppConfig::from_contents("transparency_friendly_colors = true\n");
+        assert!(opaque.transparency_friendly_colors);
+        assert!(!opaque.transparency_friendly_colors_active());
+
+        let translucent = AppConfig::from_contents(
+            "transparency_friendly_colors = true\n\
+             background_opacity = 0.6\n",
+        );
+        assert!(translucent.transparency_friendly_colors_active());
+
+        let reduced = AppConfig::from_contents(
+            "transparency_friendly_colors = true\n\
+             background_opacity = 0.6\n\
+             reduce_transparency = true\n",
+        );
+        assert!(!reduced.transparency_friendly_colors_active());
+    }
+
+    #[test]
+    fn cursor_style_cycles_through_every_shape() {
+        let mut style = CursorStyle::Block;
+        let mut seen = Vec::new();
+        for _ in 0..4 {
+            seen.push(style);
+            style = style.next();
+            assert_eq!(CursorStyle::from_str(style.config_value()), Some(style));
+        }
+        assert_eq!(style, CursorStyle::Block);
+        assert_eq!(
+            seen,
+            [
+                CursorStyle::Block,
+                CursorStyle::Line,
+                CursorStyle::Underline,
+                CursorStyle::HollowBlock
+            ]
+        );
+    }
+
     #[test]
     fn cursor_style_and_blink_parse_and_default() {
         let defaults = AppConfig::from_contents("");
@@ -1298,10 +2464,345 @@ mod tests {
         let block = AppConfig::from_contents("cursor_style = block\n");
         assert_eq!(block.cursor_style, CursorStyle::Block);
 
+        let underline = AppConfig::from_contents("cursor_style = underline\n");
+        assert_eq!(underline.cursor_style, CursorStyle::Underline);
+
+        let hollow = AppConfig::from_contents("cursor_style = hollow_block\n");
+        assert_eq!(hollow.cursor_style, CursorStyle::HollowBlock);
+
+        let hollow_alias = AppConfig::from_contents("cursor_style = hollow\n");
+        assert_eq!(hollow_alias.cursor_style, CursorStyle::HollowBlock);
+
         let blink_disabled = AppConfig::from_contents("cursor_blink = false\n");
         assert!(!blink_disabled.cursor_blink);
     }
 
+    #[test]
+    fn scrollbar_prompt_markers_parses_and_defaults_on() {
+        assert!(AppConfig::from_contents("").scrollbar_prompt_markers);
+
+        let disabled = AppConfig::from_contents("scrollbar_prompt_markers = false\n");
+        assert!(!disabled.scrollbar_prompt_markers);
+    }
+
+    #[test]
+    fn scrollbar_match_density_parses_and_defaults_off() {
+        assert!(!AppConfig::from_contents("").scrollbar_match_density);
+
+        let enabled = AppConfig::from_contents("scrollbar_match_density = true\n");
+        assert!(enabled.scrollbar_match_density);
+    }
+
+    #[test]
+    fn cursor_contrast_parses_and_defaults_on() {
+        assert!(AppConfig::from_contents("").cursor_contrast);
+
+        let disabled = AppConfig::from_contents("cursor_contrast = false\n");
+        assert!(!disabled.cursor_contrast);
+    }
+
+    #[test]
+    fn show_wrap_indicator_parses_and_defaults() {
+        let defaults = AppConfig::from_contents("");
+        assert!(!defaults.show_wrap_indicator);
+
+        let enabled = AppConfig::from_contents("show_wrap_indicator = true\n");
+        assert!(enabled.show_wrap_indicator);
+    }
+
+    #[test]
+    fn render_whitespace_parses_and_defaults() {
+        let defaults = AppConfig::from_contents("");
+        assert_eq!(defaults.render_whitespace, RenderWhitespace::None);
+
+        let all = AppConfig::from_contents("render_whitespace = all\n");
+        assert_eq!(all.render_whitespace, RenderWhitespace::All);
+
+        let invalid = AppConfig::from_contents("render_whitespace = tabs\n");
+        assert_eq!(invalid.render_whitespace, RenderWhitespace::None);
+    }
+
+    #[test]
+    fn update_channel_parses_and_defaults() {
+        let defaults = AppConfig::from_contents("");
+        assert_eq!(defaults.update_channel, UpdateChannel::Stable);
+
+        let prerelease = AppConfig::from_contents("update_channel = Prerelease\n");
+        assert_eq!(prerelease.update_channel, UpdateChannel::Prerelease);
+
+        let invalid = AppConfig::from_contents("update_channel = nightly\n");
+        assert_eq!(invalid.update_channel, UpdateChannel::Stable);
+    }
+
+    #[test]
+    fn skipped_update_version_parses_and_can_be_cleared() {
+        assert!(
+            AppConfig::from_contents("")
+                .skipped_update_version
+                .is_none()
+        );
+
+        let skipped = AppConfig::from_contents("skipped_update_version = 1.2.0\n");
+        assert_eq!(skipped.skipped_update_version.as_deref(), Some("1.2.0"));
+
+        let cleared = AppConfig::from_contents("skipped_update_version = none\n");
+        assert!(cleared.skipped_update_version.is_none());
+    }
+
+    #[test]
+    fn require_url_scheme_parses_and_defaults() {
+        assert!(!AppConfig::from_contents("").require_url_scheme);
+
+        let enabled = AppConfig::from_contents("require_url_scheme = true\n");
+        assert!(enabled.require_url_scheme);
+    }
+
+    #[test]
+    fn macos_option_as_meta_parses_and_defaults_off() {
+        assert!(!AppConfig::from_contents("").macos_option_as_meta);
+
+        let enabled = AppConfig::from_contents("macos_option_as_meta = true\n");
+        assert!(enabled.macos_option_as_meta);
+    }
+
+    #[test]
+    fn sanitize_paste_parses_and_defaults_on() {
+        assert!(AppConfig::from_contents("").sanitize_paste);
+
+        let disabled = AppConfig::from_contents("sanitize_paste = false\n");
+        assert!(!disabled.sanitize_paste);
+    }
+
+    #[test]
+    fn double_click_selection_parses_and_defaults() {
+        let defaults = AppConfig::from_contents("");
+        assert_eq!(defaults.double_click_selection, DoubleClickSelection::Token);
+
+        let word = AppConfig::from_contents("double_click_selection = word\n");
+        assert_eq!(word.double_click_selection, DoubleClickSelection::Word);
+
+        let invalid = AppConfig::from_contents("double_click_selection = line\n");
+        assert_eq!(invalid.double_click_selection, DoubleClickSelection::Token);
+    }
+
+    #[test]
+    fn trim_trailing_whitespace_on_copy_parses_and_defaults_on() {
+        assert!(AppConfig::from_contents("").trim_trailing_whitespace_on_copy);
+
+        let disabled = AppConfig::from_contents("trim_trailing_whitespace_on_copy = false\n");
+        assert!(!disabled.trim_trailing_whitespace_on_copy);
+    }
+
+    #[test]
+    fn bold_is_bright_parses_and_defaults() {
+        let defaults = AppConfig::from_contents("");
+        assert!(!defaults.bold_is_bright);
+
+        let enabled = AppConfig::from_contents("bold_is_bright = true\n");
+        assert!(enabled.bold_is_bright);
+    }
+
+    #[test]
+    fn builtin_box_drawing_parses_and_defaults_off() {
+        assert!(!AppConfig::from_contents("").builtin_box_drawing);
+
+        let enabled = AppConfig::from_contents("builtin_box_drawing = true\n");
+        assert!(enabled.builtin_box_drawing);
+    }
+
+    #[test]
+    fn diagnostic_patterns_default_and_override() {
+        let defaults = AppConfig::from_contents("");
+        assert_eq!(
+            defaults.diagnostic_patterns,
+            vec![r"(?i)\berror\b", r"(?i)\bwarning\b", "FAILED"]
+        );
+
+        let configured = AppConfig::from_contents(
+            "diagnostic_pattern = ^error\\[E\\d+\\]\n\
+             diagnostic_pattern = panicked at\n\
+             diagnostic_pattern =\n",
+        );
+        assert_eq!(
+            configured.diagnostic_patterns,
+            vec![r"^error\[E\d+\]", "panicked at"]
+        );
+    }
+
+    #[test]
+    fn minimum_contrast_parses_and_clamps() {
+        let defaults = AppConfig::from_contents("");
+        assert_eq!(defaults.minimum_contrast, 1.0);
+
+        let configured = AppConfig::from_contents("minimum_contrast = 4.5\n");
+        assert_eq!(configured.minimum_contrast, 4.5);
+
+        let clamped_low = AppConfig::from_contents("minimum_contrast = 0.2\n");
+        assert_eq!(clamped_low.minimum_contrast, 1.0);
+
+        let clamped_high = AppConfig::from_contents("minimum_contrast = 30\n");
+        assert_eq!(clamped_high.minimum_contrast, 21.0);
+    }
+
+    #[test]
+    fn search_highlight_colors_parse_and_default() {
+        let defaults = AppConfig::from_contents("");
+        assert!(defaults.search_match_color.is_none());
+        assert!(defaults.search_current_color.is_none());
+
+        let configured = AppConfig::from_contents(
+            "search_match_color = #ff0000\n\
+             search_current_color = 00ff00\n",
+        );
+        let match_color = configured.search_match_color.unwrap();
+        assert!((match_color.r - 1.0).abs() < 0.01);
+        assert!(match_color.g.abs() < 0.01);
+        let current_color = configured.search_current_color.unwrap();
+        assert!((current_color.g - 1.0).abs() < 0.01);
+
+        let invalid = AppConfig::from_contents("search_match_color = yellow\n");
+        assert!(invalid.search_match_color.is_none());
+    }
+
+    #[test]
+    fn profile_sections_override_fonts_without_touching_root() {
+        let config = AppConfig::from_contents(
+            "font_size = 14\n\
+             [profile.Presentation]\n\
+             font_size = 24\n\
+             font_family = \"Fira Code\"\n\
+             [profile.small]\n\
+             font_size = -3\n\
+             [colors]\n\
+             foreground = #ffffff\n",
+        );
+
+        assert_eq!(config.font_size, 14.0);
+        assert_eq!(config.profiles.len(), 2);
+        assert_eq!(config.profiles[0].name, "Presentation");
+        assert_eq!(config.profiles[0].font_size, Some(24.0));
+        assert_eq!(config.profiles[0].font_family.as_deref(), Some("Fira Code"));
+        assert_eq!(config.profiles[1].name, "small");
+        assert_eq!(config.profiles[1].font_size, None);
+        assert!(config.colors.foreground.is_some());
+    }
+
+    #[test]
+    fn profile_diagnostic_patterns_replace_the_root_list() {
+        let config = AppConfig::from_contents(
+            "diagnostic_pattern = panicked\n\
+             [profile.rust]\n\
+             diagnostic_pattern = ^error\\[E\\d+\\]\n\
+             diagnostic_pattern = ^warning:\n\
+             [profile.plain]\n\
+             font_size = 12\n",
+        );
+
+        assert_eq!(config.diagnostic_patterns, ["panicked"]);
+        assert_eq!(
+            config.profiles[0].diagnostic_patterns,
+            ["^error\\[E\\d+\\]", "^warning:"]
+        );
+        assert!(config.profiles[1].diagnostic_patterns.is_empty());
+    }
+
+    #[test]
+    fn profile_startup_command_overrides_or_disables_the_root_one() {
+        let config = AppConfig::from_contents(
+            "startup_command = tmux attach\n\
+             [profile.logs]\n\
+             startup_command = tail -f /var/log/system.log\n\
+             [profile.bare]\n\
+             startup_command = none\n\
+             [profile.plain]\n\
+             font_size = 12\n",
+        );
+
+        assert_eq!(config.startup_command.as_deref(), Some("tmux attach"));
+        assert_eq!(
+            config.profiles[0].startup_command,
+            Some(Some("tail -f /var/log/system.log".to_string()))
+        );
+        assert_eq!(config.profiles[1].startup_command, Some(None));
+        assert_eq!(config.profiles[2].startup_command, None);
+    }
+
+    #[test]
+    fn command_sections_define_palette_actions() {
+        let config = AppConfig::from_contents(
+            "[command.Deploy]\n\
+             run = make deploy\n\
+             keywords = ship release\n\
+             [command.Interrupt]\n\
+             send_keys = \"\\x03\\e[A\\r\"\n\
+             [command.Docs]\n\
+             open_url = https://example.com/docs\n\
+             [command.Empty]\n\
+             keywords = nothing\n",
+        );
+
+        assert_eq!(config.custom_commands.len(), 3);
+        assert_eq!(config.custom_commands[0].name, "Deploy");
+        assert_eq!(config.custom_commands[0].keywords, "ship release");
+        assert_eq!(
+            config.custom_commands[0].action,
+            Some(CustomCommandAction::RunCommand("make deploy".to_string()))
+        );
+        assert_eq!(
+            config.custom_commands[1].action,
+            Some(CustomCommandAction::SendKeys("\x03\x1b[A\r".to_string()))
+        );
+        assert_eq!(
+            config.custom_commands[2].action,
+            Some(CustomCommandAction::OpenUrl(
+                "https://example.com/docs".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn decode_key_escapes_keeps_unknown_sequences() {
+        assert_eq!(decode_key_escapes("a\\tb\\\\"), "a\tb\\");
+        assert_eq!(decode_key_escapes("\\q\\x4"), "\\q\\x4");
+        assert_eq!(decode_key_escapes("\\x41\\xff"), "A\\xff");
+    }
+
+    #[test]
+    fn search_bar_style_parses_and_defaults() {
+        let defaults = AppConfig::from_contents("");
+        assert_eq!(defaults.search_bar_style, SearchBarStyle::Neutral);
+
+        let accent = AppConfig::from_contents("search_bar_style = accent\n");
+        assert_eq!(accent.search_bar_style, SearchBarStyle::Accent);
+
+        let invalid = AppConfig::from_contents("search_bar_style = loud\n");
+        assert_eq!(invalid.search_bar_style, SearchBarStyle::Neutral);
+    }
+
+    #[test]
+    fn search_trim_query_parses_and_defaults_off() {
+        assert!(!AppConfig::from_contents("").search_trim_query);
+
+        let enabled = AppConfig::from_contents("search_trim_query = true\n");
+        assert!(enabled.search_trim_query);
+    }
+
+    #[test]
+    fn search_center_on_match_parses_and_defaults_on() {
+        assert!(AppConfig::from_contents("").search_center_on_match);
+
+        let disabled = AppConfig::from_contents("search_center_on_match = false\n");
+        assert!(!disabled.search_center_on_match);
+    }
+
+    #[test]
+    fn close_search_on_click_parses_and_defaults_off() {
+        assert!(!AppConfig::from_contents("").close_search_on_click);
+
+        let enabled = AppConfig::from_contents("close_search_on_click = true\n");
+        assert!(enabled.close_search_on_click);
+    }
+
     #[test]
     fn scrollback_history_parses_and_clamps() {
         let defaults = AppConfig::from_contents("");
@@ -1326,6 +2827,97 @@ mod tests {
         assert!(!configured.warn_on_quit_with_running_process);
     }
 
+    #[test]
+    fn inherit_tab_overrides_parses_and_defaults_on() {
+        assert!(AppConfig::from_contents("").inherit_tab_overrides);
+
+        let disabled = AppConfig::from_contents("inherit_tab_overrides = false\n");
+        assert!(!disabled.inherit_tab_overrides);
+    }
+
+    #[test]
+    fn long_lines_parses_and_defaults_to_wrap() {
+        let defaults = AppConfig::from_contents("");
+        assert_eq!(defaults.long_lines, LongLines::Wrap);
+        assert_eq!(defaults.long_line_limit, 10_000);
+
+        let truncate = AppConfig::from_contents("long_lines = truncate\nlong_line_limit = 500\n");
+        assert_eq!(truncate.long_lines, LongLines::Truncate);
+        assert_eq!(truncate.long_line_limit, 500);
+
+        let fold = AppConfig::from_contents("long_lines = fold\n");
+        assert_eq!(fold.long_lines, LongLines::Fold);
+
+        let invalid = AppConfig::from_contents("long_lines = hide\nlong_line_limit = 0\n");
+        assert_eq!(invalid.long_lines, LongLines::Wrap);
+        assert_eq!(invalid.long_line_limit, 10_000);
+    }
+
+    #[test]
+    fn max_content_columns_parses_and_defaults_to_uncapped() {
+        assert_eq!(AppConfig::from_contents("").max_content_columns, 0);
+
+        let capped = AppConfig::from_contents("max_content_columns = 120\n");
+        assert_eq!(capped.max_content_columns, 120);
+
+        let invalid = AppConfig::from_contents("max_content_columns = wide\n");
+        assert_eq!(invalid.max_content_columns, 0);
+    }
+
+    #[test]
+    fn startup_window_mode_parses_and_defaults_to_windowed() {
+        assert_eq!(
+            AppConfig::from_contents("").startup_window_mode,
+            StartupWindowMode::Windowed
+        );
+
+        let maximized = AppConfig::from_contents("startup_window_mode = Maximized\n");
+        assert_eq!(maximized.startup_window_mode, StartupWindowMode::Maximized);
+
+        let fullscreen = AppConfig::from_contents("startup_window_mode = fullscreen\n");
+        assert_eq!(
+            fullscreen.startup_window_mode,
+            StartupWindowMode::Fullscreen
+        );
+
+        let invalid = AppConfig::from_contents("startup_window_mode = restore_last\n");
+        assert_eq!(invalid.startup_window_mode, StartupWindowMode::Windowed);
+    }
+
+    #[test]
+    fn max_tabs_parses_and_defaults_to_unlimited() {
+        assert_eq!(AppConfig::from_contents("").max_tabs, 0);
+
+        let limited = AppConfig::from_contents("max_tabs = 8\n");
+        assert_eq!(limited.max_tabs, 8);
+
+        let invalid = AppConfig::from_contents("max_tabs = -1\n");
+        assert_eq!(invalid.max_tabs, 0);
+    }
+
+    #[test]
+    fn confirm_quit_multiple_tabs_parses_and_defaults_off() {
+        assert!(!AppConfig::from_contents("").confirm_quit_multiple_tabs);
+
+        let enabled = AppConfig::from_contents("confirm_quit_multiple_tabs = true\n");
+        assert!(enabled.confirm_quit_multiple_tabs);
+    }
+
+    #[test]
+    fn on_shell_exit_parses_and_defaults() {
+        let defaults = AppConfig::from_contents("");
+        assert_eq!(defaults.on_shell_exit, ShellExitBehavior::Hold);
+
+        let close = AppConfig::from_contents("on_shell_exit = close\n");
+        assert_eq!(close.on_shell_exit, ShellExitBehavior::Close);
+
+        let restart = AppConfig::from_contents("on_shell_exit = Restart\n");
+        assert_eq!(restart.on_shell_exit, ShellExitBehavior::Restart);
+
+        let invalid = AppConfig::from_contents("on_shell_exit = explode\n");
+        assert_eq!(invalid.on_shell_exit, ShellExitBehavior::Hold);
+    }
+
     #[test]
     fn removed_hide_titlebar_buttons_key_is_ignored_as_unknown() {
         let configured = AppConfig::from_contents(
@@ -1421,4 +3013,16 @@ mod tests {
             "theme = termy\nfont_size = 14\n\n[colors]\nforeground = #111111\n"
         );
     }
+
+    #[test]
+    fn replace_or_insert_section_keeps_comments_in_the_section() {
+        let input = "[colors]\n# Tokyo Night\nforeground = #ffffff\n# red = #ff0000\n\n[tab_title]\nfallback = x\n";
+        let output =
+            replace_or_insert_section(input, "colors", &["foreground = #111111".to_string()]);
+
+        assert_eq!(
+            output,
+            "[colors]\nforeground = #111111\n# Tokyo Night\n# red = #ff0000\n\n[tab_title]\nfallback = x\n"
+        );
+    }
 }
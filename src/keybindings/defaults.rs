@@ -49,6 +49,16 @@ pub fn default_keybinds() -> Vec<DefaultKeybind> {
             trigger: "secondary-0",
             action: CommandAction::ZoomReset,
         },
+        #[cfg(target_os = "macos")]
+        DefaultKeybind {
+            trigger: "secondary-ctrl-f",
+            action: CommandAction::ToggleFullscreen,
+        },
+        #[cfg(not(target_os = "macos"))]
+        DefaultKeybind {
+            trigger: "f11",
+            action: CommandAction::ToggleFullscreen,
+        },
         // Search
         DefaultKeybind {
             trigger: "secondary-f",
@@ -62,6 +72,30 @@ pub fn default_keybinds() -> Vec<DefaultKeybind> {
             trigger: "secondary-shift-g",
             action: CommandAction::SearchPrevious,
         },
+        DefaultKeybind {
+            trigger: "secondary-shift-k",
+            action: CommandAction::ClearAllHighlights,
+        },
+        DefaultKeybind {
+            trigger: "secondary-enter",
+            action: CommandAction::CopyMatchAndNext,
+        },
+        DefaultKeybind {
+            trigger: "home",
+            action: CommandAction::SearchFirstMatch,
+        },
+        DefaultKeybind {
+            trigger: "end",
+            action: CommandAction::SearchLastMatch,
+        },
+        DefaultKeybind {
+            trigger: "pageup",
+            action: CommandAction::SearchPreviousPage,
+        },
+        DefaultKeybind {
+            trigger: "pagedown",
+            action: CommandAction::SearchNextPage,
+        },
     ];
 
     #[cfg(any(target_os = "macos", target_os = "windows"))]
@@ -107,6 +141,56 @@ mod tests {
         assert!(zoom_in_triggers.contains(&"secondary-+"));
     }
 
+    #[test]
+    fn toggle_fullscreen_is_bound_by_default() {
+        let expected = if cfg!(target_os = "macos") {
+            "secondary-ctrl-f"
+        } else {
+            "f11"
+        };
+
+        assert!(
+            default_keybinds()
+                .iter()
+                .any(|binding| binding.action == CommandAction::ToggleFullscreen
+                    && binding.trigger == expected)
+        );
+    }
+
+    #[test]
+    fn clear_all_highlights_is_bound_by_default() {
+        assert!(default_keybinds().iter().any(|binding| binding.action
+            == CommandAction::ClearAllHighlights
+            && binding.trigger == "secondary-shift-k"));
+    }
+
+    #[test]
+    fn copy_match_and_next_is_bound_by_default() {
+        assert!(
+            default_keybinds()
+                .iter()
+                .any(|binding| binding.action == CommandAction::CopyMatchAndNext
+                    && binding.trigger == "secondary-enter")
+        );
+    }
+
+    #[test]
+    fn search_bar_navigation_keys_are_bound_by_default() {
+        let defaults = default_keybinds();
+        for (trigger, action) in [
+            ("home", CommandAction::SearchFirstMatch),
+            ("end", CommandAction::SearchLastMatch),
+            ("pageup", CommandAction::SearchPreviousPage),
+            ("pagedown", CommandAction::SearchNextPage),
+        ] {
+            assert!(
+                defaults
+                    .iter()
+                    .any(|binding| binding.action == action && binding.trigger == trigger)
+            );
+        }
+    }
+
     #[test]
     fn advanced_palette_actions_are_unbound_by_default() {
         let defaults = default_keybinds();
@@ -140,5 +224,125 @@ mod tests {
                 .iter()
                 .all(|binding| binding.action != CommandAction::NativeSdkExample)
         );
+        assert!(
+            defaults
+                .iter()
+                .all(|binding| binding.action != CommandAction::CopyWorkingDir)
+        );
+        assert!(
+            defaults
+                .iter()
+                .all(|binding| binding.action != CommandAction::RevealWorkingDir)
+        );
+        assert!(
+            defaults
+                .iter()
+                .all(|binding| binding.action != CommandAction::RunCliCommand)
+        );
+        assert!(
+            defaults
+                .iter()
+                .all(|binding| binding.action != CommandAction::ScrollPageUp)
+        );
+        assert!(
+            defaults
+                .iter()
+                .all(|binding| binding.action != CommandAction::ScrollPageDown)
+        );
+        assert!(
+            defaults
+                .iter()
+                .all(|binding| binding.action != CommandAction::ScrollHalfPageUp)
+        );
+        assert!(
+            defaults
+                .iter()
+                .all(|binding| binding.action != CommandAction::ScrollHalfPageDown)
+        );
+        assert!(
+            defaults
+                .iter()
+                .all(|binding| binding.action != CommandAction::NextDiagnostic)
+        );
+        assert!(
+            defaults
+                .iter()
+                .all(|binding| binding.action != CommandAction::PreviousDiagnostic)
+        );
+        assert!(
+            defaults
+                .iter()
+                .all(|binding| binding.action != CommandAction::IncreaseOpacity)
+        );
+        assert!(
+            defaults
+                .iter()
+                .all(|binding| binding.action != CommandAction::DecreaseOpacity)
+        );
+        assert!(
+            defaults
+                .iter()
+                .all(|binding| binding.action != CommandAction::ClearTabTitle)
+        );
+        assert!(
+            defaults
+                .iter()
+                .all(|binding| binding.action != CommandAction::ToggleSearchOutputOnly)
+        );
+        assert!(
+            defaults
+                .iter()
+                .all(|binding| binding.action != CommandAction::InsertCommandOutput)
+        );
+        assert!(
+            defaults
+                .iter()
+                .all(|binding| binding.action != CommandAction::SaveScrollback)
+        );
+        assert!(
+            defaults
+                .iter()
+                .all(|binding| binding.action != CommandAction::CopyScreen)
+        );
+        assert!(
+            defaults
+                .iter()
+                .all(|binding| binding.action != CommandAction::CopyScrollback)
+        );
+        assert!(
+            defaults
+                .iter()
+                .all(|binding| binding.action != CommandAction::SwitchProfile)
+        );
+        assert!(
+            defaults
+                .iter()
+                .all(|binding| binding.action != CommandAction::ResumeOutput)
+        );
+        assert!(
+            defaults
+                .iter()
+                .all(|binding| binding.action != CommandAction::CycleCursorStyle)
+        );
+        assert!(
+            defaults
+                .iter()
+                .all(|binding| binding.action != CommandAction::ToggleShellIntegration)
+        );
+        assert!(
+            defaults
+                .iter()
+                .all(|binding| binding.action != CommandAction::DuplicateTabRerun)
+        );
+        assert!(
+            defaults
+                .iter()
+                .all(|binding| binding.action != CommandAction::ReloadThemes)
+        );
+        assert!(
+            defaults
+                .iter()
+                .all(|binding| binding.action != CommandAction::FoldLongLines)
+        );
     }
 }
@@ -92,7 +92,11 @@ fn main() {
             .ok();
         });
 
+        for error in config::load_user_themes() {
+            termy_toast::warning(error);
+        }
         let app_config = config::AppConfig::load_or_create();
+        termy_themes::set_theme_cache_capacity(app_config.theme_cache_size);
         keybindings::install_keybindings(cx, &app_config);
         let window_background = initial_window_background_appearance(&app_config);
         let window_width = app_config.window_width;
@@ -110,6 +114,11 @@ fn main() {
         let window_width = window_width.max(MIN_WINDOW_WIDTH);
         let window_height = window_height.max(MIN_WINDOW_HEIGHT);
         let bounds = Bounds::centered(None, size(px(window_width), px(window_height)), cx);
+        let window_bounds = match startup_config.startup_window_mode {
+            config::StartupWindowMode::Windowed => WindowBounds::Windowed(bounds),
+            config::StartupWindowMode::Maximized => WindowBounds::Maximized(bounds),
+            config::StartupWindowMode::Fullscreen => WindowBounds::Fullscreen(bounds),
+        };
 
         #[cfg(target_os = "macos")]
         let titlebar = Some(gpui::TitlebarOptions {
@@ -132,7 +141,7 @@ fn main() {
 
         cx.open_window(
             WindowOptions {
-                window_bounds: Some(WindowBounds::Windowed(bounds)),
+                window_bounds: Some(window_bounds),
                 titlebar,
                 window_background,
                 ..Default::default()
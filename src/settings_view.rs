@@ -83,7 +83,11 @@ impl SettingsWindow {
         let mut available_font_families = window.text_system().all_font_names();
         available_font_families.sort_unstable_by_key(|font| font.to_ascii_lowercase());
         available_font_families.dedup_by(|left, right| left.eq_ignore_ascii_case(right));
-        let colors = TerminalColors::from_theme(&config.theme, &config.colors);
+        let colors = TerminalColors::from_theme(
+            &config.theme,
+            &config.colors,
+            config.transparency_friendly_colors_active(),
+        );
         let view = Self {
             active_section: SettingsSection::Appearance,
             config,
@@ -140,7 +144,11 @@ impl SettingsWindow {
     }
 
     fn apply_runtime_config(&mut self, config: AppConfig) -> bool {
-        self.colors = TerminalColors::from_theme(&config.theme, &config.colors);
+        self.colors = TerminalColors::from_theme(
+            &config.theme,
+            &config.colors,
+            config.transparency_friendly_colors_active(),
+        );
         self.config = config;
         true
     }
@@ -237,14 +245,6 @@ impl SettingsWindow {
         c
     }
 
-    fn srgb_to_linear(channel: f32) -> f32 {
-        if channel <= 0.04045 {
-            channel / 12.92
-        } else {
-            ((channel + 0.055) / 1.055).powf(2.4)
-        }
-    }
-
     fn composite_over(fg: Rgba, bg: Rgba) -> Rgba {
         let fg_alpha = fg.a.clamp(0.0, 1.0);
         Rgba {
@@ -255,19 +255,11 @@ impl SettingsWindow {
         }
     }
 
-    fn relative_luminance(color: Rgba, backdrop: Rgba) -> f32 {
-        let composited = Self::composite_over(color, backdrop);
-        let r = Self::srgb_to_linear(composited.r);
-        let g = Self::srgb_to_linear(composited.g);
-        let b = Self::srgb_to_linear(composited.b);
-        0.2126 * r + 0.7152 * g + 0.0722 * b
-    }
-
     fn contrast_ratio(a: Rgba, b: Rgba, backdrop: Rgba) -> f32 {
-        let l1 = Self::relative_luminance(a, backdrop);
-        let l2 = Self::relative_luminance(b, backdrop);
-        let (lighter, darker) = if l1 >= l2 { (l1, l2) } else { (l2, l1) };
-        (lighter + 0.05) / (darker + 0.05)
+        termy_themes::contrast_ratio(
+            Self::composite_over(a, backdrop),
+            Self::composite_over(b, backdrop),
+        )
     }
 
     fn contrasting_text_for_fill(&self, fill: Rgba, backdrop: Rgba) -> Rgba {
@@ -638,10 +630,7 @@ impl SettingsWindow {
     }
 
     fn ordered_theme_ids_for_settings(&self) -> Vec<String> {
-        let mut theme_ids: Vec<String> = termy_themes::available_theme_ids()
-            .into_iter()
-            .map(ToOwned::to_owned)
-            .collect();
+        let mut theme_ids = termy_themes::available_theme_ids();
         theme_ids.push("shell-decide".to_string());
 
         if !theme_ids.iter().any(|theme| theme == &self.config.theme) {
@@ -1416,6 +1405,62 @@ impl SettingsWindow {
                                 let _ = set_config_value("cursor_style", "line");
                                 cx.notify();
                             }))
+                    })
+                    .child({
+                        let is_selected = current == CursorStyle::Underline;
+                        div()
+                            .id("cursor-style-underline")
+                            .px_3()
+                            .py_1()
+                            .rounded_md()
+                            .cursor_pointer()
+                            .text_xs()
+                            .font_weight(gpui::FontWeight::MEDIUM)
+                            .bg(if is_selected {
+                                accent.into()
+                            } else {
+                                switch_off_bg
+                            })
+                            .text_color(if is_selected {
+                                selected_text
+                            } else {
+                                text_secondary
+                            })
+                            .hover(|s| if !is_selected { s.bg(hover_bg) } else { s })
+                            .child("Underline")
+                            .on_click(cx.listener(|view, _, _, cx| {
+                                view.config.cursor_style = CursorStyle::Underline;
+                                let _ = set_config_value("cursor_style", "underline");
+                                cx.notify();
+                            }))
+                    })
+                    .child({
+                        let is_selected = current == CursorStyle::HollowBlock;
+                        div()
+                            .id("cursor-style-hollow-block")
+                            .px_3()
+                            .py_1()
+                            .rounded_md()
+                            .cursor_pointer()
+                            .text_xs()
+                            .font_weight(gpui::FontWeight::MEDIUM)
+                            .bg(if is_selected {
+                                accent.into()
+                            } else {
+                                switch_off_bg
+                            })
+                            .text_color(if is_selected {
+                                selected_text
+                            } else {
+                                text_secondary
+                            })
+                            .hover(|s| if !is_selected { s.bg(hover_bg) } else { s })
+                            .child("Hollow")
+                            .on_click(cx.listener(|view, _, _, cx| {
+                                view.config.cursor_style = CursorStyle::HollowBlock;
+                                let _ = set_config_value("cursor_style", "hollow_block");
+                                cx.notify();
+                            }))
                     }),
             )
     }
@@ -0,0 +1,176 @@
+use super::*;
+
+const CLI_OUTPUT_PANEL_HEIGHT: f32 = 420.0;
+const CLI_OUTPUT_LINE_HEIGHT: f32 = 18.0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum CliOutputCommand {
+    ShowConfig,
+    ValidateConfig,
+    ListKeybinds,
+    ListActions,
+    ListThemes,
+    Version,
+    Help,
+}
+
+impl CliOutputCommand {
+    pub(super) const ALL: [Self; 7] = [
+        Self::ShowConfig,
+        Self::ValidateConfig,
+        Self::ListKeybinds,
+        Self::ListActions,
+        Self::ListThemes,
+        Self::Version,
+        Self::Help,
+    ];
+
+    pub(super) fn title(self) -> &'static str {
+        match self {
+            Self::ShowConfig => "-show-config",
+            Self::ValidateConfig => "-validate-config",
+            Self::ListKeybinds => "-list-keybinds",
+            Self::ListActions => "-list-actions",
+            Self::ListThemes => "-list-themes",
+            Self::Version => "-version",
+            Self::Help => "-help",
+        }
+    }
+
+    pub(super) fn keywords(self) -> &'static str {
+        match self {
+            Self::ShowConfig => "display current configuration settings",
+            Self::ValidateConfig => "check configuration errors warnings",
+            Self::ListKeybinds => "keybindings shortcuts keys",
+            Self::ListActions => "keybind actions commands",
+            Self::ListThemes => "available themes colors",
+            Self::Version => "version information about",
+            Self::Help => "available commands usage",
+        }
+    }
+
+    fn output(self) -> String {
+        use termy_cli_core::commands;
+
+        match self {
+            Self::ShowConfig => commands::show_config::output().unwrap_or_else(|error| error),
+            Self::ValidateConfig => commands::validate_config::output().0,
+            Self::ListKeybinds => commands::list_keybinds::output(),
+            Self::ListActions => commands::list_actions::output(),
+            Self::ListThemes => commands::list_themes::output(),
+            Self::Version => format!("Termy {}\n", env!("CARGO_PKG_VERSION")),
+            Self::Help => commands::help::output(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(super) struct CliOutput {
+    title: String,
+    lines: Vec<String>,
+}
+
+impl TerminalView {
+    pub(super) fn open_cli_output(&mut self, command: CliOutputCommand, cx: &mut Context<Self>) {
+        let text = command.output();
+        self.cli_output = Some(CliOutput {
+            title: format!("termy {}", command.title()),
+            lines: text.lines().map(ToOwned::to_owned).collect(),
+        });
+        self.cli_output_scroll_handle = ScrollHandle::new();
+        cx.notify();
+    }
+
+    pub(super) fn close_cli_output(&mut self, cx: &mut Context<Self>) {
+        if self.cli_output.take().is_some() {
+            cx.notify();
+        }
+    }
+
+    pub(super) fn render_cli_output_modal(&mut self, cx: &mut Context<Self>) -> Option<AnyElement> {
+        let output = self.cli_output.as_ref()?;
+        let overlay_style = self.overlay_style();
+        let overlay_bg = overlay_style.dim_background(COMMAND_PALETTE_DIM_ALPHA);
+        let panel_bg = overlay_style.panel_background_with_floor(
+            COMMAND_PALETTE_PANEL_BG_ALPHA,
+            COMMAND_PALETTE_PANEL_SOLID_ALPHA,
+        );
+        let panel_border = overlay_style.panel_cursor(OVERLAY_PANEL_BORDER_ALPHA);
+        let primary_text = overlay_style.panel_foreground(OVERLAY_PRIMARY_TEXT_ALPHA);
+        let muted_text = overlay_style.panel_foreground(OVERLAY_MUTED_TEXT_ALPHA);
+
+        let lines = output.lines.iter().map(|line| {
+            div()
+                .h(px(CLI_OUTPUT_LINE_HEIGHT))
+                .whitespace_nowrap()
+                .child(line.clone())
+        });
+
+        Some(
+            div()
+                .id("cli-output-modal")
+                .size_full()
+                .absolute()
+                .top_0()
+                .left_0()
+                .occlude()
+                .on_click(cx.listener(|this, _event, _window, cx| {
+                    this.close_cli_output(cx);
+                }))
+                .child(div().size_full().bg(overlay_bg).absolute().top_0().left_0())
+                .child(
+                    div()
+                        .size_full()
+                        .absolute()
+                        .top_0()
+                        .left_0()
+                        .flex()
+                        .flex_col()
+                        .items_center()
+                        .pt(px(36.0))
+                        .child(
+                            div()
+                                .id("cli-output-panel")
+                                .w(px(COMMAND_PALETTE_WIDTH))
+                                .px(px(10.0))
+                                .py(px(10.0))
+                                .rounded_md()
+                                .bg(panel_bg)
+                                .border_1()
+                                .border_color(panel_border)
+                                .on_click(cx.listener(|_this, _event, _window, cx| {
+                                    cx.stop_propagation();
+                                }))
+                                .child(
+                                    div()
+                                        .w_full()
+                                        .pb(px(6.0))
+                                        .text_size(px(11.0))
+                                        .text_color(muted_text)
+                                        .child(output.title.clone()),
+                                )
+                                .child(
+                                    div()
+                                        .id("cli-output-body")
+                                        .w_full()
+                                        .h(px(CLI_OUTPUT_PANEL_HEIGHT))
+                                        .overflow_scroll()
+                                        .track_scroll(&self.cli_output_scroll_handle)
+                                        .font_family(self.font_family.clone())
+                                        .text_size(px(12.0))
+                                        .text_color(primary_text)
+                                        .children(lines),
+                                )
+                                .child(
+                                    div()
+                                        .pt(px(8.0))
+                                        .text_size(px(11.0))
+                                        .text_color(muted_text)
+                                        .child("Esc: Close  Scroll: Navigate"),
+                                ),
+                        ),
+                )
+                .into_any(),
+        )
+    }
+}
@@ -27,6 +27,37 @@ impl CommandPaletteItem {
             kind: CommandPaletteItemKind::Theme(theme_id),
         }
     }
+
+    fn profile(name: Option<String>, is_active: bool) -> Self {
+        let label = name.as_deref().unwrap_or("Default");
+        let title = if is_active {
+            format!("✓ {}", label)
+        } else {
+            label.to_string()
+        };
+
+        Self {
+            title,
+            keywords: "profile font size".to_string(),
+            kind: CommandPaletteItemKind::Profile(name),
+        }
+    }
+
+    fn custom(command: &CustomCommandConfig, action: CustomCommandAction) -> Self {
+        Self {
+            title: command.name.clone(),
+            keywords: format!("custom {}", command.keywords),
+            kind: CommandPaletteItemKind::Custom(action),
+        }
+    }
+
+    fn cli_command(command: CliOutputCommand) -> Self {
+        Self {
+            title: command.title().to_string(),
+            keywords: command.keywords().to_string(),
+            kind: CommandPaletteItemKind::CliCommand(command),
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -103,16 +134,38 @@ impl TerminalView {
             CommandPaletteMode::Commands => CommandAction::palette_entries(self.use_tabs)
                 .into_iter()
                 .map(|entry| CommandPaletteItem::command(entry.title, entry.keywords, entry.action))
+                .chain(self.custom_commands.iter().filter_map(|command| {
+                    let action = command.action.clone()?;
+                    Some(CommandPaletteItem::custom(command, action))
+                }))
                 .collect(),
             CommandPaletteMode::Themes => self.command_palette_theme_items(),
+            CommandPaletteMode::CliCommands => CliOutputCommand::ALL
+                .into_iter()
+                .map(CommandPaletteItem::cli_command)
+                .collect(),
+            CommandPaletteMode::InsertCommandOutput => Vec::new(),
+            CommandPaletteMode::Profiles => self.command_palette_profile_items(),
         }
     }
 
+    fn command_palette_profile_items(&self) -> Vec<CommandPaletteItem> {
+        let active = self.tabs[self.active_tab].profile.as_deref();
+        std::iter::once(None)
+            .chain(
+                self.profiles
+                    .iter()
+                    .map(|profile| Some(profile.name.clone())),
+            )
+            .map(|name| {
+                let is_active = name.as_deref() == active;
+                CommandPaletteItem::profile(name, is_active)
+            })
+            .collect()
+    }
+
     fn command_palette_theme_items(&self) -> Vec<CommandPaletteItem> {
-        let theme_ids: Vec<String> = termy_themes::available_theme_ids()
-            .into_iter()
-            .map(ToOwned::to_owned)
-            .collect();
+        let theme_ids = termy_themes::available_theme_ids();
 
         Self::ordered_theme_ids_for_palette(theme_ids, &self.theme_id)
             .into_iter()
@@ -437,11 +490,23 @@ impl TerminalView {
     fn command_palette_escape_action(mode: CommandPaletteMode) -> CommandPaletteEscapeAction {
         match mode {
             CommandPaletteMode::Commands => CommandPaletteEscapeAction::ClosePalette,
-            CommandPaletteMode::Themes => CommandPaletteEscapeAction::BackToCommands,
+            CommandPaletteMode::Themes
+            | CommandPaletteMode::CliCommands
+            | CommandPaletteMode::InsertCommandOutput
+            | CommandPaletteMode::Profiles => CommandPaletteEscapeAction::BackToCommands,
         }
     }
 
     fn execute_command_palette_selection(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.command_palette_mode == CommandPaletteMode::InsertCommandOutput {
+            let command = self.command_palette_query().trim().to_string();
+            if !command.is_empty() {
+                self.close_command_palette(cx);
+                self.insert_command_output(command, cx);
+            }
+            return;
+        }
+
         let items = self.filtered_command_palette_items();
         if items.is_empty() {
             return;
@@ -466,7 +531,37 @@ impl TerminalView {
             CommandPaletteItemKind::Theme(theme_id) => {
                 self.select_theme_from_palette(&theme_id, cx)
             }
+            CommandPaletteItemKind::CliCommand(command) => {
+                self.close_command_palette(cx);
+                self.open_cli_output(command, cx);
+            }
+            CommandPaletteItemKind::Profile(name) => {
+                self.close_command_palette(cx);
+                self.set_active_tab_profile(name, cx);
+            }
+            CommandPaletteItemKind::Custom(action) => {
+                self.close_command_palette(cx);
+                self.run_custom_command(action, cx);
+            }
+        }
+    }
+
+    fn run_custom_command(&mut self, action: CustomCommandAction, cx: &mut Context<Self>) {
+        match action {
+            CustomCommandAction::RunCommand(command) => {
+                self.write_terminal_input(format!("{}\r", command).as_bytes(), cx);
+                self.clear_selection();
+            }
+            CustomCommandAction::SendKeys(keys) => {
+                self.write_terminal_input(keys.as_bytes(), cx);
+            }
+            CustomCommandAction::OpenUrl(url) => {
+                if !Self::open_link(&url) {
+                    termy_toast::error(format!("Failed to open {}", url));
+                }
+            }
         }
+        cx.notify();
     }
 
     fn select_theme_from_palette(&mut self, theme_id: &str, cx: &mut Context<Self>) {
@@ -493,7 +588,13 @@ impl TerminalView {
         window: &mut Window,
         cx: &mut Context<Self>,
     ) {
-        let keep_open = action == CommandAction::SwitchTheme;
+        let keep_open = matches!(
+            action,
+            CommandAction::SwitchTheme
+                | CommandAction::RunCliCommand
+                | CommandAction::InsertCommandOutput
+                | CommandAction::SwitchProfile
+        );
         if !keep_open {
             self.command_palette_open = false;
             self.command_palette_mode = CommandPaletteMode::Commands;
@@ -533,9 +634,45 @@ impl TerminalView {
             | CommandAction::SearchPrevious
             | CommandAction::ToggleSearchCaseSensitive
             | CommandAction::ToggleSearchRegex
+            | CommandAction::ToggleSearchWholeWord
             | CommandAction::OpenSettings
             | CommandAction::MinimizeWindow
-            | CommandAction::InstallCli => {}
+            | CommandAction::InstallCli
+            | CommandAction::CopyWorkingDir
+            | CommandAction::RevealWorkingDir
+            | CommandAction::RunCliCommand
+            | CommandAction::ScrollPageUp
+            | CommandAction::ScrollPageDown
+            | CommandAction::ScrollHalfPageUp
+            | CommandAction::ScrollHalfPageDown
+            | CommandAction::NextDiagnostic
+            | CommandAction::PreviousDiagnostic
+            | CommandAction::IncreaseOpacity
+            | CommandAction::DecreaseOpacity
+            | CommandAction::ClearTabTitle
+            | CommandAction::ToggleFullscreen
+            | CommandAction::ToggleSearchOutputOnly
+            | CommandAction::InsertCommandOutput
+            | CommandAction::SaveScrollback
+            | CommandAction::CopyScreen
+            | CommandAction::CopyScrollback
+            | CommandAction::SwitchProfile
+            | CommandAction::ClearAllHighlights
+            | CommandAction::ResumeOutput
+            | CommandAction::CopyMatchAndNext
+            | CommandAction::CopySearchMatches
+            | CommandAction::SearchFirstMatch
+            | CommandAction::SearchLastMatch
+            | CommandAction::SearchNextPage
+            | CommandAction::SearchPreviousPage
+            | CommandAction::ReplaceCurrent
+            | CommandAction::ReplaceAll
+            | CommandAction::CycleCursorStyle
+            | CommandAction::ToggleShellIntegration
+            | CommandAction::ToggleWhitespace
+            | CommandAction::DuplicateTabRerun
+            | CommandAction::ReloadThemes
+            | CommandAction::FoldLongLines => {}
         }
     }
 
@@ -593,7 +730,10 @@ impl TerminalView {
                 CommandPaletteItemKind::Command(action) => {
                     self.command_palette_shortcut(action, window)
                 }
-                CommandPaletteItemKind::Theme(_) => None,
+                CommandPaletteItemKind::Theme(_)
+                | CommandPaletteItemKind::CliCommand(_)
+                | CommandPaletteItemKind::Profile(_)
+                | CommandPaletteItemKind::Custom(_) => None,
             };
             let item_kind = item.kind.clone();
 
@@ -669,10 +809,16 @@ impl TerminalView {
         let mode_title = match self.command_palette_mode {
             CommandPaletteMode::Commands => "Commands".to_string(),
             CommandPaletteMode::Themes => format!("Theme: {}", self.theme_id),
+            CommandPaletteMode::CliCommands => "CLI Commands".to_string(),
+            CommandPaletteMode::InsertCommandOutput => "Insert Command Output".to_string(),
+            CommandPaletteMode::Profiles => "Tab Profile".to_string(),
         };
         let footer_hint = match self.command_palette_mode {
             CommandPaletteMode::Commands => "Enter: Run  Esc: Close  Up/Down: Navigate",
             CommandPaletteMode::Themes => "Enter: Apply Theme  Esc: Back  Up/Down: Navigate",
+            CommandPaletteMode::CliCommands => "Enter: Show Output  Esc: Back  Up/Down: Navigate",
+            CommandPaletteMode::InsertCommandOutput => "Enter: Run and Insert  Esc: Back",
+            CommandPaletteMode::Profiles => "Enter: Apply to Tab  Esc: Back  Up/Down: Navigate",
         };
         let overlay_style = self.overlay_style();
         let overlay_bg = overlay_style.dim_background(COMMAND_PALETTE_DIM_ALPHA);
@@ -697,6 +843,13 @@ impl TerminalView {
         let scrollbar_thumb =
             self.scrollbar_color(overlay_style, COMMAND_PALETTE_SCROLLBAR_THUMB_ALPHA);
 
+        let empty_text = match self.command_palette_mode {
+            CommandPaletteMode::InsertCommandOutput => {
+                "Type a shell command; its output is pasted at the prompt"
+            }
+            _ => "No matching items",
+        };
+
         let list = if items.is_empty() {
             div()
                 .w_full()
@@ -706,7 +859,7 @@ impl TerminalView {
                         .py(px(8.0))
                         .text_size(px(12.0))
                         .text_color(muted_text)
-                        .child("No matching items"),
+                        .child(empty_text),
                 )
                 .into_any_element()
         } else {
@@ -873,7 +1026,10 @@ mod tests {
             .into_iter()
             .filter_map(|item| match item.kind {
                 CommandPaletteItemKind::Command(action) => Some(action),
-                CommandPaletteItemKind::Theme(_) => None,
+                CommandPaletteItemKind::Theme(_)
+                | CommandPaletteItemKind::CliCommand(_)
+                | CommandPaletteItemKind::Profile(_)
+                | CommandPaletteItemKind::Custom(_) => None,
             })
             .collect();
 
@@ -900,7 +1056,10 @@ mod tests {
             .into_iter()
             .filter_map(|item| match item.kind {
                 CommandPaletteItemKind::Command(action) => Some(action),
-                CommandPaletteItemKind::Theme(_) => None,
+                CommandPaletteItemKind::Theme(_)
+                | CommandPaletteItemKind::CliCommand(_)
+                | CommandPaletteItemKind::Profile(_)
+                | CommandPaletteItemKind::Custom(_) => None,
             })
             .collect();
 
@@ -986,5 +1145,17 @@ mod tests {
             TerminalView::command_palette_escape_action(CommandPaletteMode::Themes),
             CommandPaletteEscapeAction::BackToCommands
         );
+        assert_eq!(
+            TerminalView::command_palette_escape_action(CommandPaletteMode::CliCommands),
+            CommandPaletteEscapeAction::BackToCommands
+        );
+        assert_eq!(
+            TerminalView::command_palette_escape_action(CommandPaletteMode::InsertCommandOutput),
+            CommandPaletteEscapeAction::BackToCommands
+        );
+        assert_eq!(
+            TerminalView::command_palette_escape_action(CommandPaletteMode::Profiles),
+            CommandPaletteEscapeAction::BackToCommands
+        );
     }
 }
@@ -0,0 +1,158 @@
+use super::search::extract_line_text;
+use super::*;
+use alacritty_terminal::grid::Dimensions;
+use termy_search::{
+    SearchConfig, SearchEngine, SearchMatch, SearchMode, SearchResults, SearchScope,
+};
+
+impl TerminalView {
+    pub(super) fn diagnostic_engine(patterns: &[String]) -> SearchEngine {
+        let config = SearchConfig {
+            case_sensitive: true,
+            mode: SearchMode::Regex,
+            trim_query: false,
+            whole_word: false,
+            smart_case: false,
+            scope: SearchScope::EntireBuffer,
+        };
+
+        // Drop invalid patterns one by one so a typo doesn't disable the whole set.
+        let mut valid = Vec::with_capacity(patterns.len());
+        for pattern in patterns {
+            let mut probe = SearchEngine::new(config.clone());
+            match probe.set_pattern(pattern) {
+                Ok(()) => valid.push(format!("(?:{})", pattern)),
+                Err(error) => log::warn!("Ignoring diagnostic_pattern {:?}: {}", pattern, error),
+            }
+        }
+
+        let mut engine = SearchEngine::new(config);
+        let _ = engine.set_pattern(&valid.join("|"));
+        engine
+    }
+
+    pub(super) fn profile_diagnostic_engines(
+        profiles: &[ProfileConfig],
+    ) -> Vec<(String, SearchEngine)> {
+        profiles
+            .iter()
+            .filter(|profile| !profile.diagnostic_patterns.is_empty())
+            .map(|profile| {
+                (
+                    profile.name.clone(),
+                    Self::diagnostic_engine(&profile.diagnostic_patterns),
+                )
+            })
+            .collect()
+    }
+
+    pub(super) fn clear_diagnostics(&mut self) {
+        self.diagnostics_visible = false;
+        self.diagnostic_results = SearchResults::new();
+    }
+
+    pub(super) fn jump_to_diagnostic(&mut self, forward: bool, cx: &mut Context<Self>) {
+        if !self.diagnostics_visible {
+            let count = self.scan_diagnostics();
+            if count == 0 {
+                termy_toast::info("No errors or warnings in output");
+                cx.notify();
+                return;
+            }
+
+            self.diagnostics_visible = true;
+            self.jump_to_diagnostic_near_viewport(forward);
+            termy_toast::info(format!(
+                "Found {} diagnostic {}",
+                count,
+                if count == 1 { "line" } else { "lines" }
+            ));
+        } else if forward {
+            self.diagnostic_results.next();
+        } else {
+            self.diagnostic_results.previous();
+        }
+
+        if let Some((line, column)) = self
+            .diagnostic_results
+            .current()
+            .map(|current| (current.line, current.start_col))
+        {
+            self.scroll_term_line_into_view(line, column, false, cx);
+        }
+        cx.notify();
+    }
+
+    /// Rescans the active tab's scrollback with its profile's patterns, keeping the
+    /// first match of each line.
+    fn scan_diagnostics(&mut self) -> usize {
+        let tab = &self.tabs[self.active_tab];
+        let terminal = &tab.terminal;
+        let (display_offset, history_size) = terminal.scroll_state();
+        let rows = terminal.size().rows as i32;
+        let start_line = -(history_size as i32);
+        let end_line = rows - 1;
+        let engine = tab
+            .profile
+            .as_deref()
+            .and_then(|name| {
+                self.profile_diagnostic_engines
+                    .iter()
+                    .find(|(profile, _)| profile == name)
+            })
+            .map_or(&self.diagnostic_engine, |(_, engine)| engine);
+
+        let results = terminal.with_term(|term| {
+            let grid = term.grid();
+            engine.search(start_line, end_line, |line_idx| {
+                extract_line_text(grid, line_idx, display_offset)
+            })
+        });
+
+        let mut matches: Vec<SearchMatch> = Vec::with_capacity(results.count());
+        for found in results.matches() {
+            if matches.last().is_none_or(|last| last.line != found.line) {
+                matches.push(found.clone());
+            }
+        }
+
+        self.diagnostic_results = SearchResults::from_matches(matches);
+        self.diagnostic_results.count()
+    }
+
+    /// Picks the first diagnostic starting from what's on screen, so the first jump
+    /// doesn't fling the viewport to the other end of the scrollback.
+    fn jump_to_diagnostic_near_viewport(&mut self, forward: bool) {
+        let (display_offset, _) = self.active_terminal().scroll_state();
+        let rows = self.active_terminal().size().rows as i32;
+        let viewport_top = -(display_offset as i32);
+        let viewport_bottom = viewport_top + rows - 1;
+
+        if forward {
+            self.diagnostic_results.jump_to_nearest(viewport_top);
+        } else {
+            let index = self
+                .diagnostic_results
+                .matches()
+                .iter()
+                .rposition(|found| found.line <= viewport_bottom)
+                .unwrap_or(self.diagnostic_results.count().saturating_sub(1));
+            self.diagnostic_results.jump_to(index);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diagnostic_engine_skips_invalid_patterns() {
+        let patterns = vec!["error".to_string(), "[".to_string(), "FAILED".to_string()];
+        let engine = TerminalView::diagnostic_engine(&patterns);
+
+        assert_eq!(engine.search_line(0, "error: mismatched types").len(), 1);
+        assert_eq!(engine.search_line(0, "test foo ... FAILED").len(), 1);
+        assert!(engine.search_line(0, "Failed quietly").is_empty());
+    }
+}
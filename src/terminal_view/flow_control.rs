@@ -0,0 +1,83 @@
+use super::*;
+
+const XOFF: u8 = 0x13;
+const XON: u8 = 0x11;
+const OUTPUT_PAUSED_LABEL: &str = "Output paused \u{2014} Ctrl-Q to resume";
+const OUTPUT_PAUSED_ANY_KEY_LABEL: &str = "Output paused \u{2014} press any key to resume";
+
+impl TerminalView {
+    /// Mirrors what the PTY line discipline does with Ctrl-S/Ctrl-Q written to the
+    /// active tab, so the view can explain why output stopped.
+    pub(super) fn track_flow_control_input(&mut self, input: &[u8]) {
+        let was_paused = self.tabs[self.active_tab].output_paused;
+        let last = input.iter().rposition(|&byte| byte == XOFF || byte == XON);
+        if last.is_none() && !was_paused {
+            return;
+        }
+
+        let terminal = self.active_terminal();
+        let resumes_on_any_input = terminal.flow_control_resumes_on_any_input();
+        let paused = match last {
+            // With IXANY whatever follows the Ctrl-S restarts output right away.
+            Some(last) if input[last] == XOFF => {
+                terminal.flow_control_enabled() && !(resumes_on_any_input && last + 1 < input.len())
+            }
+            Some(_) => false,
+            None => !resumes_on_any_input || input.is_empty(),
+        };
+        self.tabs[self.active_tab].output_paused = paused;
+    }
+
+    pub(super) fn resume_output(&mut self, cx: &mut Context<Self>) {
+        self.write_terminal_input(&[XON], cx);
+        cx.notify();
+    }
+
+    pub(super) fn render_output_paused_indicator(
+        &self,
+        cx: &mut Context<Self>,
+    ) -> Option<AnyElement> {
+        if !self.tabs[self.active_tab].output_paused {
+            return None;
+        }
+
+        let overlay_style = self.overlay_style();
+        Some(
+            div()
+                .absolute()
+                .bottom(px(12.0))
+                .left_0()
+                .right_0()
+                .flex()
+                .justify_center()
+                .child(
+                    div()
+                        .id("output-paused-indicator")
+                        .px(px(10.0))
+                        .py(px(4.0))
+                        .rounded_md()
+                        .bg(overlay_style.panel_background(SEARCH_BAR_BG_ALPHA))
+                        .border_1()
+                        .border_color(overlay_style.panel_cursor(OVERLAY_PANEL_BORDER_ALPHA))
+                        .text_size(px(11.0))
+                        .text_color(overlay_style.panel_foreground(OVERLAY_MUTED_TEXT_ALPHA))
+                        .cursor_pointer()
+                        .on_mouse_down(
+                            MouseButton::Left,
+                            cx.listener(|this, _event: &MouseDownEvent, _window, cx| {
+                                cx.stop_propagation();
+                                this.resume_output(cx);
+                            }),
+                        )
+                        .child(
+                            if self.active_terminal().flow_control_resumes_on_any_input() {
+                                OUTPUT_PAUSED_ANY_KEY_LABEL
+                            } else {
+                                OUTPUT_PAUSED_LABEL
+                            },
+                        ),
+                )
+                .into_any(),
+        )
+    }
+}
@@ -20,6 +20,7 @@ enum InlineInputTarget {
     CommandPalette,
     RenameTab,
     Search,
+    Replace,
 }
 
 #[derive(Clone, Debug)]
@@ -767,7 +768,9 @@ impl IntoElement for InlineInputElement {
                     });
                     let cursor_width = match cursor_style {
                         AppCursorStyle::Line => px(1.0),
-                        AppCursorStyle::Block => {
+                        AppCursorStyle::Block
+                        | AppCursorStyle::Underline
+                        | AppCursorStyle::HollowBlock => {
                             let fallback_width = (font_size_value * 0.62).round().max(1.0);
                             let width = text
                                 .get(cursor_utf8..)
@@ -786,20 +789,34 @@ impl IntoElement for InlineInputElement {
                         }
                     };
                     let cursor_color = match cursor_style {
-                        AppCursorStyle::Line => text_color,
+                        AppCursorStyle::Line
+                        | AppCursorStyle::Underline
+                        | AppCursorStyle::HollowBlock => text_color,
                         AppCursorStyle::Block => selection_color,
                     };
+                    let (cursor_top, cursor_height) = match cursor_style {
+                        AppCursorStyle::Underline => {
+                            let thickness = px(1.0);
+                            (line_bounds.bottom() - thickness, thickness)
+                        }
+                        AppCursorStyle::Line
+                        | AppCursorStyle::Block
+                        | AppCursorStyle::HollowBlock => {
+                            (line_bounds.top(), line_bounds.size.height)
+                        }
+                    };
 
-                    Some(fill(
-                        Bounds::new(
-                            point(
-                                line_bounds.left() + line_offset_x + cursor_x,
-                                line_bounds.top(),
-                            ),
-                            size(cursor_width, line_bounds.size.height),
-                        ),
-                        cursor_color,
-                    ))
+                    let cursor_bounds = Bounds::new(
+                        point(line_bounds.left() + line_offset_x + cursor_x, cursor_top),
+                        size(cursor_width, cursor_height),
+                    );
+                    Some(if cursor_style == AppCursorStyle::HollowBlock {
+                        fill(cursor_bounds, Hsla::transparent_black())
+                            .border_widths(px(1.0))
+                            .border_color(cursor_color)
+                    } else {
+                        fill(cursor_bounds, cursor_color)
+                    })
                 } else {
                     None
                 };
@@ -864,6 +881,8 @@ impl TerminalView {
     fn active_inline_input_target(&self) -> Option<InlineInputTarget> {
         if self.command_palette_open {
             Some(InlineInputTarget::CommandPalette)
+        } else if self.search_open && self.search_replace_focused {
+            Some(InlineInputTarget::Replace)
         } else if self.search_open {
             Some(InlineInputTarget::Search)
         } else if self.renaming_tab.is_some() {
@@ -922,6 +941,7 @@ impl TerminalView {
         match self.active_inline_input_target()? {
             InlineInputTarget::CommandPalette => Some(&self.command_palette_input),
             InlineInputTarget::Search => Some(&self.search_input),
+            InlineInputTarget::Replace => Some(&self.replace_input),
             InlineInputTarget::RenameTab => Some(&self.rename_input),
         }
     }
@@ -930,6 +950,7 @@ impl TerminalView {
         match self.active_inline_input_target()? {
             InlineInputTarget::CommandPalette => Some(&mut self.command_palette_input),
             InlineInputTarget::Search => Some(&mut self.search_input),
+            InlineInputTarget::Replace => Some(&mut self.replace_input),
             InlineInputTarget::RenameTab => Some(&mut self.rename_input),
         }
     }
@@ -974,6 +995,12 @@ impl TerminalView {
                 mutate(&mut self.search_input);
                 self.handle_search_input_changed(cx);
             }
+            Some(InlineInputTarget::Replace) => {
+                mutate(&mut self.replace_input);
+                self.search_state
+                    .set_replacement(self.replace_input.text().to_string());
+                cx.notify();
+            }
             Some(InlineInputTarget::RenameTab) => {
                 mutate(&mut self.rename_input);
                 self.enforce_tab_rename_limit();
@@ -0,0 +1,180 @@
+use super::*;
+use std::{
+    io::Read,
+    process::{Child, Stdio},
+    sync::mpsc,
+    thread,
+};
+
+const INSERT_OUTPUT_TIMEOUT: Duration = Duration::from_secs(5);
+const INSERT_OUTPUT_MAX_BYTES: usize = 64 * 1024;
+const INSERT_OUTPUT_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+impl TerminalView {
+    /// Runs `command` in a throwaway shell and pastes its stdout into the active tab.
+    pub(super) fn insert_command_output(&mut self, command: String, cx: &mut Context<Self>) {
+        let working_dir = self.active_tab_working_directory();
+        let target_tab_id = self.tabs[self.active_tab].id;
+
+        cx.spawn(async move |this, cx: &mut AsyncApp| {
+            let result = cx
+                .background_spawn(async move {
+                    run_command_for_output(&command, working_dir.as_deref())
+                })
+                .await;
+
+            let _ = cx.update(|cx| {
+                this.update(cx, |view, cx| {
+                    match result {
+                        Ok(output) if output.is_empty() => {
+                            termy_toast::info("Command produced no output");
+                        }
+                        Ok(output) => {
+                            if view.tab_index_by_id(target_tab_id) != Some(view.active_tab) {
+                                termy_toast::warning("Tab changed; command output discarded");
+                                return;
+                            }
+                            view.write_terminal_paste_input(output.as_bytes(), cx);
+                            view.clear_selection();
+                        }
+                        Err(error) => termy_toast::error(error),
+                    }
+                    cx.notify();
+                })
+            });
+        })
+        .detach();
+    }
+}
+
+fn output_shell_command(command: &str) -> Command {
+    #[cfg(target_os = "windows")]
+    {
+        let mut process = Command::new("cmd");
+        process.args(["/C", command]);
+        process
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        use std::os::unix::process::CommandExt;
+
+        let mut process = Command::new("/bin/sh");
+        process.args(["-c", command]);
+        // Its own process group, so giving up on it also stops whatever it spawned.
+        process.process_group(0);
+        process
+    }
+}
+
+fn kill_command(child: &mut Child) {
+    #[cfg(unix)]
+    {
+        // SAFETY: `kill` only sends a signal. The group id is the child's pid because
+        // `output_shell_command` put it in a new process group.
+        unsafe {
+            libc::kill(-(child.id() as libc::pid_t), libc::SIGKILL);
+        }
+    }
+
+    #[cfg(not(unix))]
+    {
+        let _ = child.kill();
+    }
+
+    let _ = child.wait();
+}
+
+fn run_command_for_output(command: &str, working_dir: Option<&Path>) -> Result<String, String> {
+    let mut process = output_shell_command(command);
+    process
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null());
+    if let Some(dir) = working_dir {
+        process.current_dir(dir);
+    }
+
+    let mut child = process
+        .spawn()
+        .map_err(|error| format!("Failed to run command: {}", error))?;
+    let stdout = child
+        .stdout
+        .take()
+        .ok_or_else(|| "Failed to capture command output".to_string())?;
+
+    // Read one byte past the limit so oversized output can be told apart from output
+    // that fits exactly.
+    let (output_tx, output_rx) = mpsc::channel();
+    thread::spawn(move || {
+        let mut buffer = Vec::new();
+        let _ = stdout
+            .take(INSERT_OUTPUT_MAX_BYTES as u64 + 1)
+            .read_to_end(&mut buffer);
+        let _ = output_tx.send(buffer);
+    });
+
+    let deadline = Instant::now() + INSERT_OUTPUT_TIMEOUT;
+    let output = output_rx.recv_timeout(INSERT_OUTPUT_TIMEOUT);
+    let output = match output {
+        Ok(output) if output.len() > INSERT_OUTPUT_MAX_BYTES => {
+            kill_command(&mut child);
+            return Err(format!(
+                "Command output exceeded {} KiB",
+                INSERT_OUTPUT_MAX_BYTES / 1024
+            ));
+        }
+        Ok(output) => output,
+        Err(_) => {
+            kill_command(&mut child);
+            return Err(format!(
+                "Command timed out after {}s",
+                INSERT_OUTPUT_TIMEOUT.as_secs()
+            ));
+        }
+    };
+
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if Instant::now() >= deadline => {
+                kill_command(&mut child);
+                return Err(format!(
+                    "Command timed out after {}s",
+                    INSERT_OUTPUT_TIMEOUT.as_secs()
+                ));
+            }
+            Ok(None) => thread::sleep(INSERT_OUTPUT_POLL_INTERVAL),
+            Err(error) => return Err(format!("Failed to wait for command: {}", error)),
+        }
+    };
+
+    if !status.success() {
+        return Err(match status.code() {
+            Some(code) => format!("Command exited with status {}", code),
+            None => "Command was terminated".to_string(),
+        });
+    }
+
+    Ok(command_output_for_insert(&output))
+}
+
+// Trailing newlines are dropped so inserted output never submits the command line.
+fn command_output_for_insert(output: &[u8]) -> String {
+    String::from_utf8_lossy(output)
+        .trim_end_matches(['\r', '\n'])
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn command_output_drops_trailing_newlines_only() {
+        assert_eq!(command_output_for_insert(b"2024-01-01\n"), "2024-01-01");
+        assert_eq!(command_output_for_insert(b"a\r\nb\r\n\r\n"), "a\r\nb");
+        assert_eq!(command_output_for_insert(b"  padded  "), "  padded  ");
+        assert_eq!(command_output_for_insert(b""), "");
+    }
+}
@@ -1,6 +1,7 @@
 use super::scrollbar as terminal_scrollbar;
 use super::*;
 use crate::ui::scrollbar as ui_scrollbar;
+use alacritty_terminal::index::{Column, Line};
 use gpui::{AppContext, PromptLevel};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -13,6 +14,9 @@ impl TerminalView {
     fn command_palette_mode_for_action(action: CommandAction) -> Option<CommandPaletteMode> {
         match action {
             CommandAction::SwitchTheme => Some(CommandPaletteMode::Themes),
+            CommandAction::RunCliCommand => Some(CommandPaletteMode::CliCommands),
+            CommandAction::InsertCommandOutput => Some(CommandPaletteMode::InsertCommandOutput),
+            CommandAction::SwitchProfile => Some(CommandPaletteMode::Profiles),
             _ => None,
         }
     }
@@ -21,26 +25,33 @@ impl TerminalView {
         matches!((self.selection_anchor, self.selection_head), (Some(anchor), Some(head)) if self.selection_moved || anchor != head)
     }
 
-    pub(super) fn selection_range(&self) -> Option<(CellPos, CellPos)> {
+    pub(super) fn selection_range(&self) -> Option<(SelectionPos, SelectionPos)> {
         if !self.has_selection() {
             return None;
         }
 
         let (anchor, head) = (self.selection_anchor?, self.selection_head?);
-        if (head.row, head.col) < (anchor.row, anchor.col) {
+        if (head.line, head.col) < (anchor.line, anchor.col) {
             Some((head, anchor))
         } else {
             Some((anchor, head))
         }
     }
 
-    pub(super) fn cell_is_selected(&self, col: usize, row: usize) -> bool {
+    pub(super) fn cell_is_selected(&self, col: usize, term_line: i32) -> bool {
         let Some((start, end)) = self.selection_range() else {
             return false;
         };
 
-        let here = (row, col);
-        here >= (start.row, start.col) && here <= (end.row, end.col)
+        let here = (term_line, col);
+        here >= (start.line, start.col) && here <= (end.line, end.col)
+    }
+
+    fn selection_pos_for_cell(&self, cell: CellPos) -> SelectionPos {
+        SelectionPos {
+            col: cell.col,
+            line: self.term_line_at_row(cell.row),
+        }
     }
 
     pub(super) fn viewport_row_from_term_line(
@@ -54,7 +65,18 @@ impl TerminalView {
         self.terminal_scroll_accumulator_y = 0.0;
         self.input_scroll_suppress_until =
             Some(Instant::now() + Duration::from_millis(INPUT_SCROLL_SUPPRESS_MS));
-        self.scroll_to_bottom(cx);
+        if self.scroll_to_bottom_on_input {
+            self.scroll_to_bottom(cx);
+        }
+
+        let tab = &mut self.tabs[self.active_tab];
+        if tab.prompt_input == PromptInput::AwaitingInput {
+            let (column, row) = tab.terminal.cursor_position();
+            tab.prompt_input = PromptInput::StartsAt {
+                line: tab.terminal.screen_top_line() + row as u64,
+                column,
+            };
+        }
     }
 
     fn consume_suppressed_scroll_event(
@@ -92,12 +114,13 @@ impl TerminalView {
         }
     }
 
-    fn write_terminal_input(&mut self, input: &[u8], cx: &mut Context<Self>) {
+    pub(super) fn write_terminal_input(&mut self, input: &[u8], cx: &mut Context<Self>) {
         if input.is_empty() {
             return;
         }
 
         self.prepare_terminal_input_write(cx);
+        self.track_flow_control_input(input);
         self.active_terminal().write(input);
     }
 
@@ -136,7 +159,51 @@ impl TerminalView {
         sanitized
     }
 
-    fn write_terminal_paste_input(&mut self, input: &[u8], cx: &mut Context<Self>) {
+    /// Strips control characters that a paste should never deliver to the PTY: C0 controls
+    /// other than tab, line feed and carriage return (this includes NUL and ESC), DEL, and
+    /// UTF-8 encoded C1 controls (U+0080..=U+009F). Returns `None` when nothing was removed.
+    fn filter_paste_control_characters(input: &[u8]) -> Option<Vec<u8>> {
+        let mut filtered: Option<Vec<u8>> = None;
+        let mut index = 0;
+        while index < input.len() {
+            let byte = input[index];
+            let control_len = match byte {
+                b'\t' | b'\n' | b'\r' => 0,
+                0x00..=0x1f | 0x7f => 1,
+                0xc2 if input
+                    .get(index + 1)
+                    .is_some_and(|next| (0x80..=0x9f).contains(next)) =>
+                {
+                    2
+                }
+                _ => 0,
+            };
+
+            if control_len > 0 {
+                if filtered.is_none() {
+                    let mut buffer = Vec::with_capacity(input.len());
+                    buffer.extend_from_slice(&input[..index]);
+                    filtered = Some(buffer);
+                }
+                index += control_len;
+                continue;
+            }
+
+            if let Some(buffer) = filtered.as_mut() {
+                buffer.push(byte);
+            }
+            index += 1;
+        }
+
+        filtered
+    }
+
+    pub(super) fn write_terminal_paste_input(&mut self, input: &[u8], cx: &mut Context<Self>) {
+        let filtered = self
+            .sanitize_paste
+            .then(|| Self::filter_paste_control_characters(input))
+            .flatten();
+        let input = filtered.as_deref().unwrap_or(input);
         if input.is_empty() {
             return;
         }
@@ -174,10 +241,26 @@ impl TerminalView {
         }
     }
 
+    /// Runs what a toast button stands for once `ToastManager::activate_action` has
+    /// queued the click.
+    #[cfg_attr(not(target_os = "macos"), allow(unused_variables))]
+    pub(super) fn handle_toast_action(
+        &mut self,
+        event: &termy_toast::ToastActionEvent,
+        cx: &mut Context<Self>,
+    ) {
+        match event.action_id.as_str() {
+            #[cfg(target_os = "macos")]
+            super::update_toasts::INSTALL_UPDATE_TOAST_ACTION => self.install_update_from_toast(cx),
+            action_id => log::debug!("Ignoring unknown toast action {:?}", action_id),
+        }
+    }
+
     fn import_colors_action(&mut self, cx: &mut Context<Self>) {
         cx.spawn(async move |this, cx: &mut AsyncApp| {
             let file = rfd::AsyncFileDialog::new()
-                .add_filter("JSON", &["json"])
+                .add_filter("Color Schemes", &["json", "itermcolors", "Xresources"])
+                .add_filter("All Files", &["*"])
                 .set_title("Import Colors")
                 .pick_file()
                 .await;
@@ -187,7 +270,7 @@ impl TerminalView {
             };
 
             let path = file.path().to_path_buf();
-            let result = config::import_colors_from_json(&path);
+            let result = config::import_colors_from_file(&path);
 
             let _ = cx.update(|cx| {
                 this.update(cx, |view, cx| {
@@ -197,7 +280,7 @@ impl TerminalView {
                             view.reload_config(cx);
                         }
                         Err(err) => {
-                            termy_toast::error(err);
+                            termy_toast::error_high(err);
                         }
                     }
                     cx.notify();
@@ -207,16 +290,108 @@ impl TerminalView {
         .detach();
     }
 
+    fn copy_terminal_text(&mut self, include_scrollback: bool, cx: &mut Context<Self>) {
+        let terminal = self.active_terminal();
+        let text = if include_scrollback {
+            terminal.scrollback_text()
+        } else {
+            terminal.viewport_text()
+        };
+
+        if text.is_empty() {
+            termy_toast::info("Nothing to copy");
+            return;
+        }
+
+        cx.write_to_clipboard(ClipboardItem::new_string(text));
+        termy_toast::success(if include_scrollback {
+            "Copied scrollback"
+        } else {
+            "Copied screen"
+        });
+        cx.notify();
+    }
+
+    fn save_scrollback_action(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let prompt = window.prompt(
+            PromptLevel::Info,
+            "Save Scrollback",
+            Some("Choose how to save the active tab's scrollback."),
+            &["Plain Text", "ANSI (keep colors)", "Cancel"],
+            cx,
+        );
+        let target_tab_id = self.tabs[self.active_tab].id;
+
+        cx.spawn(async move |this, cx: &mut AsyncApp| {
+            let (format, extension) = match prompt.await {
+                Ok(0) => (ScrollbackFormat::PlainText, "txt"),
+                Ok(1) => (ScrollbackFormat::Ansi, "ansi"),
+                _ => return,
+            };
+
+            let file = rfd::AsyncFileDialog::new()
+                .set_title("Save Scrollback")
+                .set_file_name(format!("scrollback.{}", extension))
+                .save_file()
+                .await;
+
+            let Some(file) = file else {
+                return;
+            };
+
+            let path = file.path().to_path_buf();
+            // Snapshot under the terminal lock, then leave the disk write to the background.
+            let contents = cx.update(|cx| {
+                this.update(cx, |view, _| {
+                    let index = view.tab_index_by_id(target_tab_id)?;
+                    let mut contents = Vec::new();
+                    view.tabs[index]
+                        .terminal
+                        .write_scrollback(&mut contents, format)
+                        .ok()?;
+                    Some(contents)
+                })
+            });
+            let Ok(Ok(Some(contents))) = contents else {
+                termy_toast::error("Tab was closed before scrollback could be saved");
+                return;
+            };
+
+            let result = cx
+                .background_spawn({
+                    let path = path.clone();
+                    async move { fs::write(path, contents) }
+                })
+                .await;
+            match result {
+                Ok(()) => termy_toast::success(format!("Saved scrollback to {}", path.display())),
+                Err(error) => termy_toast::error(format!("Failed to save scrollback: {}", error)),
+            }
+        })
+        .detach();
+    }
+
     fn native_sdk_example_action(&mut self, cx: &mut Context<Self>) {
         cx.spawn(async move |this, cx: &mut AsyncApp| {
-            termy_native_sdk::show_alert(
+            let (dismissed_tx, dismissed_rx) = bounded(1);
+            termy_native_sdk::show_alert_async(
                 "Update Available",
                 "A new Termy update is available and ready to install.",
+                move || {
+                    let _ = dismissed_tx.send(());
+                },
             );
-            let confirmed = termy_native_sdk::confirm(
+            let _ = dismissed_rx.recv_async().await;
+
+            let (confirmed_tx, confirmed_rx) = bounded(1);
+            termy_native_sdk::confirm_async(
                 "Install Update",
                 "Would you like to install the latest update now?",
+                move |confirmed| {
+                    let _ = confirmed_tx.send(confirmed);
+                },
             );
+            let confirmed = confirmed_rx.recv_async().await.unwrap_or(false);
 
             let _ = cx.update(|cx| {
                 this.update(cx, |_view, cx| {
@@ -278,49 +453,45 @@ impl TerminalView {
 
     pub(super) fn selected_text(&self) -> Option<String> {
         let (start, end) = self.selection_range()?;
-        let size = self.active_terminal().size();
-        let cols = size.cols as usize;
-        let rows = size.rows as usize;
-        if cols == 0 || rows == 0 {
-            return None;
-        }
+        let trim_trailing_whitespace = self.trim_trailing_whitespace_on_copy;
 
-        let mut grid = vec![vec![' '; cols]; rows];
-        self.active_terminal().with_term(|term| {
-            let content = term.renderable_content();
-            for cell in content.display_iter {
-                let Some(row) =
-                    Self::viewport_row_from_term_line(cell.point.line.0, content.display_offset)
-                else {
-                    continue;
-                };
-                let col = cell.point.column.0;
-                if row >= rows || col >= cols {
-                    continue;
-                }
+        let lines = self.active_terminal().with_term(|term| {
+            let grid = term.grid();
+            let cols = grid.columns();
+            if cols == 0 {
+                return Vec::new();
+            }
 
-                let c = cell.cell.c;
-                if c != '\0' {
-                    grid[row][col] = if c.is_control() { ' ' } else { c };
+            // Output may have pushed selected lines out of the scrollback since selecting.
+            let topmost = -(grid.history_size() as i32);
+            let bottommost = grid.screen_lines() as i32 - 1;
+            let mut lines = Vec::new();
+            for line_index in start.line.max(topmost)..=end.line.min(bottommost) {
+                let row = &grid[Line(line_index)];
+                let col_start = if line_index == start.line {
+                    start.col
+                } else {
+                    0
+                };
+                let col_end = if line_index == end.line {
+                    end.col.min(cols - 1)
+                } else {
+                    cols - 1
+                };
+                let mut line: String = (col_start..=col_end)
+                    .map(|col| {
+                        let c = row[Column(col)].c;
+                        if c == '\0' || c.is_control() { ' ' } else { c }
+                    })
+                    .collect();
+                if trim_trailing_whitespace {
+                    line.truncate(line.trim_end().len());
                 }
+                lines.push(line);
             }
+            lines
         });
 
-        let mut lines = Vec::new();
-        for row in start.row..=end.row {
-            let col_start = if row == start.row { start.col } else { 0 };
-            let col_end = if row == end.row {
-                end.col
-            } else {
-                cols.saturating_sub(1)
-            };
-            let mut line: String = grid[row][col_start..=col_end].iter().collect();
-            while line.ends_with(' ') {
-                line.pop();
-            }
-            lines.push(line);
-        }
-
         if lines.is_empty() {
             None
         } else {
@@ -329,6 +500,15 @@ impl TerminalView {
     }
 
     pub(super) fn row_text(&self, row: usize) -> Option<Vec<char>> {
+        self.row_text_and_hyperlinks(row).map(|(line, _)| line)
+    }
+
+    /// The row's text along with the OSC 8 hyperlink, if any, of each cell. Cells
+    /// hidden past a long line cut read as blank.
+    fn row_text_and_hyperlinks(
+        &self,
+        row: usize,
+    ) -> Option<(Vec<char>, Vec<Option<CellHyperlink>>)> {
         let size = self.active_terminal().size();
         let cols = size.cols as usize;
         let rows = size.rows as usize;
@@ -336,50 +516,179 @@ impl TerminalView {
             return None;
         }
 
+        let term_line = self.term_line_at_row(row);
+        let visible_cols = self
+            .long_line_cut_at_row(row)
+            .map_or(cols, |cut| cut.min(cols));
         let mut line = vec![' '; cols];
+        let mut hyperlinks = vec![None; cols];
         self.active_terminal().with_term(|term| {
-            let content = term.renderable_content();
-            for cell in content.display_iter {
-                let Some(cell_row) =
-                    Self::viewport_row_from_term_line(cell.point.line.0, content.display_offset)
-                else {
-                    continue;
-                };
-                if cell_row != row {
-                    continue;
-                }
-
-                let col = cell.point.column.0;
-                if col >= cols {
-                    continue;
-                }
+            let grid = term.grid();
+            if term_line < grid.topmost_line().0 || term_line > grid.bottommost_line().0 {
+                return;
+            }
+            let grid_row = &grid[Line(term_line)];
+            for col in 0..visible_cols.min(grid.columns()) {
+                let cell = &grid_row[Column(col)];
 
-                if cell.cell.flags.intersects(
+                // Spacers still belong to the link so a wide glyph's run stays unbroken.
+                hyperlinks[col] = cell_hyperlink(cell);
+                if cell.flags.intersects(
                     Flags::WIDE_CHAR_SPACER | Flags::LEADING_WIDE_CHAR_SPACER | Flags::HIDDEN,
                 ) {
                     continue;
                 }
 
-                let c = cell.cell.c;
+                let c = cell.c;
                 if c != '\0' {
                     line[col] = if c.is_control() { ' ' } else { c };
                 }
             }
         });
 
-        Some(line)
+        Some((line, hyperlinks))
+    }
+
+    /// Selects the word or token under `cell`, per `double_click_selection`.
+    fn select_word_at_cell(&mut self, cell: CellPos) -> bool {
+        let Some(line) = self.row_text(cell.row) else {
+            return false;
+        };
+        let trim_edges = self.double_click_selection == DoubleClickSelection::Token;
+        let Some((start_col, end_col)) = word_bounds_in_line(&line, cell.col, trim_edges) else {
+            return false;
+        };
+
+        self.selection_anchor = Some(self.selection_pos_for_cell(CellPos {
+            col: start_col,
+            row: cell.row,
+        }));
+        self.selection_head = Some(self.selection_pos_for_cell(CellPos {
+            col: end_col,
+            row: cell.row,
+        }));
+        self.selection_dragging = false;
+        self.selection_moved = true;
+        true
     }
 
     pub(super) fn link_at_cell(&self, cell: CellPos) -> Option<HoveredLink> {
-        let line = self.row_text(cell.row)?;
-        let detected = find_link_in_line(&line, cell.col)?;
+        let (line, hyperlinks) = self.row_text_and_hyperlinks(cell.row)?;
+        let url_scheme = if self.require_url_scheme {
+            UrlScheme::Required
+        } else {
+            UrlScheme::Optional
+        };
+        let detected = find_link_in_line(&line, &hyperlinks, cell.col, url_scheme)?;
 
         Some(HoveredLink {
             row: cell.row,
             start_col: detected.start_col,
             end_col: detected.end_col,
             target: detected.target,
+            kind: detected.kind,
+        })
+    }
+
+    fn open_hovered_link(&mut self, link: &HoveredLink, cx: &mut Context<Self>) {
+        match link.kind {
+            LinkKind::Url => {
+                if !Self::open_link(&link.target) {
+                    termy_toast::error("Failed to open link");
+                }
+            }
+            LinkKind::Hyperlink { .. } => match hyperlink_target(&link.target) {
+                HyperlinkTarget::Web => {
+                    if !Self::open_link(&link.target) {
+                        termy_toast::error("Failed to open link");
+                    }
+                }
+                HyperlinkTarget::File => self.confirm_open_file_hyperlink(link.target.clone(), cx),
+                HyperlinkTarget::Blocked => {
+                    termy_toast::warning(format!("Blocked link to {}", link.target));
+                }
+            },
+            LinkKind::FileLocation { line, column } => {
+                self.open_file_location(&link.target, line, column, cx)
+            }
+        }
+    }
+
+    /// A `file:` target from program output could launch an app, so the user sees
+    /// the real target and agrees before it opens.
+    fn confirm_open_file_hyperlink(&mut self, uri: String, cx: &mut Context<Self>) {
+        cx.spawn(async move |_this, _cx: &mut AsyncApp| {
+            let (confirmed_tx, confirmed_rx) = bounded(1);
+            termy_native_sdk::confirm_async(
+                "Open Link",
+                &format!(
+                    "A program in this terminal linked to\n\n{}\n\nOpen it?",
+                    uri
+                ),
+                move |confirmed| {
+                    let _ = confirmed_tx.send(confirmed);
+                },
+            );
+            if confirmed_rx.recv_async().await.unwrap_or(false) && !Self::open_link(&uri) {
+                termy_toast::error("Failed to open link");
+            }
         })
+        .detach();
+    }
+
+    /// Opens `path` at `line`/`column` in `$EDITOR`, run in a new tab, or with the
+    /// system handler when no editor is set. Relative paths resolve against the
+    /// active tab's working directory.
+    fn open_file_location(
+        &mut self,
+        path: &str,
+        line: u32,
+        column: Option<u32>,
+        cx: &mut Context<Self>,
+    ) {
+        let path = match self.active_tab_working_directory() {
+            Some(dir) if Path::new(path).is_relative() => dir.join(path),
+            _ => PathBuf::from(path),
+        };
+        if !path.exists() {
+            termy_toast::warning(format!("File not found: {}", path.display()));
+            return;
+        }
+
+        let editor = std::env::var("EDITOR")
+            .ok()
+            .filter(|editor| !editor.trim().is_empty());
+        match editor {
+            Some(editor) if self.use_tabs => {
+                self.open_tab_running(Self::editor_command(&editor, &path, line, column), cx);
+            }
+            _ => {
+                if !Self::open_link(&path.to_string_lossy()) {
+                    termy_toast::error("Failed to open file");
+                }
+            }
+        }
+    }
+
+    /// Shell command that opens `path` at a position, using the `-g file:line:col`
+    /// form for VS Code-style editors, `file:line:col` for editors that take it
+    /// directly and `+line file` for everything else.
+    fn editor_command(editor: &str, path: &Path, line: u32, column: Option<u32>) -> String {
+        let quoted = format!("'{}'", path.to_string_lossy().replace('\'', "'\\''"));
+        let program = editor
+            .split_whitespace()
+            .next()
+            .and_then(|program| Path::new(program).file_name())
+            .and_then(|name| name.to_str())
+            .unwrap_or_default();
+        let column = column.unwrap_or(1);
+        match program {
+            "code" | "code-insiders" | "codium" | "cursor" => {
+                format!("{} -g {}:{}:{}", editor, quoted, line, column)
+            }
+            "hx" | "subl" | "zed" => format!("{} {}:{}:{}", editor, quoted, line, column),
+            _ => format!("{} +{} {}", editor, line, quoted),
+        }
     }
 
     pub(super) fn open_link(url: &str) -> bool {
@@ -401,8 +710,9 @@ impl TerminalView {
         }
         #[cfg(target_os = "windows")]
         {
-            return Command::new("cmd")
-                .args(["/C", "start", "", url])
+            // Not `cmd /C start`: cmd would treat `&` and friends in the URL as syntax.
+            return Command::new("rundll32")
+                .args(["url.dll,FileProtocolHandler", url])
                 .status()
                 .map(|status| status.success())
                 .unwrap_or(false);
@@ -449,24 +759,105 @@ impl TerminalView {
 
     pub(super) fn update_zoom(&mut self, next_size: f32, cx: &mut Context<Self>) {
         let clamped = next_size.clamp(MIN_FONT_SIZE, MAX_FONT_SIZE);
-        let current: f32 = self.font_size.into();
+        let font = &mut self.tabs[self.active_tab].font;
+        let current: f32 = font.size.into();
         if (current - clamped).abs() < f32::EPSILON {
             return;
         }
 
-        self.font_size = px(clamped);
+        font.size = px(clamped);
         // Force cell size recalc so terminal grid reflows at the new zoom.
         self.cell_size = None;
         cx.notify();
     }
 
+    pub(super) fn cycle_cursor_style(&mut self, cx: &mut Context<Self>) {
+        let next = self.cursor_style.next();
+        self.cursor_style = next;
+        self.cursor_blink_visible = true;
+        if let Err(error) = config::set_config_value("cursor_style", next.config_value()) {
+            termy_toast::error(error);
+        }
+        termy_toast::info(format!("Cursor style: {}", next.config_value()));
+        cx.notify();
+    }
+
+    pub(super) fn toggle_shell_integration(&mut self, cx: &mut Context<Self>) {
+        let enabled = !self.tab_shell_integration.enabled;
+        self.tab_title.shell_integration = enabled;
+        self.tab_shell_integration.enabled = enabled;
+        let value = if enabled { "true" } else { "false" };
+        if let Err(error) = config::set_config_value("tab_title_shell_integration", value) {
+            termy_toast::error(error);
+        }
+        // The integration is injected into the shell's environment at spawn time.
+        termy_toast::info(format!(
+            "Shell integration {} for new tabs; open tabs keep their current behavior",
+            if enabled { "on" } else { "off" }
+        ));
+        cx.notify();
+    }
+
+    pub(super) fn toggle_whitespace(&mut self, cx: &mut Context<Self>) {
+        let next = if self.render_whitespace == RenderWhitespace::All {
+            RenderWhitespace::None
+        } else {
+            RenderWhitespace::All
+        };
+        self.render_whitespace = next;
+        if let Err(error) = config::set_config_value("render_whitespace", next.config_value()) {
+            termy_toast::error(error);
+        }
+        cx.notify();
+    }
+
+    /// Re-reads theme files and reapplies the active theme in case its file changed.
+    pub(super) fn reload_themes(&mut self, cx: &mut Context<Self>) {
+        let refresh = termy_themes::refresh_themes();
+        self.reload_config(cx);
+        for error in &refresh.errors {
+            termy_toast::warning(error);
+        }
+        let summary = match refresh.loaded {
+            1 => "Reloaded 1 theme file".to_string(),
+            count => format!("Reloaded {} theme files", count),
+        };
+        termy_toast::success(summary);
+        cx.notify();
+    }
+
+    pub(super) fn step_background_opacity(&mut self, delta: f32, cx: &mut Context<Self>) {
+        if self.reduce_transparency {
+            termy_toast::info("Opacity is fixed while reduce_transparency is on");
+            return;
+        }
+
+        // Round to the step grid so repeated presses don't accumulate float drift.
+        let next = ((self.background_opacity + delta) * 100.0).round() / 100.0;
+        let next = next.clamp(0.0, 1.0);
+        if (next - self.background_opacity).abs() < f32::EPSILON {
+            return;
+        }
+
+        self.background_opacity = next;
+        if let Err(error) = config::set_config_value("background_opacity", &format!("{:.3}", next))
+        {
+            termy_toast::error(error);
+        }
+        termy_toast::info(format!("Background opacity {}%", (next * 100.0).round()));
+        cx.notify();
+    }
+
     pub(super) fn calculate_cell_size(&mut self, window: &mut Window, _cx: &App) -> Size<Pixels> {
-        if let Some(cell_size) = self.cell_size {
-            return cell_size;
+        let tab_font = self.active_tab_font().clone();
+        if let Some((measured_font, cell_size)) = &self.cell_size
+            && *measured_font == tab_font
+        {
+            return *cell_size;
         }
 
         let font = Font {
-            family: self.font_family.clone(),
+            family: tab_font.family.clone(),
             weight: FontWeight::NORMAL,
             ..Default::default()
         };
@@ -475,22 +866,22 @@ impl TerminalView {
         let text_system = window.text_system();
         let font_id = text_system.resolve_font(&font);
         let cell_width = text_system
-            .advance(font_id, self.font_size, 'M')
+            .advance(font_id, tab_font.size, 'M')
             .map(|advance| advance.width)
             .unwrap_or(px(9.0));
 
-        let cell_height = self.font_size * self.line_height;
+        let cell_height = tab_font.size * self.line_height;
 
         let cell_size = Size {
             width: cell_width,
             height: cell_height,
         };
-        self.cell_size = Some(cell_size);
+        self.cell_size = Some((tab_font, cell_size));
         cell_size
     }
 
     pub(super) fn sync_terminal_size(&mut self, window: &Window, cell_size: Size<Pixels>) {
-        let (padding_x, padding_y) = self.effective_terminal_padding();
+        let (padding_x, padding_y) = self.configured_terminal_padding();
         let viewport = window.viewport_size();
         let viewport_width: f32 = viewport.width.into();
         let viewport_height: f32 = viewport.height.into();
@@ -507,12 +898,18 @@ impl TerminalView {
         // In alternate-screen UIs (e.g. fullscreen TUIs), use edge-to-edge sizing
         // so partial-cell remainders don't leave a visible strip on the right/bottom.
         let edge_to_edge_grid = self.active_terminal().alternate_screen_mode();
-        let cols = if edge_to_edge_grid {
+        let window_cols = if edge_to_edge_grid {
             (terminal_width / cell_width).ceil()
         } else {
             (terminal_width / cell_width).floor()
         }
         .max(2.0) as u16;
+        let cols = Self::capped_content_columns(window_cols, self.max_content_columns);
+        self.content_margin_x = if cols < window_cols {
+            ((terminal_width - f32::from(cols) * cell_width) / 2.0).max(0.0)
+        } else {
+            0.0
+        };
         let rows = if edge_to_edge_grid {
             (terminal_height / cell_height).ceil()
         } else {
@@ -520,7 +917,13 @@ impl TerminalView {
         }
         .max(1.0) as u16;
 
+        // Tabs on a different font are resized with their own cell size once activated.
+        let active_font = self.active_tab_font().clone();
         for tab in &mut self.tabs {
+            if tab.font != active_font {
+                continue;
+            }
+
             let current = tab.terminal.size();
             if current.cols != cols
                 || current.rows != rows
@@ -537,6 +940,13 @@ impl TerminalView {
         }
     }
 
+    fn capped_content_columns(window_cols: u16, max_content_columns: u16) -> u16 {
+        if max_content_columns == 0 {
+            return window_cols;
+        }
+        window_cols.min(max_content_columns.max(2))
+    }
+
     pub(super) fn terminal_scroll_lines_from_pixels(
         accumulated_pixels: &mut f32,
         delta_pixels: f32,
@@ -558,6 +968,17 @@ impl TerminalView {
         new_offset - old_offset
     }
 
+    // Precise pixel deltas come from trackpads; line deltas come from notched wheels.
+    fn scroll_device_factor(&self, delta: &ScrollDelta) -> f32 {
+        let (multiplier, invert) = match delta {
+            ScrollDelta::Pixels(_) => {
+                (self.trackpad_scroll_multiplier, self.invert_trackpad_scroll)
+            }
+            ScrollDelta::Lines(_) => (self.wheel_scroll_multiplier, self.invert_wheel_scroll),
+        };
+        if invert { -multiplier } else { multiplier }
+    }
+
     pub(super) fn terminal_scroll_delta_to_lines(&mut self, event: &ScrollWheelEvent) -> i32 {
         match event.touch_phase {
             TouchPhase::Started => {
@@ -574,7 +995,7 @@ impl TerminalView {
                 let line_height: f32 = size.cell_height.into();
                 let viewport_height = line_height * f32::from(size.rows);
                 let raw_delta_pixels: f32 = event.delta.pixel_delta(size.cell_height).y.into();
-                let delta_pixels = raw_delta_pixels * self.mouse_scroll_multiplier;
+                let delta_pixels = raw_delta_pixels * self.scroll_device_factor(&event.delta);
 
                 Self::terminal_scroll_lines_from_pixels(
                     &mut self.terminal_scroll_accumulator_y,
@@ -676,6 +1097,21 @@ impl TerminalView {
             return;
         }
 
+        if let Some(line) = self.terminal_scrollbar_prompt_line_at(hit.local_y, layout) {
+            // Put the prompt at the top of the viewport so its output reads downward.
+            let (display_offset, _) = self.active_terminal().scroll_state();
+            let target_offset = (-line).clamp(0, layout.history_size as i32);
+            if self
+                .active_terminal()
+                .scroll_display(target_offset - display_offset as i32)
+            {
+                self.terminal_scroll_accumulator_y = 0.0;
+            }
+            self.mark_terminal_scrollbar_activity(cx);
+            cx.notify();
+            return;
+        }
+
         let changed = self.apply_terminal_scroll_offset(
             ui_scrollbar::offset_from_track_click(hit.local_y, range, metrics),
             layout,
@@ -687,6 +1123,29 @@ impl TerminalView {
         cx.notify();
     }
 
+    fn terminal_scrollbar_prompt_line_at(
+        &self,
+        local_y: f32,
+        layout: terminal_scrollbar::TerminalScrollbarLayout,
+    ) -> Option<i32> {
+        if !self.scrollbar_prompt_markers {
+            return None;
+        }
+
+        terminal_scrollbar::line_near_marker(
+            self.terminal_scrollbar_prompt_lines(layout.history_size),
+            local_y,
+            layout.history_size,
+            layout.viewport_rows,
+            TERMINAL_SCROLLBAR_MARKER_HEIGHT,
+            terminal_scrollbar::marker_top_limit(
+                layout.metrics.track_height,
+                TERMINAL_SCROLLBAR_MARKER_HEIGHT,
+            ),
+            TERMINAL_SCROLLBAR_PROMPT_MARKER_HIT_SLOP,
+        )
+    }
+
     fn handle_terminal_scrollbar_drag(
         &mut self,
         position: gpui::Point<Pixels>,
@@ -769,7 +1228,10 @@ impl TerminalView {
         }
 
         let busy_titles = self.busy_tab_titles_for_quit();
-        if !self.warn_on_quit_with_running_process || busy_titles.is_empty() {
+        let warn_busy = self.warn_on_quit_with_running_process && !busy_titles.is_empty();
+        let confirm_tabs =
+            self.confirm_quit_multiple_tabs && (self.tabs.len() > 1 || !busy_titles.is_empty());
+        if !warn_busy && !confirm_tabs {
             if target == QuitRequestTarget::Application {
                 self.allow_quit_without_prompt = true;
                 cx.quit();
@@ -779,7 +1241,11 @@ impl TerminalView {
         }
 
         self.quit_prompt_in_flight = true;
-        let detail = self.quit_warning_detail(&busy_titles);
+        let detail = if busy_titles.is_empty() {
+            format!("{} tabs are open.\n\nQuit anyway?", self.tabs.len())
+        } else {
+            self.quit_warning_detail(&busy_titles)
+        };
         let prompt = window.prompt(
             PromptLevel::Warning,
             "Quit Termy?",
@@ -844,6 +1310,12 @@ impl TerminalView {
         cx: &mut Context<Self>,
     ) {
         let shortcuts_suspended = respect_shortcut_suspend && self.command_shortcuts_suspended();
+        if respect_shortcut_suspend
+            && !shortcuts_suspended
+            && !self.begin_navigation_repeat(action, cx)
+        {
+            return;
+        }
 
         match action {
             CommandAction::ToggleCommandPalette => {
@@ -853,7 +1325,10 @@ impl TerminalView {
                     self.open_command_palette(cx);
                 }
             }
-            CommandAction::SwitchTheme => {
+            CommandAction::SwitchTheme
+            | CommandAction::RunCliCommand
+            | CommandAction::InsertCommandOutput
+            | CommandAction::SwitchProfile => {
                 if let Some(mode) = Self::command_palette_mode_for_action(action) {
                     self.command_palette_open = true;
                     self.set_command_palette_mode(mode, false, cx);
@@ -862,6 +1337,15 @@ impl TerminalView {
             CommandAction::Quit => {
                 self.request_quit(QuitRequestTarget::Application, window, cx);
             }
+            // Only reachable while the search bar has focus, where shortcuts are suspended.
+            CommandAction::CopyMatchAndNext => self.copy_match_and_next(cx),
+            CommandAction::CopySearchMatches => self.copy_search_matches(cx),
+            CommandAction::SearchFirstMatch => self.search_first_match(cx),
+            CommandAction::SearchLastMatch => self.search_last_match(cx),
+            CommandAction::SearchNextPage => self.search_page(true, cx),
+            CommandAction::SearchPreviousPage => self.search_page(false, cx),
+            CommandAction::ReplaceCurrent => self.replace_search_matches(false, cx),
+            CommandAction::ReplaceAll => self.replace_search_matches(true, cx),
             _ if shortcuts_suspended => {}
             CommandAction::OpenConfig => config::open_config_file(),
             CommandAction::ImportColors => self.import_colors_action(cx),
@@ -938,14 +1422,14 @@ impl TerminalView {
                 }
             }
             CommandAction::ZoomIn => {
-                let current: f32 = self.font_size.into();
+                let current: f32 = self.active_tab_font().size.into();
                 self.update_zoom(current + ZOOM_STEP, cx);
             }
             CommandAction::ZoomOut => {
-                let current: f32 = self.font_size.into();
+                let current: f32 = self.active_tab_font().size.into();
                 self.update_zoom(current - ZOOM_STEP, cx);
             }
-            CommandAction::ZoomReset => self.update_zoom(self.base_font_size, cx),
+            CommandAction::ZoomReset => self.update_zoom(self.active_tab_font().base_size, cx),
             // Search
             CommandAction::OpenSearch => self.open_search(cx),
             CommandAction::CloseSearch => self.close_search(cx),
@@ -961,6 +1445,12 @@ impl TerminalView {
                 self.perform_search();
                 cx.notify();
             }
+            CommandAction::ToggleSearchWholeWord => {
+                let whole_word = !self.search_state.is_whole_word();
+                self.search_state.set_whole_word(whole_word);
+                self.perform_search();
+                cx.notify();
+            }
             CommandAction::OpenSettings => {
                 use crate::settings_view::SettingsWindow;
                 use gpui::{Bounds, WindowBounds, WindowOptions, px, size};
@@ -998,9 +1488,79 @@ impl TerminalView {
             CommandAction::InstallCli => {
                 self.install_cli_action(cx);
             }
+            CommandAction::CopyWorkingDir => {
+                match self.active_tab_working_directory() {
+                    Some(path) => {
+                        cx.write_to_clipboard(ClipboardItem::new_string(
+                            path.to_string_lossy().into_owned(),
+                        ));
+                        termy_toast::success("Copied working directory");
+                    }
+                    None => termy_toast::warning("Working directory is unknown for this tab"),
+                }
+                cx.notify();
+            }
+            CommandAction::RevealWorkingDir => {
+                match self.active_tab_working_directory() {
+                    Some(path) => {
+                        if !Self::open_link(&path.to_string_lossy()) {
+                            termy_toast::error("Failed to open working directory");
+                        }
+                    }
+                    None => termy_toast::warning("Working directory is unknown for this tab"),
+                }
+                cx.notify();
+            }
+            CommandAction::ScrollPageUp => self.scroll_by_pages(false, 1, cx),
+            CommandAction::ScrollPageDown => self.scroll_by_pages(false, -1, cx),
+            CommandAction::ScrollHalfPageUp => self.scroll_by_pages(true, 1, cx),
+            CommandAction::ScrollHalfPageDown => self.scroll_by_pages(true, -1, cx),
+            CommandAction::NextDiagnostic => self.jump_to_diagnostic(true, cx),
+            CommandAction::PreviousDiagnostic => self.jump_to_diagnostic(false, cx),
+            CommandAction::IncreaseOpacity => {
+                self.step_background_opacity(BACKGROUND_OPACITY_STEP, cx)
+            }
+            CommandAction::DecreaseOpacity => {
+                self.step_background_opacity(-BACKGROUND_OPACITY_STEP, cx)
+            }
+            CommandAction::ClearTabTitle => self.clear_manual_tab_title(self.active_tab, cx),
+            CommandAction::ToggleFullscreen => window.toggle_fullscreen(),
+            CommandAction::ToggleSearchOutputOnly => self.toggle_search_output_only(cx),
+            CommandAction::SaveScrollback => self.save_scrollback_action(window, cx),
+            CommandAction::CopyScreen => self.copy_terminal_text(false, cx),
+            CommandAction::CopyScrollback => self.copy_terminal_text(true, cx),
+            CommandAction::ClearAllHighlights => self.clear_all_highlights(cx),
+            CommandAction::ResumeOutput => self.resume_output(cx),
+            CommandAction::CycleCursorStyle => self.cycle_cursor_style(cx),
+            CommandAction::ToggleShellIntegration => self.toggle_shell_integration(cx),
+            CommandAction::ToggleWhitespace => self.toggle_whitespace(cx),
+            CommandAction::DuplicateTabRerun => self.duplicate_tab_rerun(cx),
+            CommandAction::ReloadThemes => self.reload_themes(cx),
+            CommandAction::FoldLongLines => self.fold_long_lines(cx),
+        }
+    }
+
+    /// Scroll the viewport by a full or half page; positive `direction` moves into history.
+    pub(super) fn scroll_by_pages(&mut self, half: bool, direction: i32, cx: &mut Context<Self>) {
+        let rows = self.active_terminal().size().rows;
+        let delta = Self::page_scroll_lines(rows, half) * direction.signum();
+        let delta = self.long_line_scroll_delta(delta);
+        if self.active_terminal().scroll_display(delta) {
+            self.terminal_scroll_accumulator_y = 0.0;
+            self.mark_terminal_scrollbar_activity(cx);
+            cx.notify();
         }
     }
 
+    fn page_scroll_lines(viewport_rows: u16, half: bool) -> i32 {
+        let lines = if half {
+            viewport_rows / 2
+        } else {
+            viewport_rows
+        };
+        i32::from(lines.max(1))
+    }
+
     fn install_cli_action(&mut self, cx: &mut Context<Self>) {
         match Self::install_cli_binary() {
             Ok(path) => {
@@ -1373,40 +1933,367 @@ impl TerminalView {
         self.execute_command_action(CommandAction::SearchPrevious, true, window, cx);
     }
 
-    pub(super) fn handle_toggle_search_case_sensitive_action(
+    pub(super) fn handle_copy_match_and_next_action(
         &mut self,
-        _: &commands::ToggleSearchCaseSensitive,
+        _: &commands::CopyMatchAndNext,
         window: &mut Window,
         cx: &mut Context<Self>,
     ) {
-        self.execute_command_action(CommandAction::ToggleSearchCaseSensitive, true, window, cx);
+        self.execute_command_action(CommandAction::CopyMatchAndNext, true, window, cx);
     }
 
-    pub(super) fn handle_toggle_search_regex_action(
+    pub(super) fn handle_copy_search_matches_action(
         &mut self,
-        _: &commands::ToggleSearchRegex,
+        _: &commands::CopySearchMatches,
         window: &mut Window,
         cx: &mut Context<Self>,
     ) {
-        self.execute_command_action(CommandAction::ToggleSearchRegex, true, window, cx);
+        self.execute_command_action(CommandAction::CopySearchMatches, true, window, cx);
     }
 
-    pub(super) fn handle_install_cli_action(
+    pub(super) fn handle_search_first_match_action(
         &mut self,
-        _: &commands::InstallCli,
+        _: &commands::SearchFirstMatch,
         window: &mut Window,
         cx: &mut Context<Self>,
     ) {
-        self.execute_command_action(CommandAction::InstallCli, true, window, cx);
+        self.execute_command_action(CommandAction::SearchFirstMatch, true, window, cx);
     }
 
-    pub(super) fn handle_key_down(
+    pub(super) fn handle_search_last_match_action(
         &mut self,
-        event: &KeyDownEvent,
+        _: &commands::SearchLastMatch,
         window: &mut Window,
         cx: &mut Context<Self>,
     ) {
-        self.reset_cursor_blink_phase();
+        self.execute_command_action(CommandAction::SearchLastMatch, true, window, cx);
+    }
+
+    pub(super) fn handle_search_next_page_action(
+        &mut self,
+        _: &commands::SearchNextPage,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.execute_command_action(CommandAction::SearchNextPage, true, window, cx);
+    }
+
+    pub(super) fn handle_search_previous_page_action(
+        &mut self,
+        _: &commands::SearchPreviousPage,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.execute_command_action(CommandAction::SearchPreviousPage, true, window, cx);
+    }
+
+    pub(super) fn handle_replace_current_action(
+        &mut self,
+        _: &commands::ReplaceCurrent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.execute_command_action(CommandAction::ReplaceCurrent, true, window, cx);
+    }
+
+    pub(super) fn handle_replace_all_action(
+        &mut self,
+        _: &commands::ReplaceAll,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.execute_command_action(CommandAction::ReplaceAll, true, window, cx);
+    }
+
+    pub(super) fn handle_toggle_search_case_sensitive_action(
+        &mut self,
+        _: &commands::ToggleSearchCaseSensitive,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.execute_command_action(CommandAction::ToggleSearchCaseSensitive, true, window, cx);
+    }
+
+    pub(super) fn handle_toggle_search_regex_action(
+        &mut self,
+        _: &commands::ToggleSearchRegex,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.execute_command_action(CommandAction::ToggleSearchRegex, true, window, cx);
+    }
+
+    pub(super) fn handle_toggle_search_whole_word_action(
+        &mut self,
+        _: &commands::ToggleSearchWholeWord,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.execute_command_action(CommandAction::ToggleSearchWholeWord, true, window, cx);
+    }
+
+    pub(super) fn handle_install_cli_action(
+        &mut self,
+        _: &commands::InstallCli,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.execute_command_action(CommandAction::InstallCli, true, window, cx);
+    }
+
+    pub(super) fn handle_copy_working_dir_action(
+        &mut self,
+        _: &commands::CopyWorkingDir,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.execute_command_action(CommandAction::CopyWorkingDir, true, window, cx);
+    }
+
+    pub(super) fn handle_reveal_working_dir_action(
+        &mut self,
+        _: &commands::RevealWorkingDir,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.execute_command_action(CommandAction::RevealWorkingDir, true, window, cx);
+    }
+
+    pub(super) fn handle_run_cli_command_action(
+        &mut self,
+        _: &commands::RunCliCommand,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.execute_command_action(CommandAction::RunCliCommand, true, window, cx);
+    }
+
+    pub(super) fn handle_scroll_page_up_action(
+        &mut self,
+        _: &commands::ScrollPageUp,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.execute_command_action(CommandAction::ScrollPageUp, true, window, cx);
+    }
+
+    pub(super) fn handle_scroll_page_down_action(
+        &mut self,
+        _: &commands::ScrollPageDown,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.execute_command_action(CommandAction::ScrollPageDown, true, window, cx);
+    }
+
+    pub(super) fn handle_scroll_half_page_up_action(
+        &mut self,
+        _: &commands::ScrollHalfPageUp,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.execute_command_action(CommandAction::ScrollHalfPageUp, true, window, cx);
+    }
+
+    pub(super) fn handle_scroll_half_page_down_action(
+        &mut self,
+        _: &commands::ScrollHalfPageDown,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.execute_command_action(CommandAction::ScrollHalfPageDown, true, window, cx);
+    }
+
+    pub(super) fn handle_next_diagnostic_action(
+        &mut self,
+        _: &commands::NextDiagnostic,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.execute_command_action(CommandAction::NextDiagnostic, true, window, cx);
+    }
+
+    pub(super) fn handle_previous_diagnostic_action(
+        &mut self,
+        _: &commands::PreviousDiagnostic,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.execute_command_action(CommandAction::PreviousDiagnostic, true, window, cx);
+    }
+
+    pub(super) fn handle_increase_opacity_action(
+        &mut self,
+        _: &commands::IncreaseOpacity,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.execute_command_action(CommandAction::IncreaseOpacity, true, window, cx);
+    }
+
+    pub(super) fn handle_decrease_opacity_action(
+        &mut self,
+        _: &commands::DecreaseOpacity,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.execute_command_action(CommandAction::DecreaseOpacity, true, window, cx);
+    }
+
+    pub(super) fn handle_clear_tab_title_action(
+        &mut self,
+        _: &commands::ClearTabTitle,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.execute_command_action(CommandAction::ClearTabTitle, true, window, cx);
+    }
+
+    pub(super) fn handle_toggle_fullscreen_action(
+        &mut self,
+        _: &commands::ToggleFullscreen,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.execute_command_action(CommandAction::ToggleFullscreen, true, window, cx);
+    }
+
+    pub(super) fn handle_toggle_search_output_only_action(
+        &mut self,
+        _: &commands::ToggleSearchOutputOnly,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.execute_command_action(CommandAction::ToggleSearchOutputOnly, true, window, cx);
+    }
+
+    pub(super) fn handle_insert_command_output_action(
+        &mut self,
+        _: &commands::InsertCommandOutput,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.execute_command_action(CommandAction::InsertCommandOutput, true, window, cx);
+    }
+
+    pub(super) fn handle_save_scrollback_action(
+        &mut self,
+        _: &commands::SaveScrollback,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.execute_command_action(CommandAction::SaveScrollback, true, window, cx);
+    }
+
+    pub(super) fn handle_copy_screen_action(
+        &mut self,
+        _: &commands::CopyScreen,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.execute_command_action(CommandAction::CopyScreen, true, window, cx);
+    }
+
+    pub(super) fn handle_copy_scrollback_action(
+        &mut self,
+        _: &commands::CopyScrollback,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.execute_command_action(CommandAction::CopyScrollback, true, window, cx);
+    }
+
+    pub(super) fn handle_switch_profile_action(
+        &mut self,
+        _: &commands::SwitchProfile,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.execute_command_action(CommandAction::SwitchProfile, true, window, cx);
+    }
+
+    pub(super) fn handle_clear_all_highlights_action(
+        &mut self,
+        _: &commands::ClearAllHighlights,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.execute_command_action(CommandAction::ClearAllHighlights, true, window, cx);
+    }
+
+    pub(super) fn handle_resume_output_action(
+        &mut self,
+        _: &commands::ResumeOutput,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.execute_command_action(CommandAction::ResumeOutput, true, window, cx);
+    }
+
+    pub(super) fn handle_cycle_cursor_style_action(
+        &mut self,
+        _: &commands::CycleCursorStyle,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.execute_command_action(CommandAction::CycleCursorStyle, true, window, cx);
+    }
+
+    pub(super) fn handle_toggle_shell_integration_action(
+        &mut self,
+        _: &commands::ToggleShellIntegration,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.execute_command_action(CommandAction::ToggleShellIntegration, true, window, cx);
+    }
+
+    pub(super) fn handle_toggle_whitespace_action(
+        &mut self,
+        _: &commands::ToggleWhitespace,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.execute_command_action(CommandAction::ToggleWhitespace, true, window, cx);
+    }
+
+    pub(super) fn handle_duplicate_tab_rerun_action(
+        &mut self,
+        _: &commands::DuplicateTabRerun,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.execute_command_action(CommandAction::DuplicateTabRerun, true, window, cx);
+    }
+
+    pub(super) fn handle_reload_themes_action(
+        &mut self,
+        _: &commands::ReloadThemes,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.execute_command_action(CommandAction::ReloadThemes, true, window, cx);
+    }
+
+    pub(super) fn handle_fold_long_lines_action(
+        &mut self,
+        _: &commands::FoldLongLines,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.execute_command_action(CommandAction::FoldLongLines, true, window, cx);
+    }
+
+    pub(super) fn handle_key_down(
+        &mut self,
+        event: &KeyDownEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.reset_cursor_blink_phase();
+        if !event.is_held {
+            self.stop_navigation_repeat();
+        }
         let key = event.keystroke.key.as_str();
 
         if self.command_palette_open {
@@ -1414,6 +2301,13 @@ impl TerminalView {
             return;
         }
 
+        if self.cli_output.is_some() {
+            if key == "escape" {
+                self.close_cli_output(cx);
+            }
+            return;
+        }
+
         if self.search_open {
             self.handle_search_key_down(key, cx);
             return;
@@ -1433,9 +2327,18 @@ impl TerminalView {
             }
         }
 
-        if let Some(input) = keystroke_to_input(&event.keystroke) {
+        if self.tabs[self.active_tab].exited {
+            if key == "enter" {
+                self.close_exited_tab(self.active_tab, cx);
+            }
+            return;
+        }
+
+        let option_as_meta = cfg!(target_os = "macos") && self.macos_option_as_meta;
+        if let Some(input) = keystroke_to_input(&event.keystroke, option_as_meta) {
             self.write_terminal_input(&input, cx);
             self.clear_selection();
+            self.clear_diagnostics();
             // Request a redraw to show the typed character
             cx.notify();
         }
@@ -1486,12 +2389,14 @@ impl TerminalView {
             return;
         }
 
+        if self.close_search_on_click {
+            self.close_search(cx);
+        }
+
         if Self::is_link_modifier(event.modifiers) {
             if let Some(cell) = self.position_to_cell(event.position, false) {
                 if let Some(link) = self.link_at_cell(cell) {
-                    if !Self::open_link(&link.target) {
-                        termy_toast::error("Failed to open link");
-                    }
+                    self.open_hovered_link(&link, cx);
                     if self.clear_hovered_link() {
                         cx.notify();
                     }
@@ -1507,8 +2412,22 @@ impl TerminalView {
             return;
         };
 
-        self.selection_anchor = Some(cell);
-        self.selection_head = Some(cell);
+        if event.click_count == 1 && self.unfold_long_line_at(cell) {
+            self.clear_selection();
+            self.clear_hovered_link();
+            cx.notify();
+            return;
+        }
+
+        if event.click_count == 2 && self.select_word_at_cell(cell) {
+            self.clear_hovered_link();
+            cx.notify();
+            return;
+        }
+
+        let anchor = self.selection_pos_for_cell(cell);
+        self.selection_anchor = Some(anchor);
+        self.selection_head = Some(anchor);
         self.selection_dragging = true;
         self.selection_moved = false;
         self.clear_hovered_link();
@@ -1554,18 +2473,114 @@ impl TerminalView {
             return;
         }
 
-        let Some(next_cell) = self.position_to_cell(event.position, true) else {
-            return;
+        if self.extend_selection_to(event.position) {
+            self.clear_hovered_link();
+            cx.notify();
+        }
+
+        // Dragging past the top or bottom edge keeps scrolling to grow the selection.
+        self.selection_autoscroll_pointer = Some(event.position);
+        if self.selection_autoscroll_delta(event.position) != 0 {
+            self.ensure_selection_autoscroll_animation(cx);
+        }
+    }
+
+    fn extend_selection_to(&mut self, position: gpui::Point<Pixels>) -> bool {
+        let Some(next_cell) = self.position_to_cell(position, true) else {
+            return false;
+        };
+
+        let next_head = self.selection_pos_for_cell(next_cell);
+        if self.selection_head == Some(next_head) {
+            return false;
+        }
+
+        self.selection_head = Some(next_head);
+        if self.selection_anchor != self.selection_head {
+            self.selection_moved = true;
+        }
+        true
+    }
+
+    fn selection_autoscroll_delta(&self, position: gpui::Point<Pixels>) -> i32 {
+        let (_, padding_y) = self.effective_terminal_padding();
+        let size = self.active_terminal().size();
+        let cell_height: f32 = size.cell_height.into();
+        let top = self.chrome_height() + padding_y;
+        let bottom = top + cell_height * f32::from(size.rows);
+
+        Self::selection_autoscroll_lines(position.y.into(), top, bottom, cell_height)
+    }
+
+    /// Lines to scroll per autoscroll tick for a pointer at `y`; positive scrolls
+    /// up into history. Speed grows with how far past the edge the pointer is.
+    fn selection_autoscroll_lines(y: f32, top: f32, bottom: f32, cell_height: f32) -> i32 {
+        if cell_height <= f32::EPSILON {
+            return 0;
+        }
+
+        let (overshoot, direction) = if y < top {
+            (top - y, 1)
+        } else if y > bottom {
+            (y - bottom, -1)
+        } else {
+            return 0;
         };
 
-        if self.selection_head != Some(next_cell) {
-            self.selection_head = Some(next_cell);
-            if self.selection_anchor != self.selection_head {
-                self.selection_moved = true;
+        let lines = (1 + (overshoot / cell_height) as i32).min(SELECTION_AUTOSCROLL_MAX_LINES);
+        lines * direction
+    }
+
+    fn ensure_selection_autoscroll_animation(&mut self, cx: &mut Context<Self>) {
+        if self.selection_autoscroll_animating {
+            return;
+        }
+        self.selection_autoscroll_animating = true;
+
+        cx.spawn(async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+            loop {
+                smol::Timer::after(Duration::from_millis(50)).await;
+                let keep_animating = match cx.update(|cx| {
+                    this.update(cx, |view, cx| {
+                        let keep_animating = view.tick_selection_autoscroll();
+                        if keep_animating {
+                            view.mark_terminal_scrollbar_activity(cx);
+                            cx.notify();
+                        } else {
+                            view.selection_autoscroll_animating = false;
+                        }
+                        keep_animating
+                    })
+                }) {
+                    Ok(keep_animating) => keep_animating,
+                    _ => break,
+                };
+
+                if !keep_animating {
+                    break;
+                }
             }
-            self.clear_hovered_link();
-            cx.notify();
+        })
+        .detach();
+    }
+
+    fn tick_selection_autoscroll(&mut self) -> bool {
+        if !self.selection_dragging {
+            return false;
         }
+        let Some(position) = self.selection_autoscroll_pointer else {
+            return false;
+        };
+
+        let delta = self.long_line_scroll_delta(self.selection_autoscroll_delta(position));
+        // Stop at the scrollback bounds; the next mouse move restarts scrolling.
+        if delta == 0 || !self.active_terminal().scroll_display(delta) {
+            return false;
+        }
+
+        self.terminal_scroll_accumulator_y = 0.0;
+        self.extend_selection_to(position);
+        true
     }
 
     pub(super) fn handle_mouse_up(
@@ -1584,14 +2599,9 @@ impl TerminalView {
             return;
         }
 
-        if let Some(next_cell) = self.position_to_cell(event.position, true) {
-            self.selection_head = Some(next_cell);
-            if self.selection_anchor != self.selection_head {
-                self.selection_moved = true;
-            }
-        }
-
+        self.extend_selection_to(event.position);
         self.selection_dragging = false;
+        self.selection_autoscroll_pointer = None;
         if !self.selection_moved {
             self.clear_selection();
         }
@@ -1639,6 +2649,7 @@ impl TerminalView {
         if delta_lines == 0 {
             return;
         }
+        let delta_lines = self.long_line_scroll_delta(delta_lines);
 
         if self.active_terminal().scroll_display(delta_lines) {
             cx.notify();
@@ -1674,7 +2685,7 @@ impl TerminalView {
     }
 
     pub(super) fn tab_bar_height(&self) -> f32 {
-        if self.show_tab_bar() {
+        if self.show_tab_bar() && !self.tab_bar_overlays_terminal() {
             TABBAR_HEIGHT
         } else {
             0.0
@@ -1702,6 +2713,72 @@ impl TerminalView {
 mod tests {
     use super::*;
 
+    #[test]
+    fn paste_filter_strips_controls_but_keeps_whitespace_and_text() {
+        assert_eq!(
+            TerminalView::filter_paste_control_characters(b"ls -la\n\tcd ~\r\n"),
+            None
+        );
+        assert_eq!(
+            TerminalView::filter_paste_control_characters(b"a\x00b\x1b[201~c\x7fd\x08"),
+            Some(b"ab[201~cd".to_vec())
+        );
+        assert_eq!(
+            TerminalView::filter_paste_control_characters("x\u{9b}31m é ✓".as_bytes()),
+            Some("x31m é ✓".as_bytes().to_vec())
+        );
+    }
+
+    #[test]
+    fn selection_autoscroll_speeds_up_past_the_edges() {
+        // Viewport spans y = 40..=240 with 20px rows.
+        assert_eq!(
+            TerminalView::selection_autoscroll_lines(120.0, 40.0, 240.0, 20.0),
+            0
+        );
+        assert_eq!(
+            TerminalView::selection_autoscroll_lines(35.0, 40.0, 240.0, 20.0),
+            1
+        );
+        assert_eq!(
+            TerminalView::selection_autoscroll_lines(285.0, 40.0, 240.0, 20.0),
+            -3
+        );
+        assert_eq!(
+            TerminalView::selection_autoscroll_lines(-1000.0, 40.0, 240.0, 20.0),
+            SELECTION_AUTOSCROLL_MAX_LINES
+        );
+        assert_eq!(
+            TerminalView::selection_autoscroll_lines(0.0, 40.0, 240.0, 0.0),
+            0
+        );
+    }
+
+    #[test]
+    fn editor_command_matches_each_editors_position_syntax() {
+        let path = Path::new("/tmp/it's.rs");
+        assert_eq!(
+            TerminalView::editor_command("nvim", path, 42, Some(10)),
+            "nvim +42 '/tmp/it'\\''s.rs'"
+        );
+        assert_eq!(
+            TerminalView::editor_command("/usr/local/bin/code --wait", path, 42, None),
+            "/usr/local/bin/code --wait -g '/tmp/it'\\''s.rs':42:1"
+        );
+        assert_eq!(
+            TerminalView::editor_command("hx", path, 7, Some(3)),
+            "hx '/tmp/it'\\''s.rs':7:3"
+        );
+    }
+
+    #[test]
+    fn page_scroll_lines_use_viewport_rows() {
+        assert_eq!(TerminalView::page_scroll_lines(24, false), 24);
+        assert_eq!(TerminalView::page_scroll_lines(24, true), 12);
+        assert_eq!(TerminalView::page_scroll_lines(1, true), 1);
+        assert_eq!(TerminalView::page_scroll_lines(0, false), 1);
+    }
+
     #[test]
     fn viewport_row_maps_scrollback_lines_into_viewport() {
         assert_eq!(TerminalView::viewport_row_from_term_line(-3, 3), Some(0));
@@ -1763,12 +2840,28 @@ mod tests {
         assert_eq!(accumulated, 12.0);
     }
 
+    #[test]
+    fn content_columns_are_capped_only_when_configured() {
+        assert_eq!(TerminalView::capped_content_columns(240, 0), 240);
+        assert_eq!(TerminalView::capped_content_columns(240, 120), 120);
+        assert_eq!(TerminalView::capped_content_columns(80, 120), 80);
+        assert_eq!(TerminalView::capped_content_columns(80, 1), 2);
+    }
+
     #[test]
     fn switch_theme_action_maps_to_theme_palette_mode() {
         assert_eq!(
             TerminalView::command_palette_mode_for_action(CommandAction::SwitchTheme),
             Some(CommandPaletteMode::Themes)
         );
+        assert_eq!(
+            TerminalView::command_palette_mode_for_action(CommandAction::RunCliCommand),
+            Some(CommandPaletteMode::CliCommands)
+        );
+        assert_eq!(
+            TerminalView::command_palette_mode_for_action(CommandAction::InsertCommandOutput),
+            Some(CommandPaletteMode::InsertCommandOutput)
+        );
         assert_eq!(
             TerminalView::command_palette_mode_for_action(CommandAction::OpenConfig),
             None
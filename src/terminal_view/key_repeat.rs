@@ -0,0 +1,94 @@
+use super::*;
+
+impl TerminalView {
+    fn is_repeatable_navigation(action: CommandAction) -> bool {
+        matches!(
+            action,
+            CommandAction::SearchNext
+                | CommandAction::SearchPrevious
+                | CommandAction::ScrollPageUp
+                | CommandAction::ScrollPageDown
+                | CommandAction::ScrollHalfPageUp
+                | CommandAction::ScrollHalfPageDown
+                | CommandAction::NextDiagnostic
+                | CommandAction::PreviousDiagnostic
+        )
+    }
+
+    /// Called when a navigation key goes down. Returns false when the press is an
+    /// OS auto-repeat of a key whose repeat is already driven by
+    /// `key_repeat_delay`/`key_repeat_rate`, so the caller should drop it.
+    pub(super) fn begin_navigation_repeat(
+        &mut self,
+        action: CommandAction,
+        cx: &mut Context<Self>,
+    ) -> bool {
+        if self.key_repeat_rate == 0 || !Self::is_repeatable_navigation(action) {
+            return true;
+        }
+        if self.navigation_repeat == Some(action) {
+            return false;
+        }
+
+        self.navigation_repeat = Some(action);
+        self.navigation_repeat_token = self.navigation_repeat_token.wrapping_add(1);
+        let token = self.navigation_repeat_token;
+        let delay = Duration::from_millis(self.key_repeat_delay);
+        let interval = Duration::from_secs_f64(1.0 / f64::from(self.key_repeat_rate));
+
+        cx.spawn(async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+            smol::Timer::after(delay).await;
+            loop {
+                let result = cx.update(|cx| {
+                    this.update(cx, |view, cx| {
+                        if view.navigation_repeat_token != token {
+                            return false;
+                        }
+                        // The key-up is never delivered once the window loses focus.
+                        if cx.active_window().is_none() {
+                            view.stop_navigation_repeat();
+                            return false;
+                        }
+                        view.run_navigation_action(action, cx);
+                        true
+                    })
+                });
+                if !matches!(result, Ok(Ok(true))) {
+                    break;
+                }
+                smol::Timer::after(interval).await;
+            }
+        })
+        .detach();
+        true
+    }
+
+    pub(super) fn stop_navigation_repeat(&mut self) {
+        if self.navigation_repeat.take().is_some() {
+            self.navigation_repeat_token = self.navigation_repeat_token.wrapping_add(1);
+        }
+    }
+
+    pub(super) fn handle_key_up(
+        &mut self,
+        _event: &KeyUpEvent,
+        _window: &mut Window,
+        _cx: &mut Context<Self>,
+    ) {
+        self.stop_navigation_repeat();
+    }
+
+    fn run_navigation_action(&mut self, action: CommandAction, cx: &mut Context<Self>) {
+        match action {
+            CommandAction::SearchNext => self.search_next(cx),
+            CommandAction::SearchPrevious => self.search_previous(cx),
+            CommandAction::ScrollPageUp => self.scroll_by_pages(false, 1, cx),
+            CommandAction::ScrollPageDown => self.scroll_by_pages(false, -1, cx),
+            CommandAction::ScrollHalfPageUp => self.scroll_by_pages(true, 1, cx),
+            CommandAction::ScrollHalfPageDown => self.scroll_by_pages(true, -1, cx),
+            CommandAction::NextDiagnostic => self.jump_to_diagnostic(true, cx),
+            CommandAction::PreviousDiagnostic => self.jump_to_diagnostic(false, cx),
+            _ => {}
+        }
+    }
+}
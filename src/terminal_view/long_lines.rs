@@ -0,0 +1,521 @@
+use super::*;
+use alacritty_terminal::{
+    grid::{Dimensions, Grid},
+    index::{Column, Line},
+    term::{TermMode, cell::Cell},
+};
+
+/// Marks where `long_lines = truncate` cut a line.
+pub(super) const TRUNCATED_MARKER: char = '\u{2026}';
+/// Marks a line folded by `long_lines = fold`; clicking it unfolds the line.
+pub(super) const FOLDED_MARKER: char = '\u{22EF}';
+
+/// Where long lines are cut: after `limit` characters, except for the logical
+/// lines `unfolded` accepts. `unfolded` is given the grid line a logical line
+/// starts on.
+pub(super) struct LineCut<'a> {
+    pub(super) limit: usize,
+    pub(super) unfolded: &'a dyn Fn(i32) -> bool,
+}
+
+impl LineCut<'_> {
+    /// Rows a cut logical line keeps.
+    fn kept_rows(&self, columns: usize) -> i32 {
+        self.limit.div_ceil(columns.max(1)).max(1) as i32
+    }
+
+    /// Whether the row `line`, part of the logical line starting at `start`, is
+    /// cut away entirely.
+    fn hides(&self, start: i32, line: i32, columns: usize) -> bool {
+        line - start >= self.kept_rows(columns) && !(self.unfolded)(start)
+    }
+}
+
+/// One screen row of a [`LongLineLayout`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) struct LayoutRow {
+    /// Grid line drawn on this row.
+    pub(super) line: i32,
+    /// Column drawn as the cut marker; the cells after it stay empty.
+    pub(super) cut: Option<usize>,
+}
+
+/// Which grid line each screen row shows once long lines are cut. Rows past a
+/// cut are left out instead of drawn blank, so later lines move up and older
+/// ones fill the screen from above. The grid keeps every character, so search,
+/// selection and copy still see whole lines.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(super) struct LongLineLayout {
+    /// Top to bottom, in ascending grid line order.
+    rows: Vec<LayoutRow>,
+}
+
+impl LongLineLayout {
+    /// Lays out the screen so that its bottom row shows the last visible row at
+    /// or above the bottom of `grid`'s viewport.
+    pub(super) fn new(grid: &Grid<Cell>, cut: &LineCut) -> Self {
+        let columns = grid.columns();
+        let screen_lines = grid.screen_lines();
+        let topmost = grid.topmost_line().0;
+        let bottommost = grid.bottommost_line().0;
+        let bottom = bottommost - grid.display_offset() as i32;
+        let kept_rows = cut.kept_rows(columns);
+
+        // One logical line at a time, from the bottom row up.
+        let mut rows = Vec::with_capacity(screen_lines);
+        let mut end = bottom;
+        while rows.len() < screen_lines && end >= topmost {
+            let start = logical_line_start(grid, end);
+            let unfolded = (cut.unfolded)(start);
+            let last_shown = if unfolded {
+                end
+            } else {
+                end.min(start + kept_rows - 1)
+            };
+            for line in (start..=last_shown).rev() {
+                if rows.len() == screen_lines {
+                    break;
+                }
+                rows.push(layout_row(grid, cut, start, line, unfolded));
+            }
+            end = start - 1;
+        }
+        rows.reverse();
+
+        // History ran out before the screen filled, so continue below the viewport.
+        let mut start = logical_line_start(grid, bottom + 1);
+        for line in bottom + 1..=bottommost {
+            if rows.len() == screen_lines {
+                break;
+            }
+            if !wraps(grid, line - 1) {
+                start = line;
+            }
+            if !cut.hides(start, line, columns) {
+                let unfolded = (cut.unfolded)(start);
+                rows.push(layout_row(grid, cut, start, line, unfolded));
+            }
+        }
+
+        Self { rows }
+    }
+
+    pub(super) fn rows(&self) -> &[LayoutRow] {
+        &self.rows
+    }
+
+    /// Grid line drawn on screen row `row`. Rows below the last drawn one count
+    /// on from its line.
+    pub(super) fn line_at(&self, row: usize) -> Option<i32> {
+        match self.rows.get(row) {
+            Some(layout_row) => Some(layout_row.line),
+            None => {
+                let last = self.rows.last()?;
+                Some(last.line + (row + 1 - self.rows.len()) as i32)
+            }
+        }
+    }
+}
+
+fn layout_row(
+    grid: &Grid<Cell>,
+    cut: &LineCut,
+    start: i32,
+    line: i32,
+    unfolded: bool,
+) -> LayoutRow {
+    let columns = grid.columns();
+    let cut_column = (cut.limit.max(1) - 1) % columns.max(1);
+    let on_cut_row = line - start == cut.kept_rows(columns) - 1;
+    LayoutRow {
+        line,
+        cut: (!unfolded && on_cut_row && continues_after(grid, line, cut_column))
+            .then_some(cut_column),
+    }
+}
+
+/// The grid line `rows` visible rows above `from` when `rows` is positive, or
+/// below it when negative, skipping the rows `cut` hides. Stops at either end
+/// of the grid.
+pub(super) fn step_visible_lines(grid: &Grid<Cell>, cut: &LineCut, from: i32, rows: i32) -> i32 {
+    let columns = grid.columns();
+    let topmost = grid.topmost_line().0;
+    let bottommost = grid.bottommost_line().0;
+    let kept_rows = cut.kept_rows(columns);
+
+    let mut start = logical_line_start(grid, from);
+    let mut line = if cut.hides(start, from, columns) {
+        start + kept_rows - 1
+    } else {
+        from
+    };
+    for _ in 0..rows.unsigned_abs() {
+        if rows > 0 {
+            if line <= topmost {
+                break;
+            }
+            line -= 1;
+            start = logical_line_start(grid, line);
+            if cut.hides(start, line, columns) {
+                line = start + kept_rows - 1;
+            }
+        } else {
+            let mut next = line + 1;
+            if cut.hides(start, next, columns) {
+                while next <= bottommost && wraps(grid, next - 1) {
+                    next += 1;
+                }
+            }
+            if next > bottommost {
+                break;
+            }
+            if !wraps(grid, next - 1) {
+                start = next;
+            }
+            line = next;
+        }
+    }
+    line
+}
+
+/// The logical line start of the cell at `line`/`column`, when `cut` hides that
+/// cell or draws the marker over it.
+pub(super) fn cut_line_start(
+    grid: &Grid<Cell>,
+    cut: &LineCut,
+    line: i32,
+    column: usize,
+) -> Option<i32> {
+    let start = logical_line_start(grid, line);
+    if (cut.unfolded)(start) {
+        return None;
+    }
+    let offset = (line - start) as usize * grid.columns() + column;
+    let limit = cut.limit.max(1);
+    let cut_away = offset >= limit || (offset + 1 == limit && continues_after(grid, line, column));
+    cut_away.then_some(start)
+}
+
+/// What a cached [`LongLineLayout`] was computed from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct LongLineLayoutKey {
+    tab_id: u64,
+    long_line_revision: u64,
+    screen_top_line: u64,
+    history_size: usize,
+    display_offset: usize,
+    cursor_line: i32,
+    columns: usize,
+    screen_lines: usize,
+}
+
+/// The active tab's layout as of the last frame, so mouse positions map to the
+/// lines that were drawn there.
+#[derive(Clone, Debug, Default)]
+pub(super) struct LongLineLayoutCache {
+    key: Option<LongLineLayoutKey>,
+    /// `None` while nothing is cut: `long_lines = wrap` or the alternate screen.
+    layout: Option<LongLineLayout>,
+}
+
+impl LongLineLayoutCache {
+    pub(super) fn clear(&mut self) {
+        self.key = None;
+        self.layout = None;
+    }
+
+    pub(super) fn layout(&self) -> Option<&LongLineLayout> {
+        self.layout.as_ref()
+    }
+}
+
+impl TerminalView {
+    /// Runs `f` on the active tab's grid with the cut in effect, or returns `None`
+    /// when lines are not cut. The cursor's logical line is never cut, so typing
+    /// stays visible; in fold mode neither are the lines the user unfolded.
+    fn with_long_line_cut<R>(&self, f: impl FnOnce(&Grid<Cell>, &LineCut, u64) -> R) -> Option<R> {
+        if self.long_lines == LongLines::Wrap {
+            return None;
+        }
+        let fold = self.long_lines == LongLines::Fold;
+        let tab = &self.tabs[self.active_tab];
+        tab.terminal
+            .with_term_at_screen_top(|term, screen_top_line| {
+                if term.mode().contains(TermMode::ALT_SCREEN) {
+                    return None;
+                }
+                let grid = term.grid();
+                let cursor_start = logical_line_start(grid, grid.cursor.point.line.0);
+                let unfolded = |start: i32| {
+                    start == cursor_start
+                        || (fold
+                            && screen_top_line
+                                .checked_add_signed(i64::from(start))
+                                .is_some_and(|line| tab.unfolded_long_lines.contains(&line)))
+                };
+                let cut = LineCut {
+                    limit: self.long_line_limit,
+                    unfolded: &unfolded,
+                };
+                Some(f(grid, &cut, screen_top_line))
+            })
+    }
+
+    /// Recomputes the active tab's layout when its output, scroll position, size
+    /// or unfolded lines changed since the last frame.
+    pub(super) fn refresh_long_line_layout(&mut self) {
+        let tab = &self.tabs[self.active_tab];
+        let tab_id = tab.id;
+        let long_line_revision = tab.long_line_revision;
+        let cached_key = self.long_line_layout.key;
+        let refreshed = self.with_long_line_cut(|grid, cut, screen_top_line| {
+            let key = LongLineLayoutKey {
+                tab_id,
+                long_line_revision,
+                screen_top_line,
+                history_size: grid.history_size(),
+                display_offset: grid.display_offset(),
+                cursor_line: grid.cursor.point.line.0,
+                columns: grid.columns(),
+                screen_lines: grid.screen_lines(),
+            };
+            (cached_key != Some(key)).then(|| (key, LongLineLayout::new(grid, cut)))
+        });
+        match refreshed {
+            None => self.long_line_layout.clear(),
+            Some(None) => {}
+            Some(Some((key, layout))) => {
+                self.long_line_layout.key = Some(key);
+                self.long_line_layout.layout = Some(layout);
+            }
+        }
+    }
+
+    /// The marker drawn where a line is cut.
+    pub(super) fn long_line_marker(&self) -> char {
+        if self.long_lines == LongLines::Fold {
+            FOLDED_MARKER
+        } else {
+            TRUNCATED_MARKER
+        }
+    }
+
+    /// Grid line drawn on viewport row `row` in the last frame.
+    pub(super) fn term_line_at_row(&self, row: usize) -> i32 {
+        if let Some(line) = self
+            .long_line_layout
+            .layout()
+            .and_then(|layout| layout.line_at(row))
+        {
+            return line;
+        }
+        let (display_offset, _) = self.active_terminal().scroll_state();
+        row as i32 - display_offset as i32
+    }
+
+    /// Column of the cut marker on viewport row `row` in the last frame.
+    pub(super) fn long_line_cut_at_row(&self, row: usize) -> Option<usize> {
+        self.long_line_layout
+            .layout()?
+            .rows()
+            .get(row)
+            .and_then(|row| row.cut)
+    }
+
+    /// Converts a scroll by `rows` viewport rows into a `scroll_display` delta,
+    /// which counts grid lines, so scrolling keeps pace with what is drawn.
+    pub(super) fn long_line_scroll_delta(&self, rows: i32) -> i32 {
+        self.with_long_line_cut(|grid, cut, _| {
+            let bottom = grid.bottommost_line().0 - grid.display_offset() as i32;
+            bottom - step_visible_lines(grid, cut, bottom, rows)
+        })
+        .unwrap_or(rows)
+    }
+
+    /// Makes the cell at `line`/`column` visible: in fold mode by unfolding its
+    /// line, otherwise by returning the row its line is cut on.
+    pub(super) fn reveal_long_line_cell(&mut self, line: i32, column: usize) -> i32 {
+        let fold = self.long_lines == LongLines::Fold;
+        let Some((visible_line, folded)) = self.with_long_line_cut(|grid, cut, screen_top_line| {
+            let folded = cut_line_start(grid, cut, line, column)
+                .filter(|_| fold)
+                .and_then(|start| screen_top_line.checked_add_signed(i64::from(start)));
+            (step_visible_lines(grid, cut, line, 0), folded)
+        }) else {
+            return line;
+        };
+        match folded {
+            Some(start) => {
+                self.unfold_long_line(start);
+                line
+            }
+            None => visible_line,
+        }
+    }
+
+    /// Unfolds the line under a click on its fold marker.
+    pub(super) fn unfold_long_line_at(&mut self, cell: CellPos) -> bool {
+        if self.long_lines != LongLines::Fold
+            || self.long_line_cut_at_row(cell.row) != Some(cell.col)
+        {
+            return false;
+        }
+        let line = self.term_line_at_row(cell.row);
+        let start = self
+            .with_long_line_cut(|grid, _, screen_top_line| {
+                screen_top_line.checked_add_signed(i64::from(logical_line_start(grid, line)))
+            })
+            .flatten();
+        start.is_some_and(|start| self.unfold_long_line(start))
+    }
+
+    /// Unfolds the logical line starting at absolute line `start`, numbered like
+    /// `Terminal::screen_top_line`.
+    fn unfold_long_line(&mut self, start: u64) -> bool {
+        let tab = &mut self.tabs[self.active_tab];
+        if !tab.unfolded_long_lines.insert(start) {
+            return false;
+        }
+        tab.long_line_revision = tab.long_line_revision.wrapping_add(1);
+        true
+    }
+
+    pub(super) fn fold_long_lines(&mut self, cx: &mut Context<Self>) {
+        if self.long_lines != LongLines::Fold {
+            termy_toast::info("Set long_lines = fold to fold long lines");
+            return;
+        }
+        let tab = &mut self.tabs[self.active_tab];
+        if tab.unfolded_long_lines.is_empty() {
+            return;
+        }
+        tab.unfolded_long_lines.clear();
+        tab.long_line_revision = tab.long_line_revision.wrapping_add(1);
+        cx.notify();
+    }
+}
+
+/// First grid line of the logical line `line` belongs to.
+pub(super) fn logical_line_start(grid: &Grid<Cell>, line: i32) -> i32 {
+    let topmost = grid.topmost_line().0;
+    let mut start = line;
+    while start > topmost && wraps(grid, start - 1) {
+        start -= 1;
+    }
+    start
+}
+
+fn wraps(grid: &Grid<Cell>, line: i32) -> bool {
+    let columns = grid.columns();
+    line >= grid.topmost_line().0
+        && line <= grid.bottommost_line().0
+        && columns > 0
+        && grid[Line(line)][Column(columns - 1)]
+            .flags
+            .contains(Flags::WRAPLINE)
+}
+
+/// Whether the logical line holds any text after the cell at `column` on `line`.
+fn continues_after(grid: &Grid<Cell>, line: i32, column: usize) -> bool {
+    if wraps(grid, line) {
+        return true;
+    }
+    let row = &grid[Line(line)];
+    (column + 1..grid.columns()).any(|col| row[Column(col)].c != ' ')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wrapped_grid(rows: &[&str], columns: usize, wraps: &[usize]) -> Grid<Cell> {
+        let mut grid = Grid::new(rows.len(), columns, 0);
+        for (index, text) in rows.iter().enumerate() {
+            for (col, c) in text.chars().enumerate() {
+                grid[Line(index as i32)][Column(col)].c = c;
+            }
+        }
+        for &row in wraps {
+            grid[Line(row as i32)][Column(columns - 1)]
+                .flags
+                .insert(Flags::WRAPLINE);
+        }
+        grid
+    }
+
+    fn lines(layout: &LongLineLayout) -> Vec<(i32, Option<usize>)> {
+        layout
+            .rows()
+            .iter()
+            .map(|row| (row.line, row.cut))
+            .collect()
+    }
+
+    #[test]
+    fn cut_rows_are_left_out_and_older_lines_fill_the_screen() {
+        // "ab" then a five row line, then "cd" and "ef".
+        let grid = wrapped_grid(
+            &["ab", "1111", "2222", "3333", "4444", "55", "cd", "ef"],
+            4,
+            &[1, 2, 3, 4],
+        );
+        let folded = |_: i32| false;
+        let cut = LineCut {
+            limit: 6,
+            unfolded: &folded,
+        };
+
+        let layout = LongLineLayout::new(&grid, &cut);
+        assert_eq!(
+            lines(&layout),
+            [(0, None), (1, None), (2, Some(1)), (6, None), (7, None)]
+        );
+        assert_eq!(layout.line_at(3), Some(6));
+        assert_eq!(layout.line_at(6), Some(8));
+
+        let unfolded = |start: i32| start == 1;
+        let cut = LineCut {
+            limit: 6,
+            unfolded: &unfolded,
+        };
+        assert_eq!(LongLineLayout::new(&grid, &cut).rows().len(), 8);
+    }
+
+    #[test]
+    fn stepping_skips_cut_rows_in_both_directions() {
+        let grid = wrapped_grid(
+            &["ab", "1111", "2222", "3333", "4444", "55", "cd", "ef"],
+            4,
+            &[1, 2, 3, 4],
+        );
+        let folded = |_: i32| false;
+        let cut = LineCut {
+            limit: 6,
+            unfolded: &folded,
+        };
+
+        assert_eq!(step_visible_lines(&grid, &cut, 6, 1), 2);
+        assert_eq!(step_visible_lines(&grid, &cut, 4, 1), 1);
+        assert_eq!(step_visible_lines(&grid, &cut, 2, -1), 6);
+        assert_eq!(step_visible_lines(&grid, &cut, 0, -2), 2);
+        assert_eq!(step_visible_lines(&grid, &cut, 7, -1), 7);
+        assert_eq!(step_visible_lines(&grid, &cut, 1, 5), 0);
+    }
+
+    #[test]
+    fn cut_line_start_covers_the_marker_and_everything_after_it() {
+        let grid = wrapped_grid(&["1111", "2222", "3"], 4, &[0, 1]);
+        let folded = |_: i32| false;
+        let cut = LineCut {
+            limit: 6,
+            unfolded: &folded,
+        };
+
+        assert_eq!(cut_line_start(&grid, &cut, 1, 0), None);
+        assert_eq!(cut_line_start(&grid, &cut, 1, 1), Some(0));
+        assert_eq!(cut_line_start(&grid, &cut, 2, 0), Some(0));
+
+        let short = wrapped_grid(&["1111", "22"], 4, &[0]);
+        assert_eq!(cut_line_start(&short, &cut, 1, 1), None);
+    }
+}
@@ -1,8 +1,10 @@
-use crate::colors::TerminalColors;
+use crate::colors::{TerminalColors, ensure_minimum_contrast};
 use crate::commands::{self, CommandAction};
 use crate::config::{
-    self, AppConfig, CursorStyle as AppCursorStyle, TabTitleConfig, TabTitleSource,
-    TerminalScrollbarStyle, TerminalScrollbarVisibility,
+    self, AppConfig, CursorStyle as AppCursorStyle, CustomCommandAction, CustomCommandConfig,
+    DoubleClickSelection, LongLines, ProfileConfig, RenderWhitespace, SearchBarStyle,
+    ShellExitBehavior, TabBarVisibility, TabTitleConfig, TabTitleSource, TerminalScrollbarStyle,
+    TerminalScrollbarVisibility,
 };
 use crate::keybindings;
 use crate::ui::scrollbar::{ScrollbarVisibilityController, ScrollbarVisibilityMode};
@@ -10,35 +12,45 @@ use alacritty_terminal::term::cell::Flags;
 use flume::{Sender, bounded};
 use gpui::{
     AnyElement, App, AsyncApp, ClipboardItem, Context, Element, ExternalPaths, FocusHandle,
-    Focusable, Font, FontWeight, InteractiveElement, IntoElement, KeyDownEvent, MouseButton,
-    MouseDownEvent, MouseMoveEvent, MouseUpEvent, ParentElement, Pixels, Render, ScrollHandle,
-    ScrollWheelEvent, SharedString, Size, StatefulInteractiveElement, Styled, TouchPhase,
-    UniformListScrollHandle, WeakEntity, Window, WindowBackgroundAppearance, WindowControlArea,
-    div, point, px,
+    Focusable, Font, FontWeight, InteractiveElement, IntoElement, KeyDownEvent, KeyUpEvent,
+    MouseButton, MouseDownEvent, MouseMoveEvent, MouseUpEvent, ParentElement, Pixels, Render,
+    ScrollDelta, ScrollHandle, ScrollWheelEvent, SharedString, Size, StatefulInteractiveElement,
+    Styled, TouchPhase, UniformListScrollHandle, WeakEntity, Window, WindowBackgroundAppearance,
+    WindowControlArea, div, point, px,
 };
 use std::{
+    collections::HashSet,
     env, fs,
     hash::{DefaultHasher, Hash, Hasher},
     path::{Path, PathBuf},
     process::Command,
     time::{Duration, Instant},
 };
-use termy_search::SearchState;
+use termy_search::{SearchEngine, SearchResults, SearchScope, SearchState};
 use termy_terminal_ui::{
-    CellRenderInfo, TabTitleShellIntegration, Terminal, TerminalCursorStyle, TerminalEvent,
-    TerminalGrid, TerminalRuntimeConfig, TerminalSize,
-    WorkingDirFallback as RuntimeWorkingDirFallback, find_link_in_line, keystroke_to_input,
+    CellHyperlink, CellRenderInfo, HyperlinkIds, HyperlinkTarget, LinkKind, ScrollbackFormat,
+    TabTitleShellIntegration, Terminal, TerminalCursorStyle, TerminalEvent, TerminalGrid,
+    TerminalRuntimeConfig, TerminalSize, UrlScheme,
+    WorkingDirFallback as RuntimeWorkingDirFallback, cell_hyperlink, find_link_in_line,
+    hyperlink_target, keystroke_to_input, word_bounds_in_line,
 };
-use termy_toast::ToastManager;
+use termy_themes::ThemeAppearance;
+use termy_toast::{ToastDurations, ToastManager};
 
 #[cfg(target_os = "macos")]
 use gpui::{AppContext, Entity};
 #[cfg(target_os = "macos")]
-use termy_auto_update::{AutoUpdater, UpdateState};
+use termy_auto_update::{AutoUpdater, UpdateChannel, UpdateState};
 
+mod cli_output;
 mod command_palette;
+mod diagnostics;
+mod flow_control;
 mod inline_input;
+mod insert_output;
 mod interaction;
+mod key_repeat;
+mod long_lines;
 mod render;
 mod scrollbar;
 mod search;
@@ -48,16 +60,20 @@ mod titles;
 #[cfg(target_os = "macos")]
 mod update_toasts;
 
+use cli_output::{CliOutput, CliOutputCommand};
 use inline_input::{InlineInputAlignment, InlineInputState};
 
 const MIN_FONT_SIZE: f32 = 8.0;
 const MAX_FONT_SIZE: f32 = 40.0;
 const ZOOM_STEP: f32 = 1.0;
+const BACKGROUND_OPACITY_STEP: f32 = 0.05;
 #[cfg(target_os = "windows")]
 const TITLEBAR_HEIGHT: f32 = 32.0;
 #[cfg(not(target_os = "windows"))]
 const TITLEBAR_HEIGHT: f32 = 34.0;
 const TABBAR_HEIGHT: f32 = 34.0;
+// Distance below the titlebar within which the pointer reveals a hover-reveal tab bar.
+const TABBAR_REVEAL_EDGE: f32 = 6.0;
 const TOP_STRIP_SIDE_PADDING: f32 = 10.0;
 #[cfg(macos_sdk_26)]
 const TOP_STRIP_MACOS_TRAFFIC_LIGHT_PADDING: f32 = 78.0;
@@ -86,9 +102,16 @@ const TAB_STROKE_FOREGROUND_MIX: f32 = 0.12;
 const TAB_STROKE_THICKNESS: f32 = 1.0;
 const TAB_DROP_MARKER_WIDTH: f32 = 2.0;
 const TAB_DROP_MARKER_INSET_Y: f32 = 3.0;
+const TAB_ACTIVITY_INDICATOR_SIZE: f32 = 5.0;
+const TAB_ACTIVITY_INDICATOR_INSET_X: f32 = 3.0;
 const TAB_DRAG_AUTOSCROLL_EDGE_WIDTH: f32 = 32.0;
 const TAB_DRAG_AUTOSCROLL_MAX_STEP: f32 = 24.0;
+const SELECTION_AUTOSCROLL_MAX_LINES: i32 = 8;
 const TABBAR_ACTION_RAIL_WIDTH: f32 = 40.0;
+const SHELL_EXIT_HOLD_MESSAGE: &str = "\r\n[Process exited, press Enter to close]\r\n";
+// Restart loop guard: a shell that dies this often is given up on and held instead.
+const SHELL_RESTART_LIMIT: usize = 5;
+const SHELL_RESTART_WINDOW: Duration = Duration::from_secs(10);
 const TABBAR_NEW_TAB_BUTTON_SIZE: f32 = 22.0;
 const TABBAR_NEW_TAB_BUTTON_RADIUS: f32 = 2.0;
 const TABBAR_NEW_TAB_ICON_SIZE: f32 = 13.0;
@@ -101,6 +124,7 @@ const CONFIG_WATCH_INTERVAL_MS: u64 = 750;
 const CURSOR_BLINK_INTERVAL_MS: u64 = 530;
 const SELECTION_BG_ALPHA: f32 = 0.35;
 const DIM_TEXT_FACTOR: f32 = 0.66;
+const WRAP_INDICATOR_ALPHA: f32 = 0.35;
 #[cfg(target_os = "macos")]
 const UPDATE_BANNER_HEIGHT: f32 = 44.0;
 const COMMAND_PALETTE_WIDTH: f32 = 640.0;
@@ -122,7 +146,13 @@ const TERMINAL_SCROLLBAR_TRACK_ALPHA: f32 = 0.28;
 const TERMINAL_SCROLLBAR_THUMB_ALPHA: f32 = 0.56;
 const TERMINAL_SCROLLBAR_THUMB_ACTIVE_ALPHA: f32 = 0.78;
 const TERMINAL_SCROLLBAR_MATCH_MARKER_ALPHA: f32 = 0.55;
+/// Match marker alpha for the denser tiers of `scrollbar_match_density`.
+const TERMINAL_SCROLLBAR_DENSE_MARKER_ALPHAS: [f32; scrollbar::MARKER_DENSITY_TIERS - 1] =
+    [0.72, 0.88];
 const TERMINAL_SCROLLBAR_CURRENT_MARKER_ALPHA: f32 = 0.92;
+const TERMINAL_SCROLLBAR_PROMPT_MARKER_ALPHA: f32 = 0.8;
+// Extra distance either side of a prompt marker that still counts as clicking it.
+const TERMINAL_SCROLLBAR_PROMPT_MARKER_HIT_SLOP: f32 = 3.0;
 const TERMINAL_SCROLLBAR_MARKER_HEIGHT: f32 = 2.0;
 const TERMINAL_SCROLLBAR_TRACK_RADIUS: f32 = 0.0;
 const TERMINAL_SCROLLBAR_THUMB_RADIUS: f32 = 0.0;
@@ -131,10 +161,45 @@ const TERMINAL_SCROLLBAR_MUTED_THEME_BLEND: f32 = 0.38;
 const SEARCH_BAR_WIDTH: f32 = 320.0;
 const SEARCH_BAR_HEIGHT: f32 = 36.0;
 const SEARCH_DEBOUNCE_MS: u64 = 50;
+const SEARCH_PAGE_MATCHES: usize = 10;
+/// Lines searched per frame, so a long scrollback doesn't stall rendering.
+const SEARCH_CHUNK_LINES: usize = 5_000;
+const MAX_OUTPUT_ZONES: usize = 1000;
+// Search highlight colors tuned for strong contrast on dark terminal themes.
+const SEARCH_MATCH_BG: gpui::Hsla = gpui::Hsla {
+    h: 0.14,
+    s: 0.92,
+    l: 0.62,
+    a: 0.62,
+};
+const SEARCH_CURRENT_BG: gpui::Hsla = gpui::Hsla {
+    h: 0.09,
+    s: 0.98,
+    l: 0.56,
+    a: 0.86,
+};
+// Red tints keep error/warning lines distinct from the amber search highlights.
+const DIAGNOSTIC_MATCH_BG: gpui::Hsla = gpui::Hsla {
+    h: 0.0,
+    s: 0.82,
+    l: 0.6,
+    a: 0.5,
+};
+const DIAGNOSTIC_CURRENT_BG: gpui::Hsla = gpui::Hsla {
+    h: 0.0,
+    s: 0.9,
+    l: 0.58,
+    a: 0.86,
+};
 const INPUT_SCROLL_SUPPRESS_MS: u64 = 160;
 const TOAST_COPY_FEEDBACK_MS: u64 = 1200;
+const THEME_TRANSITION_MS: u64 = 150;
+// Roughly what fits beside the icon and Copy button in a 320px toast at 13px.
+const TOAST_MESSAGE_CHARS_PER_LINE: usize = 30;
 const OVERLAY_PANEL_ALPHA_FLOOR_RATIO: f32 = 0.72;
 const OVERLAY_DIM_MIN_SCALE: f32 = 0.25;
+// A dark scrim over a light theme reads much stronger than a light one over a dark theme.
+const LIGHT_THEME_DIM_ALPHA_SCALE: f32 = 0.4;
 const OVERLAY_PANEL_BORDER_ALPHA: f32 = 0.24;
 const OVERLAY_PRIMARY_TEXT_ALPHA: f32 = 0.95;
 const OVERLAY_MUTED_TEXT_ALPHA: f32 = 0.62;
@@ -157,6 +222,10 @@ const SEARCH_COUNTER_TEXT_ALPHA: f32 = 0.60;
 const SEARCH_BUTTON_TEXT_ALPHA: f32 = 0.70;
 const SEARCH_BUTTON_HOVER_BG_ALPHA: f32 = 0.20;
 const SEARCH_INPUT_SELECTION_ALPHA: f32 = 0.30;
+const SEARCH_BAR_ACCENT_BG_BLEND: f32 = 0.22;
+const SEARCH_BAR_ACCENT_INPUT_BLEND: f32 = 0.10;
+const SEARCH_BAR_ACCENT_BORDER_ALPHA: f32 = 0.90;
+const SEARCH_BAR_ACCENT_TEXT_MIN_CONTRAST: f32 = 4.5;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 struct CellPos {
@@ -164,6 +233,14 @@ struct CellPos {
     row: usize,
 }
 
+/// Selection endpoint anchored to buffer content rather than the viewport, so it
+/// stays put while scrolling. `line` uses alacritty coordinates (negative = history).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct SelectionPos {
+    col: usize,
+    line: i32,
+}
+
 #[derive(Clone, Copy, Debug)]
 pub(super) struct TerminalViewportGeometry {
     origin_x: f32,
@@ -192,7 +269,9 @@ struct TerminalScrollbarHit {
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 struct TerminalScrollbarMarkerCacheKey {
-    results_revision: u64,
+    source_revision: u64,
+    /// Moves while output scrolls even once `history_size` stops growing at its cap.
+    screen_top_line: u64,
     history_size: usize,
     viewport_rows: usize,
     marker_top_limit_bucket: i32,
@@ -202,17 +281,48 @@ struct TerminalScrollbarMarkerCacheKey {
 struct TerminalScrollbarMarkerCache {
     key: Option<TerminalScrollbarMarkerCacheKey>,
     marker_tops: Vec<f32>,
+    /// Markers above the base density tier, filled only with `scrollbar_match_density`.
+    dense_marker_tops: [Vec<f32>; scrollbar::MARKER_DENSITY_TIERS - 1],
 }
 
 impl TerminalScrollbarMarkerCache {
     fn clear(&mut self) {
         self.key = None;
         self.marker_tops.clear();
+        for tops in &mut self.dense_marker_tops {
+            tops.clear();
+        }
+    }
+}
+
+/// Font a tab's grid renders with. Tabs on a profile can differ from the root config.
+#[derive(Clone, Debug, PartialEq)]
+struct TabFont {
+    family: SharedString,
+    /// Size from config, restored by zoom reset.
+    base_size: f32,
+    /// Current size including zoom.
+    size: Pixels,
+}
+
+impl TabFont {
+    fn new(family: SharedString, base_size: f32) -> Self {
+        let base_size = base_size.clamp(MIN_FONT_SIZE, MAX_FONT_SIZE);
+        Self {
+            family,
+            base_size,
+            size: px(base_size),
+        }
     }
 }
 
 struct TerminalTab {
+    /// Unique for the view's lifetime, unlike the tab's index.
+    id: u64,
     terminal: Terminal,
+    /// Name of the `[profile.<name>]` section this tab uses, if any.
+    profile: Option<String>,
+    font: TabFont,
     manual_title: Option<String>,
     explicit_title: Option<String>,
     shell_title: Option<String>,
@@ -221,10 +331,64 @@ struct TerminalTab {
     title: String,
     display_width: f32,
     running_process: bool,
+    /// Working directory last reported by the shell prompt, in display form.
+    working_dir: Option<String>,
+    /// Most recent command line reported by shell integration.
+    last_command: Option<String>,
+    /// Output arrived while this tab was in the background.
+    unseen_activity: bool,
+    /// A bell fired while this tab was in the background.
+    bell_since_viewed: bool,
+    /// The shell has exited and the tab is held open until Enter is pressed.
+    exited: bool,
+    /// Ctrl-S stopped output through XON/XOFF flow control and no Ctrl-Q followed yet.
+    output_paused: bool,
+    /// When the shell was last auto-restarted, for the restart loop guard.
+    recent_restarts: Vec<Instant>,
+    /// Command output regions reported by shell integration, oldest first.
+    output_zones: Vec<OutputZone>,
+    /// Changes whenever `output_zones` does; 0 while no zone was ever recorded.
+    output_zones_revision: u64,
+    /// Where the shell's input line begins, for edits typed on the user's behalf.
+    prompt_input: PromptInput,
+    /// Logical lines unfolded with `long_lines = fold`, by the absolute number of
+    /// their first row, numbered like `OutputZone`.
+    unfolded_long_lines: HashSet<u64>,
+    /// Changes on new output and whenever `unfolded_long_lines` does, so the
+    /// cached long line layout is recomputed.
+    long_line_revision: u64,
+}
+
+/// Lines between a command starting and the next prompt, numbered like
+/// `Terminal::screen_top_line`. `end` is exclusive and unset while the command runs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct OutputZone {
+    start: u64,
+    end: Option<u64>,
+}
+
+/// The start of the input line after a shell integration prompt. The prompt
+/// reports itself before it is drawn, so its end is taken from the cursor at
+/// the first input that follows.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum PromptInput {
+    /// No prompt was reported, or a command is running.
+    #[default]
+    Unknown,
+    /// A prompt was reported and nothing has been typed since.
+    AwaitingInput,
+    /// Input starts at this column of this line, numbered like `OutputZone`.
+    StartsAt { line: u64, column: usize },
 }
 
 impl TerminalTab {
-    fn new(terminal: Terminal, predicted_prompt_title: Option<String>) -> Self {
+    fn new(
+        id: u64,
+        terminal: Terminal,
+        font: TabFont,
+        predicted_prompt_title: Option<String>,
+        predicted_working_dir: Option<String>,
+    ) -> Self {
         let title = predicted_prompt_title
             .as_deref()
             .unwrap_or(DEFAULT_TAB_TITLE)
@@ -232,7 +396,10 @@ impl TerminalTab {
         let display_width = TerminalView::tab_display_width_for_title(&title);
 
         Self {
+            id,
             terminal,
+            profile: None,
+            font,
             manual_title: None,
             explicit_title: predicted_prompt_title,
             shell_title: None,
@@ -241,13 +408,25 @@ impl TerminalTab {
             title,
             display_width,
             running_process: false,
+            working_dir: predicted_working_dir,
+            last_command: None,
+            unseen_activity: false,
+            bell_since_viewed: false,
+            exited: false,
+            output_paused: false,
+            recent_restarts: Vec::new(),
+            output_zones: Vec::new(),
+            output_zones_revision: 0,
+            prompt_input: PromptInput::Unknown,
+            unfolded_long_lines: HashSet::new(),
+            long_line_revision: 0,
         }
     }
 }
 
 enum ExplicitTitlePayload {
-    Prompt(String),
-    Command(String),
+    Prompt { cwd: String, title: String },
+    Command { command: String, title: String },
     Title(String),
 }
 
@@ -257,18 +436,27 @@ struct HoveredLink {
     start_col: usize,
     end_col: usize,
     target: String,
+    kind: LinkKind,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum CommandPaletteMode {
     Commands,
     Themes,
+    CliCommands,
+    InsertCommandOutput,
+    Profiles,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 enum CommandPaletteItemKind {
     Command(CommandAction),
     Theme(String),
+    CliCommand(CliOutputCommand),
+    /// `None` is the root config font.
+    Profile(Option<String>),
+    /// A `[command.<name>]` entry from the config.
+    Custom(CustomCommandAction),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -442,7 +630,14 @@ impl<'a> OverlayStyleBuilder<'a> {
 
     fn dim_background(self, base_alpha: f32) -> gpui::Rgba {
         let alpha = adaptive_overlay_dim_alpha_for_opacity(base_alpha, self.background_opacity);
-        self.with_alpha(self.colors.background, alpha)
+        match self.colors.appearance {
+            ThemeAppearance::Dark => self.with_alpha(self.colors.background, alpha),
+            // Covering a light theme with its own background only washes it out,
+            // so shade it with the dark foreground instead.
+            ThemeAppearance::Light => {
+                self.with_alpha(self.colors.foreground, alpha * LIGHT_THEME_DIM_ALPHA_SCALE)
+            }
+        }
     }
 
     fn panel_background(self, base_alpha: f32) -> gpui::Rgba {
@@ -487,8 +682,8 @@ pub(crate) fn initial_window_background_appearance(
     config: &AppConfig,
 ) -> WindowBackgroundAppearance {
     resolve_background_appearance(
-        config.background_opacity,
-        config.background_blur,
+        config.effective_background_opacity(),
+        config.effective_background_blur(),
         BackgroundSupportContext::current(),
     )
     .appearance
@@ -505,8 +700,14 @@ pub struct TerminalView {
     theme_id: String,
     colors: TerminalColors,
     use_tabs: bool,
+    tab_bar_visibility: TabBarVisibility,
+    tab_bar_hover_revealed: bool,
+    inherit_tab_overrides: bool,
+    max_tabs: usize,
     inactive_tab_scrollback: Option<usize>,
     warn_on_quit_with_running_process: bool,
+    confirm_quit_multiple_tabs: bool,
+    on_shell_exit: ShellExitBehavior,
     tab_title: TabTitleConfig,
     tab_shell_integration: TabTitleShellIntegration,
     configured_working_dir: Option<String>,
@@ -515,26 +716,57 @@ pub struct TerminalView {
     config_fingerprint: Option<u64>,
     font_family: SharedString,
     base_font_size: f32,
-    font_size: Pixels,
+    profiles: Vec<ProfileConfig>,
+    custom_commands: Vec<CustomCommandConfig>,
     cursor_style: AppCursorStyle,
     cursor_blink: bool,
     cursor_blink_visible: bool,
+    cursor_contrast: bool,
     background_opacity: f32,
     background_blur: bool,
+    reduce_transparency: bool,
     background_support_context: BackgroundSupportContext,
     last_window_background_appearance: Option<WindowBackgroundAppearance>,
     warned_blur_unsupported_once: bool,
     padding_x: f32,
     padding_y: f32,
-    mouse_scroll_multiplier: f32,
+    max_content_columns: u16,
+    /// Extra left inset that centers a grid capped by `max_content_columns`.
+    content_margin_x: f32,
+    wheel_scroll_multiplier: f32,
+    trackpad_scroll_multiplier: f32,
+    invert_wheel_scroll: bool,
+    invert_trackpad_scroll: bool,
+    scroll_to_bottom_on_input: bool,
+    key_repeat_delay: u64,
+    key_repeat_rate: u32,
+    navigation_repeat: Option<CommandAction>,
+    navigation_repeat_token: u64,
+    show_wrap_indicator: bool,
+    render_whitespace: RenderWhitespace,
+    long_lines: LongLines,
+    long_line_limit: usize,
+    long_line_layout: long_lines::LongLineLayoutCache,
+    require_url_scheme: bool,
+    trim_trailing_whitespace_on_copy: bool,
+    double_click_selection: DoubleClickSelection,
+    sanitize_paste: bool,
+    macos_option_as_meta: bool,
+    bold_is_bright: bool,
+    builtin_box_drawing: bool,
+    minimum_contrast: f32,
     line_height: f32,
-    selection_anchor: Option<CellPos>,
-    selection_head: Option<CellPos>,
+    selection_anchor: Option<SelectionPos>,
+    selection_head: Option<SelectionPos>,
     selection_dragging: bool,
     selection_moved: bool,
+    selection_autoscroll_pointer: Option<gpui::Point<Pixels>>,
+    selection_autoscroll_animating: bool,
     hovered_link: Option<HoveredLink>,
     hovered_toast: Option<u64>,
     copied_toast_feedback: Option<(u64, Instant)>,
+    /// Colors being blended from after a theme switch, and when it started.
+    theme_transition: Option<(TerminalColors, TerminalColors, Instant)>,
     toast_animation_scheduled: bool,
     toast_manager: ToastManager,
     command_palette_open: bool,
@@ -544,6 +776,8 @@ pub struct TerminalView {
     command_palette_selected: usize,
     command_palette_scroll_handle: UniformListScrollHandle,
     tab_strip_scroll_handle: ScrollHandle,
+    cli_output: Option<CliOutput>,
+    cli_output_scroll_handle: ScrollHandle,
     command_palette_scroll_target_y: Option<f32>,
     command_palette_scroll_max_y: f32,
     command_palette_scroll_animating: bool,
@@ -560,17 +794,41 @@ pub struct TerminalView {
     tab_drag_autoscroll_animating: bool,
     terminal_scrollbar_visibility: TerminalScrollbarVisibility,
     terminal_scrollbar_style: TerminalScrollbarStyle,
+    scrollbar_prompt_markers: bool,
+    scrollbar_match_density: bool,
     terminal_scrollbar_visibility_controller: ScrollbarVisibilityController,
     terminal_scrollbar_animation_active: bool,
     terminal_scrollbar_drag: Option<TerminalScrollbarDragState>,
     terminal_scrollbar_marker_cache: TerminalScrollbarMarkerCache,
+    terminal_scrollbar_prompt_marker_cache: TerminalScrollbarMarkerCache,
+    /// Source of `TerminalTab::output_zones_revision`, unique across tabs.
+    next_output_zones_revision: u64,
+    /// Source of `TerminalTab::id`.
+    next_tab_id: u64,
     /// Cached cell dimensions
-    cell_size: Option<Size<Pixels>>,
+    /// Measured cell size and the font it was measured for.
+    cell_size: Option<(TabFont, Size<Pixels>)>,
     // Search state
     search_open: bool,
     search_input: InlineInputState,
+    /// Find/replace field shown under the search bar; `tab` moves between the two.
+    replace_input: InlineInputState,
+    search_replace_open: bool,
+    search_replace_focused: bool,
     search_state: SearchState,
     search_debounce_token: u64,
+    search_output_only: bool,
+    search_match_color: Option<gpui::Rgba>,
+    search_center_on_match: bool,
+    close_search_on_click: bool,
+    search_current_color: Option<gpui::Rgba>,
+    search_bar_style: SearchBarStyle,
+    // Error/warning navigation
+    diagnostic_engine: SearchEngine,
+    /// Engines for profiles that set their own `diagnostic_pattern` list.
+    profile_diagnostic_engines: Vec<(String, SearchEngine)>,
+    diagnostic_results: SearchResults,
+    diagnostics_visible: bool,
     // Pending clipboard write from OSC 52
     pending_clipboard: Option<String>,
     quit_prompt_in_flight: bool,
@@ -586,6 +844,23 @@ pub struct TerminalView {
 }
 
 impl TerminalView {
+    fn toast_durations_from_app_config(config: &AppConfig) -> ToastDurations {
+        ToastDurations {
+            info: Duration::from_millis(config.toast_duration_info),
+            success: Duration::from_millis(config.toast_duration_success),
+            warning: Duration::from_millis(config.toast_duration_warning),
+            error: Duration::from_millis(config.toast_duration_error),
+        }
+    }
+
+    #[cfg(target_os = "macos")]
+    fn update_channel_from_app_config(config: &AppConfig) -> UpdateChannel {
+        match config.update_channel {
+            config::UpdateChannel::Stable => UpdateChannel::Stable,
+            config::UpdateChannel::Prerelease => UpdateChannel::Prerelease,
+        }
+    }
+
     fn runtime_config_from_app_config(config: &AppConfig) -> TerminalRuntimeConfig {
         let working_dir_fallback = match config.working_dir_fallback {
             config::WorkingDirFallback::Home => RuntimeWorkingDirFallback::Home,
@@ -598,6 +873,8 @@ impl TerminalView {
             colorterm: config.colorterm.clone(),
             working_dir_fallback,
             scrollback_history: config.scrollback_history,
+            startup_command: config.startup_command.clone(),
+            flow_control: config.flow_control,
         }
     }
 
@@ -669,6 +946,46 @@ impl TerminalView {
         Some(Self::display_working_directory_for_prompt(&path))
     }
 
+    /// Font for a tab on `profile`, falling back to the root config for unset fields
+    /// and for profiles that no longer exist.
+    fn resolve_tab_font(&self, profile: Option<&str>) -> TabFont {
+        let profile = profile.and_then(|name| {
+            self.profiles
+                .iter()
+                .find(|candidate| candidate.name == name)
+        });
+        let family = profile
+            .and_then(|profile| profile.font_family.clone())
+            .map(SharedString::from)
+            .unwrap_or_else(|| self.font_family.clone());
+        let size = profile
+            .and_then(|profile| profile.font_size)
+            .unwrap_or(self.base_font_size);
+        TabFont::new(family, size)
+    }
+
+    /// Startup command for a new tab on `profile`. A profile's own setting wins,
+    /// including one that turns the root command off.
+    fn resolve_startup_command(&self, profile: Option<&str>) -> Option<String> {
+        profile
+            .and_then(|name| {
+                self.profiles
+                    .iter()
+                    .find(|candidate| candidate.name == name)
+            })
+            .and_then(|profile| profile.startup_command.clone())
+            .unwrap_or_else(|| self.terminal_runtime.startup_command.clone())
+    }
+
+    fn active_tab_font(&self) -> &TabFont {
+        &self.tabs[self.active_tab].font
+    }
+
+    fn active_tab_working_directory(&self) -> Option<PathBuf> {
+        let working_dir = self.tabs.get(self.active_tab)?.working_dir.as_deref()?;
+        Self::resolve_configured_working_directory(Some(working_dir))
+    }
+
     fn background_opacity_factor(&self) -> f32 {
         background_opacity_factor(self.background_opacity)
     }
@@ -681,7 +998,7 @@ impl TerminalView {
         scaled_chrome_alpha_for_opacity(base_alpha, self.background_opacity)
     }
 
-    fn effective_terminal_padding(&self) -> (f32, f32) {
+    fn configured_terminal_padding(&self) -> (f32, f32) {
         if self.active_terminal().alternate_screen_mode() {
             (0.0, 0.0)
         } else {
@@ -689,6 +1006,28 @@ impl TerminalView {
         }
     }
 
+    fn effective_terminal_padding(&self) -> (f32, f32) {
+        let (padding_x, padding_y) = self.configured_terminal_padding();
+        (padding_x + self.content_margin_x, padding_y)
+    }
+
+    /// Steps a theme switch animation; called once per frame.
+    fn advance_theme_transition(&mut self, window: &mut Window) {
+        let Some((from, to, started_at)) = self.theme_transition.take() else {
+            return;
+        };
+
+        let t = started_at.elapsed().as_secs_f32()
+            / Duration::from_millis(THEME_TRANSITION_MS).as_secs_f32();
+        if t >= 1.0 {
+            self.colors = to;
+        } else {
+            self.colors = from.lerp(&to, t);
+            self.theme_transition = Some((from, to, started_at));
+            window.request_animation_frame();
+        }
+    }
+
     fn overlay_style(&self) -> OverlayStyleBuilder<'_> {
         OverlayStyleBuilder::new(&self.colors, self.background_opacity)
     }
@@ -793,6 +1132,20 @@ impl TerminalView {
         self.terminal_scrollbar_marker_cache.clear();
     }
 
+    /// Grid lines of the prompts in the active tab that are still in scrollback, oldest first.
+    /// A command is reported after Enter, so its prompt sits one line above the output zone.
+    pub(super) fn terminal_scrollbar_prompt_lines(
+        &self,
+        screen_top_line: u64,
+        history_size: usize,
+    ) -> impl Iterator<Item = i32> + '_ {
+        self.tabs[self.active_tab]
+            .output_zones
+            .iter()
+            .map(move |zone| (zone.start as i64 - 1 - screen_top_line as i64) as i32)
+            .filter(move |&line| line >= -(history_size as i32))
+    }
+
     pub(super) fn mark_terminal_scrollbar_activity(&mut self, cx: &mut Context<Self>) {
         if self.terminal_scrollbar_mode() != ScrollbarVisibilityMode::OnScroll {
             return;
@@ -972,7 +1325,11 @@ impl TerminalView {
         let config_path = config::ensure_config_file();
         let config_fingerprint = config_path.as_ref().and_then(Self::config_fingerprint);
         let theme_id = config.theme.clone();
-        let colors = TerminalColors::from_theme(&config.theme, &config.colors);
+        let colors = TerminalColors::from_theme(
+            &config.theme,
+            &config.colors,
+            config.transparency_friendly_colors_active(),
+        );
         let base_font_size = config.font_size.clamp(MIN_FONT_SIZE, MAX_FONT_SIZE);
         let padding_x = config.padding_x.max(0.0);
         let padding_y = config.padding_y.max(0.0);
@@ -984,6 +1341,7 @@ impl TerminalView {
             explicit_prefix: tab_title.explicit_prefix.clone(),
         };
         let terminal_runtime = Self::runtime_config_from_app_config(&config);
+        let toast_durations = Self::toast_durations_from_app_config(&config);
         let predicted_prompt_cwd = Self::predicted_prompt_cwd(
             configured_working_dir.as_deref(),
             terminal_runtime.working_dir_fallback,
@@ -998,9 +1356,17 @@ impl TerminalView {
             Some(&terminal_runtime),
         )
         .expect("Failed to create terminal");
+        let mut search_state = SearchState::new();
+        search_state.set_trim_query(config.search_trim_query);
 
         let mut view = Self {
-            tabs: vec![TerminalTab::new(terminal, startup_predicted_title)],
+            tabs: vec![TerminalTab::new(
+                0,
+                terminal,
+                TabFont::new(config.font_family.clone().into(), base_font_size),
+                startup_predicted_title,
+                predicted_prompt_cwd,
+            )],
             active_tab: 0,
             renaming_tab: None,
             rename_input: InlineInputState::new(String::new()),
@@ -1009,8 +1375,14 @@ impl TerminalView {
             theme_id,
             colors,
             use_tabs: config.use_tabs,
+            tab_bar_visibility: config.tab_bar_visibility,
+            tab_bar_hover_revealed: false,
+            inherit_tab_overrides: config.inherit_tab_overrides,
+            max_tabs: config.max_tabs,
             inactive_tab_scrollback: config.inactive_tab_scrollback,
             warn_on_quit_with_running_process: config.warn_on_quit_with_running_process,
+            confirm_quit_multiple_tabs: config.confirm_quit_multiple_tabs,
+            on_shell_exit: config.on_shell_exit,
             tab_title,
             tab_shell_integration,
             configured_working_dir,
@@ -1019,26 +1391,55 @@ impl TerminalView {
             config_fingerprint,
             font_family: config.font_family.into(),
             base_font_size,
-            font_size: px(base_font_size),
+            profiles: config.profiles.clone(),
+            custom_commands: config.custom_commands.clone(),
             cursor_style: config.cursor_style,
             cursor_blink: config.cursor_blink,
             cursor_blink_visible: true,
-            background_opacity: config.background_opacity,
-            background_blur: config.background_blur,
+            cursor_contrast: config.cursor_contrast,
+            background_opacity: config.effective_background_opacity(),
+            background_blur: config.effective_background_blur(),
+            reduce_transparency: config.reduce_transparency_enabled(),
             background_support_context,
             last_window_background_appearance: None,
             warned_blur_unsupported_once: false,
             padding_x,
             padding_y,
-            mouse_scroll_multiplier: config.mouse_scroll_multiplier,
+            max_content_columns: config.max_content_columns,
+            content_margin_x: 0.0,
+            wheel_scroll_multiplier: config.effective_wheel_scroll_multiplier(),
+            trackpad_scroll_multiplier: config.effective_trackpad_scroll_multiplier(),
+            invert_wheel_scroll: config.invert_wheel_scroll,
+            invert_trackpad_scroll: config.invert_trackpad_scroll,
+            scroll_to_bottom_on_input: config.scroll_to_bottom_on_input,
+            key_repeat_delay: config.key_repeat_delay,
+            key_repeat_rate: config.key_repeat_rate,
+            navigation_repeat: None,
+            navigation_repeat_token: 0,
+            show_wrap_indicator: config.show_wrap_indicator,
+            render_whitespace: config.render_whitespace,
+            long_lines: config.long_lines,
+            long_line_limit: config.long_line_limit,
+            long_line_layout: long_lines::LongLineLayoutCache::default(),
+            require_url_scheme: config.require_url_scheme,
+            trim_trailing_whitespace_on_copy: config.trim_trailing_whitespace_on_copy,
+            double_click_selection: config.double_click_selection,
+            sanitize_paste: config.sanitize_paste,
+            macos_option_as_meta: config.macos_option_as_meta,
+            bold_is_bright: config.bold_is_bright,
+            builtin_box_drawing: config.builtin_box_drawing,
+            minimum_contrast: config.minimum_contrast,
             line_height: 1.4,
             selection_anchor: None,
             selection_head: None,
             selection_dragging: false,
             selection_moved: false,
+            selection_autoscroll_pointer: None,
+            selection_autoscroll_animating: false,
             hovered_link: None,
             hovered_toast: None,
             copied_toast_feedback: None,
+            theme_transition: None,
             toast_animation_scheduled: false,
             toast_manager: ToastManager::new(),
             command_palette_open: false,
@@ -1048,6 +1449,8 @@ impl TerminalView {
             command_palette_selected: 0,
             command_palette_scroll_handle: UniformListScrollHandle::new(),
             tab_strip_scroll_handle: ScrollHandle::new(),
+            cli_output: None,
+            cli_output_scroll_handle: ScrollHandle::new(),
             command_palette_scroll_target_y: None,
             command_palette_scroll_max_y: 0.0,
             command_palette_scroll_animating: false,
@@ -1064,15 +1467,33 @@ impl TerminalView {
             tab_drag_autoscroll_animating: false,
             terminal_scrollbar_visibility: config.terminal_scrollbar_visibility,
             terminal_scrollbar_style: config.terminal_scrollbar_style,
+            scrollbar_prompt_markers: config.scrollbar_prompt_markers,
+            scrollbar_match_density: config.scrollbar_match_density,
             terminal_scrollbar_visibility_controller: ScrollbarVisibilityController::default(),
             terminal_scrollbar_animation_active: false,
             terminal_scrollbar_drag: None,
             terminal_scrollbar_marker_cache: TerminalScrollbarMarkerCache::default(),
+            terminal_scrollbar_prompt_marker_cache: TerminalScrollbarMarkerCache::default(),
+            next_output_zones_revision: 0,
+            next_tab_id: 1,
             cell_size: None,
             search_open: false,
             search_input: InlineInputState::new(String::new()),
-            search_state: SearchState::new(),
+            replace_input: InlineInputState::new(String::new()),
+            search_replace_open: false,
+            search_replace_focused: false,
+            search_state,
             search_debounce_token: 0,
+            search_output_only: false,
+            search_match_color: config.search_match_color,
+            search_center_on_match: config.search_center_on_match,
+            close_search_on_click: config.close_search_on_click,
+            search_current_color: config.search_current_color,
+            search_bar_style: config.search_bar_style,
+            diagnostic_engine: Self::diagnostic_engine(&config.diagnostic_patterns),
+            profile_diagnostic_engines: Self::profile_diagnostic_engines(&config.profiles),
+            diagnostic_results: SearchResults::new(),
+            diagnostics_visible: false,
             pending_clipboard: None,
             quit_prompt_in_flight: false,
             allow_quit_without_prompt: false,
@@ -1086,10 +1507,17 @@ impl TerminalView {
             update_check_toast_id: None,
         };
         view.refresh_tab_title(0);
+        view.toast_manager.set_durations(toast_durations);
 
         #[cfg(target_os = "macos")]
         {
-            let updater = cx.new(|_| AutoUpdater::new(crate::APP_VERSION));
+            let channel = Self::update_channel_from_app_config(&config);
+            let skipped_version = config.skipped_update_version.clone();
+            let updater = cx.new(|_| {
+                let mut updater = AutoUpdater::new(crate::APP_VERSION, channel);
+                updater.set_skipped_version(skipped_version);
+                updater
+            });
             cx.observe(&updater, |_, _, cx| cx.notify()).detach();
             let weak = updater.downgrade();
             cx.spawn(async move |_this: WeakEntity<Self>, cx: &mut AsyncApp| {
@@ -1105,11 +1533,27 @@ impl TerminalView {
 
     fn apply_runtime_config(&mut self, config: AppConfig, cx: &mut Context<Self>) -> bool {
         keybindings::install_keybindings(cx, &config);
+        let colors = TerminalColors::from_theme(
+            &config.theme,
+            &config.colors,
+            config.transparency_friendly_colors_active(),
+        );
+        if self.theme_id != config.theme {
+            self.theme_transition = Some((self.colors.clone(), colors, Instant::now()));
+        } else {
+            self.theme_transition = None;
+            self.colors = colors;
+        }
         self.theme_id = config.theme.clone();
-        self.colors = TerminalColors::from_theme(&config.theme, &config.colors);
         self.use_tabs = config.use_tabs;
+        self.tab_bar_visibility = config.tab_bar_visibility;
+        self.tab_bar_hover_revealed = false;
+        self.inherit_tab_overrides = config.inherit_tab_overrides;
+        self.max_tabs = config.max_tabs;
         self.inactive_tab_scrollback = config.inactive_tab_scrollback;
         self.warn_on_quit_with_running_process = config.warn_on_quit_with_running_process;
+        self.confirm_quit_multiple_tabs = config.confirm_quit_multiple_tabs;
+        self.on_shell_exit = config.on_shell_exit;
         self.tab_title = config.tab_title.clone();
         self.tab_shell_integration = TabTitleShellIntegration {
             enabled: self.tab_title.shell_integration,
@@ -1119,16 +1563,55 @@ impl TerminalView {
         self.terminal_runtime = Self::runtime_config_from_app_config(&config);
         self.font_family = config.font_family.into();
         self.base_font_size = config.font_size.clamp(MIN_FONT_SIZE, MAX_FONT_SIZE);
-        self.font_size = px(self.base_font_size);
+        self.profiles = config.profiles.clone();
+        self.custom_commands = config.custom_commands.clone();
+        for index in 0..self.tabs.len() {
+            self.tabs[index].font = self.resolve_tab_font(self.tabs[index].profile.as_deref());
+        }
         self.cursor_style = config.cursor_style;
         self.cursor_blink = config.cursor_blink;
         self.cursor_blink_visible = true;
+        self.cursor_contrast = config.cursor_contrast;
         self.cell_size = None;
-        self.background_opacity = config.background_opacity;
-        self.background_blur = config.background_blur;
+        self.background_opacity = config.effective_background_opacity();
+        self.background_blur = config.effective_background_blur();
+        self.reduce_transparency = config.reduce_transparency_enabled();
         self.padding_x = config.padding_x.max(0.0);
         self.padding_y = config.padding_y.max(0.0);
-        self.mouse_scroll_multiplier = config.mouse_scroll_multiplier;
+        self.max_content_columns = config.max_content_columns;
+        self.wheel_scroll_multiplier = config.effective_wheel_scroll_multiplier();
+        self.trackpad_scroll_multiplier = config.effective_trackpad_scroll_multiplier();
+        self.invert_wheel_scroll = config.invert_wheel_scroll;
+        self.invert_trackpad_scroll = config.invert_trackpad_scroll;
+        self.scroll_to_bottom_on_input = config.scroll_to_bottom_on_input;
+        self.key_repeat_delay = config.key_repeat_delay;
+        self.key_repeat_rate = config.key_repeat_rate;
+        self.toast_manager
+            .set_durations(Self::toast_durations_from_app_config(&config));
+        termy_themes::set_theme_cache_capacity(config.theme_cache_size);
+        #[cfg(target_os = "macos")]
+        if let Some(updater) = self.auto_updater.as_ref() {
+            let channel = Self::update_channel_from_app_config(&config);
+            let skipped_version = config.skipped_update_version.clone();
+            updater.update(cx, |updater, _| {
+                updater.set_channel(channel);
+                updater.set_skipped_version(skipped_version);
+            });
+        }
+        self.stop_navigation_repeat();
+        self.show_wrap_indicator = config.show_wrap_indicator;
+        self.render_whitespace = config.render_whitespace;
+        self.long_lines = config.long_lines;
+        self.long_line_limit = config.long_line_limit;
+        self.long_line_layout.clear();
+        self.require_url_scheme = config.require_url_scheme;
+        self.trim_trailing_whitespace_on_copy = config.trim_trailing_whitespace_on_copy;
+        self.double_click_selection = config.double_click_selection;
+        self.sanitize_paste = config.sanitize_paste;
+        self.macos_option_as_meta = config.macos_option_as_meta;
+        self.bold_is_bright = config.bold_is_bright;
+        self.builtin_box_drawing = config.builtin_box_drawing;
+        self.minimum_contrast = config.minimum_contrast;
         if self.terminal_scrollbar_visibility != config.terminal_scrollbar_visibility {
             self.terminal_scrollbar_visibility = config.terminal_scrollbar_visibility;
             self.terminal_scrollbar_visibility_controller.reset();
@@ -1137,7 +1620,26 @@ impl TerminalView {
             self.clear_terminal_scrollbar_marker_cache();
         }
         self.terminal_scrollbar_style = config.terminal_scrollbar_style;
+        self.scrollbar_prompt_markers = config.scrollbar_prompt_markers;
+        if self.scrollbar_match_density != config.scrollbar_match_density {
+            self.scrollbar_match_density = config.scrollbar_match_density;
+            self.clear_terminal_scrollbar_marker_cache();
+        }
         self.command_palette_show_keybinds = config.command_palette_show_keybinds;
+        self.search_match_color = config.search_match_color;
+        self.search_center_on_match = config.search_center_on_match;
+        self.close_search_on_click = config.close_search_on_click;
+        self.search_current_color = config.search_current_color;
+        self.search_bar_style = config.search_bar_style;
+        if self.search_state.trims_query() != config.search_trim_query {
+            self.search_state.set_trim_query(config.search_trim_query);
+            if self.search_open {
+                self.perform_search();
+            }
+        }
+        self.diagnostic_engine = Self::diagnostic_engine(&config.diagnostic_patterns);
+        self.profile_diagnostic_engines = Self::profile_diagnostic_engines(&config.profiles);
+        self.clear_diagnostics();
 
         for index in 0..self.tabs.len() {
             self.refresh_tab_title(index);
@@ -1202,7 +1704,7 @@ impl TerminalView {
     }
 
     fn tick_cursor_blink(&mut self) -> bool {
-        if !self.cursor_blink {
+        if !self.cursor_blink && !self.terminal_cursor_blinks() {
             if self.cursor_blink_visible {
                 return false;
             }
@@ -1222,28 +1724,71 @@ impl TerminalView {
         !self.cursor_blink || !focused || self.cursor_blink_visible
     }
 
-    pub(super) fn terminal_cursor_style(&self) -> TerminalCursorStyle {
+    /// Like `cursor_visible_for_focus`, for the terminal cursor, whose blinking a
+    /// program can turn on or off with DECSCUSR.
+    pub(super) fn terminal_cursor_visible_for_focus(&self, focused: bool) -> bool {
+        !self.terminal_cursor_blinks() || !focused || self.cursor_blink_visible
+    }
+
+    fn terminal_cursor_blinks(&self) -> bool {
+        self.active_terminal()
+            .app_cursor_blinking()
+            .unwrap_or(self.cursor_blink)
+    }
+
+    /// The configured cursor style, unless the program in the active tab has
+    /// requested a shape with DECSCUSR. Any shape turns hollow while the
+    /// terminal is unfocused.
+    pub(super) fn terminal_cursor_style(&self, focused: bool) -> TerminalCursorStyle {
+        if !focused {
+            return TerminalCursorStyle::HollowBlock;
+        }
+        if let Some(shape) = self.active_terminal().app_cursor_shape() {
+            return shape;
+        }
+
         match self.cursor_style {
             AppCursorStyle::Line => TerminalCursorStyle::Line,
             AppCursorStyle::Block => TerminalCursorStyle::Block,
+            AppCursorStyle::Underline => TerminalCursorStyle::Underline,
+            AppCursorStyle::HollowBlock => TerminalCursorStyle::HollowBlock,
         }
     }
 
     fn process_terminal_events(&mut self, cx: &mut Context<Self>) -> bool {
         let mut should_redraw = false;
+        let mut exited_tabs = Vec::new();
         let active_tab = self.active_tab;
 
         for index in 0..self.tabs.len() {
             let events = self.tabs[index].terminal.process_events();
             for event in events {
                 match event {
-                    TerminalEvent::Wakeup | TerminalEvent::Bell | TerminalEvent::Exit => {
+                    TerminalEvent::Wakeup => {
+                        let tab = &mut self.tabs[index];
+                        tab.long_line_revision = tab.long_line_revision.wrapping_add(1);
                         if index == active_tab {
                             should_redraw = true;
+                        } else if !self.tabs[index].unseen_activity {
+                            self.tabs[index].unseen_activity = true;
+                            should_redraw |= self.show_tab_bar();
                         }
                     }
-                    TerminalEvent::Title(title) => {
-                        if self.apply_terminal_title(index, &title, cx)
+                    TerminalEvent::Bell => {
+                        if index == active_tab {
+                            should_redraw = true;
+                        } else if !self.tabs[index].bell_since_viewed {
+                            self.tabs[index].bell_since_viewed = true;
+                            should_redraw |= self.show_tab_bar();
+                        }
+                    }
+                    TerminalEvent::Exit => {
+                        if !exited_tabs.contains(&index) {
+                            exited_tabs.push(index);
+                        }
+                    }
+                    TerminalEvent::Title { title, cursor_line } => {
+                        if self.apply_terminal_title(index, &title, cursor_line, cx)
                             && (index == active_tab || self.show_tab_bar())
                         {
                             should_redraw = true;
@@ -1262,6 +1807,12 @@ impl TerminalView {
                     }
                 }
             }
+            self.prune_output_zones(index);
+        }
+
+        // Walk backwards so closing a tab doesn't shift the indices still to handle.
+        for index in exited_tabs.into_iter().rev() {
+            should_redraw |= self.handle_shell_exit(index, cx);
         }
 
         should_redraw
@@ -1272,6 +1823,14 @@ impl TerminalView {
         self.selection_head = None;
         self.selection_dragging = false;
         self.selection_moved = false;
+        self.selection_autoscroll_pointer = None;
+        // A search scoped to the selection falls back to the whole buffer.
+        if self.search_state.scope() != SearchScope::EntireBuffer {
+            self.search_state.set_scope(SearchScope::EntireBuffer);
+            if self.search_open {
+                self.perform_search();
+            }
+        }
     }
 
     fn clear_hovered_link(&mut self) -> bool {
@@ -1284,7 +1843,42 @@ impl TerminalView {
     }
 
     fn show_tab_bar(&self) -> bool {
-        self.use_tabs
+        if !self.use_tabs {
+            return false;
+        }
+
+        match self.tab_bar_visibility {
+            TabBarVisibility::Always => true,
+            TabBarVisibility::Auto => self.tabs.len() > 1,
+            TabBarVisibility::HoverReveal => self.tab_bar_hover_revealed,
+        }
+    }
+
+    /// A hover-reveal tab bar is drawn over the terminal so revealing it never resizes the grid.
+    fn tab_bar_overlays_terminal(&self) -> bool {
+        self.use_tabs && self.tab_bar_visibility == TabBarVisibility::HoverReveal
+    }
+
+    /// Reveals or hides a hover-reveal tab bar for a pointer at `pointer_y` in window
+    /// coordinates. Returns whether the visibility changed.
+    fn update_tab_bar_hover_reveal(&mut self, pointer_y: f32) -> bool {
+        if !self.tab_bar_overlays_terminal() || self.tab_drag.is_some() {
+            return false;
+        }
+
+        let reveal_limit = self.titlebar_height()
+            + if self.tab_bar_hover_revealed {
+                TABBAR_HEIGHT
+            } else {
+                TABBAR_REVEAL_EDGE
+            };
+        let revealed = pointer_y <= reveal_limit;
+        if revealed == self.tab_bar_hover_revealed {
+            return false;
+        }
+
+        self.tab_bar_hover_revealed = revealed;
+        true
     }
 
     fn active_context_title(&self) -> &str {
@@ -1400,6 +1994,27 @@ mod tests {
         assert!(low_opacity < high_opacity);
     }
 
+    #[test]
+    fn overlay_dim_shades_light_themes_with_the_foreground() {
+        let dark = TerminalColors::default();
+        let dim = OverlayStyleBuilder::new(&dark, 1.0).dim_background(0.78);
+        assert_eq!(
+            (dim.r, dim.g, dim.b),
+            (dark.background.r, dark.background.g, dark.background.b)
+        );
+
+        let light = TerminalColors {
+            appearance: ThemeAppearance::Light,
+            ..TerminalColors::default()
+        };
+        let dim = OverlayStyleBuilder::new(&light, 1.0).dim_background(0.78);
+        assert_eq!(
+            (dim.r, dim.g, dim.b),
+            (light.foreground.r, light.foreground.g, light.foreground.b)
+        );
+        assert!(dim.a < 0.78);
+    }
+
     #[test]
     fn overlay_panel_floor_applies_only_when_background_is_translucent() {
         let base = 0.64;
@@ -1,7 +1,11 @@
 use super::scrollbar as terminal_scrollbar;
 use super::tabs::TabDropMarkerSide;
 use super::*;
-use crate::ui::scrollbar::{self as ui_scrollbar, ScrollbarPaintStyle};
+use crate::ui::scrollbar::{self as ui_scrollbar, ScrollbarMarkerLayer, ScrollbarPaintStyle};
+use alacritty_terminal::{
+    index::{Column, Line},
+    term::cell::Cell,
+};
 
 impl Focusable for TerminalView {
     fn focus_handle(&self, _cx: &App) -> FocusHandle {
@@ -24,7 +28,8 @@ impl TerminalView {
         let marker_top_limit =
             terminal_scrollbar::marker_top_limit(layout.metrics.track_height, marker_height);
         let cache_key = TerminalScrollbarMarkerCacheKey {
-            results_revision: self.search_state.results_revision(),
+            source_revision: self.search_state.results_revision(),
+            screen_top_line: self.search_state.results_screen_top_line(),
             history_size: layout.history_size,
             viewport_rows: layout.viewport_rows,
             marker_top_limit_bucket: terminal_scrollbar::marker_top_limit_bucket(marker_top_limit),
@@ -38,16 +43,37 @@ impl TerminalView {
             } else {
                 let current_line = results.current().map(|current| current.line);
                 let new_marker_tops = rebuild_markers.then(|| {
-                    terminal_scrollbar::deduped_marker_tops(
-                        results
-                            .matches()
-                            .iter()
-                            .map(|search_match| search_match.line),
-                        layout.history_size,
-                        layout.viewport_rows,
-                        marker_height,
-                        marker_top_limit,
-                    )
+                    let mut tiers: [Vec<f32>; terminal_scrollbar::MARKER_DENSITY_TIERS] =
+                        Default::default();
+                    if self.scrollbar_match_density {
+                        let weighted = terminal_scrollbar::deduped_weighted_marker_tops(
+                            results.match_density(),
+                            layout.history_size,
+                            layout.viewport_rows,
+                            marker_height,
+                            marker_top_limit,
+                        );
+                        let max_weight = weighted.iter().map(|&(_, weight)| weight).max();
+                        for (top, weight) in weighted {
+                            let tier = terminal_scrollbar::marker_density_tier(
+                                weight,
+                                max_weight.unwrap_or(1),
+                            );
+                            tiers[tier].push(top);
+                        }
+                    } else {
+                        tiers[0] = terminal_scrollbar::deduped_marker_tops(
+                            results
+                                .matches()
+                                .iter()
+                                .map(|search_match| search_match.line),
+                            layout.history_size,
+                            layout.viewport_rows,
+                            marker_height,
+                            marker_top_limit,
+                        );
+                    }
+                    tiers
                 });
                 (false, current_line, new_marker_tops)
             }
@@ -58,8 +84,9 @@ impl TerminalView {
             return None;
         }
 
-        if let Some(marker_tops) = new_marker_tops {
+        if let Some([marker_tops, dense_marker_tops @ ..]) = new_marker_tops {
             self.terminal_scrollbar_marker_cache.marker_tops = marker_tops;
+            self.terminal_scrollbar_marker_cache.dense_marker_tops = dense_marker_tops;
             self.terminal_scrollbar_marker_cache.key = Some(cache_key);
         }
 
@@ -73,6 +100,42 @@ impl TerminalView {
         })
     }
 
+    fn refresh_terminal_scrollbar_prompt_marker_cache(
+        &mut self,
+        layout: terminal_scrollbar::TerminalScrollbarLayout,
+        marker_height: f32,
+    ) {
+        if !self.scrollbar_prompt_markers {
+            self.terminal_scrollbar_prompt_marker_cache.clear();
+            return;
+        }
+
+        let marker_height = marker_height.max(0.0);
+        let marker_top_limit =
+            terminal_scrollbar::marker_top_limit(layout.metrics.track_height, marker_height);
+        let screen_top_line = self.tabs[self.active_tab].terminal.screen_top_line();
+        let cache_key = TerminalScrollbarMarkerCacheKey {
+            source_revision: self.tabs[self.active_tab].output_zones_revision,
+            screen_top_line,
+            history_size: layout.history_size,
+            viewport_rows: layout.viewport_rows,
+            marker_top_limit_bucket: terminal_scrollbar::marker_top_limit_bucket(marker_top_limit),
+        };
+        if self.terminal_scrollbar_prompt_marker_cache.key.as_ref() == Some(&cache_key) {
+            return;
+        }
+
+        let marker_tops = terminal_scrollbar::deduped_marker_tops(
+            self.terminal_scrollbar_prompt_lines(screen_top_line, layout.history_size),
+            layout.history_size,
+            layout.viewport_rows,
+            marker_height,
+            marker_top_limit,
+        );
+        self.terminal_scrollbar_prompt_marker_cache.marker_tops = marker_tops;
+        self.terminal_scrollbar_prompt_marker_cache.key = Some(cache_key);
+    }
+
     fn render_terminal_scrollbar_overlay(
         &mut self,
         layout: terminal_scrollbar::TerminalScrollbarLayout,
@@ -91,6 +154,8 @@ impl TerminalView {
         }
         let overlay_style = self.overlay_style();
         let gutter_bg = overlay_style.panel_background(TERMINAL_SCROLLBAR_GUTTER_ALPHA);
+        let dense_marker_colors = TERMINAL_SCROLLBAR_DENSE_MARKER_ALPHAS
+            .map(|marker_alpha| self.scrollbar_color(overlay_style, marker_alpha));
         let style = ScrollbarPaintStyle {
             width: TERMINAL_SCROLLBAR_TRACK_WIDTH,
             track_radius: TERMINAL_SCROLLBAR_TRACK_RADIUS,
@@ -113,7 +178,30 @@ impl TerminalView {
 
         let current_marker_top =
             self.refresh_terminal_scrollbar_marker_cache(layout, TERMINAL_SCROLLBAR_MARKER_HEIGHT);
+        self.refresh_terminal_scrollbar_prompt_marker_cache(
+            layout,
+            TERMINAL_SCROLLBAR_MARKER_HEIGHT,
+        );
         let marker_tops = &self.terminal_scrollbar_marker_cache.marker_tops;
+        let mut prompt_marker_color = self.colors.ansi[4];
+        prompt_marker_color.a = TERMINAL_SCROLLBAR_PROMPT_MARKER_ALPHA;
+        let mut marker_layers = vec![
+            ScrollbarMarkerLayer {
+                tops: &self.terminal_scrollbar_prompt_marker_cache.marker_tops,
+                color: prompt_marker_color,
+            }
+            .scale_alpha(alpha),
+        ];
+        for (tops, color) in self
+            .terminal_scrollbar_marker_cache
+            .dense_marker_tops
+            .iter()
+            .zip(dense_marker_colors)
+        {
+            if !tops.is_empty() {
+                marker_layers.push(ScrollbarMarkerLayer { tops, color }.scale_alpha(alpha));
+            }
+        }
 
         Some(
             div()
@@ -136,6 +224,7 @@ impl TerminalView {
                             layout.metrics,
                             style,
                             self.terminal_scrollbar_visibility_controller.is_dragging(),
+                            &marker_layers,
                             marker_tops,
                             current_marker_top,
                             TERMINAL_SCROLLBAR_MARKER_HEIGHT,
@@ -145,6 +234,34 @@ impl TerminalView {
         )
     }
 
+    /// The real target of a hovered OSC 8 link, which the printed text may not
+    /// show at all. Pinned to the bottom left like a browser's status bar.
+    fn render_hovered_hyperlink_target(&self) -> Option<AnyElement> {
+        let link = self
+            .hovered_link
+            .as_ref()
+            .filter(|link| matches!(link.kind, LinkKind::Hyperlink { .. }))?;
+        let overlay_style = self.overlay_style();
+        Some(
+            div()
+                .absolute()
+                .bottom(px(8.0))
+                .left(px(8.0))
+                .max_w(px(480.0))
+                .px(px(8.0))
+                .py(px(3.0))
+                .rounded_md()
+                .bg(overlay_style.panel_background(SEARCH_BAR_BG_ALPHA))
+                .border_1()
+                .border_color(overlay_style.panel_cursor(OVERLAY_PANEL_BORDER_ALPHA))
+                .text_size(px(11.0))
+                .text_color(overlay_style.panel_foreground(OVERLAY_MUTED_TEXT_ALPHA))
+                .truncate()
+                .child(link.target.clone())
+                .into_any(),
+        )
+    }
+
     #[cfg(target_os = "macos")]
     fn render_update_banner(
         &mut self,
@@ -233,6 +350,9 @@ impl TerminalView {
                                     termy_toast::info("Downloading update...");
                                 }
                             }
+                            termy_auto_update_ui::UpdateBannerAction::Skip => {
+                                this.skip_available_update(cx);
+                            }
                             termy_auto_update_ui::UpdateBannerAction::CompleteInstall => {
                                 if let Some(ref weak) = updater_weak
                                     && let Some(entity) = weak.upgrade()
@@ -389,6 +509,17 @@ impl TerminalView {
             )
             .into_any_element()
     }
+
+    /// Configured highlight colors keep the tuned alpha so cell text stays readable.
+    fn search_highlight_bg(configured: Option<gpui::Rgba>, default: gpui::Hsla) -> gpui::Hsla {
+        configured
+            .map(|color| {
+                let mut hsla: gpui::Hsla = color.into();
+                hsla.a = default.a;
+                hsla
+            })
+            .unwrap_or(default)
+    }
 }
 
 impl Render for TerminalView {
@@ -398,8 +529,27 @@ impl Render for TerminalView {
             cx.write_to_clipboard(ClipboardItem::new_string(text));
         }
 
+        self.advance_theme_transition(window);
+
         self.toast_manager.ingest_pending();
-        self.toast_manager.tick_with_hovered(self.hovered_toast);
+        for event in termy_toast::drain_pending_toast_actions() {
+            self.handle_toast_action(&event, cx);
+        }
+        let expired_toasts = self.toast_manager.tick_with_hovered(self.hovered_toast);
+        if self
+            .copied_toast_feedback
+            .is_some_and(|(id, _)| expired_toasts.contains(&id))
+        {
+            self.copied_toast_feedback = None;
+        }
+        // A loading toast that timed out can no longer be updated in place.
+        #[cfg(target_os = "macos")]
+        if self
+            .update_check_toast_id
+            .is_some_and(|id| expired_toasts.contains(&id))
+        {
+            self.update_check_toast_id = None;
+        }
         if let Some((_, copied_at)) = self.copied_toast_feedback
             && copied_at.elapsed() >= Duration::from_millis(TOAST_COPY_FEEDBACK_MS)
         {
@@ -422,6 +572,21 @@ impl Render for TerminalView {
             .detach();
         }
 
+        if self.search_open {
+            self.follow_search_screen();
+        }
+        // Long scrollbacks are searched a chunk per frame to keep typing responsive.
+        if self.search_open && self.search_state.is_searching() {
+            let had_current = self.search_state.results().current().is_some();
+            self.continue_search();
+            if !had_current {
+                self.scroll_to_current_match(cx);
+            }
+            if self.search_state.is_searching() {
+                window.request_animation_frame();
+            }
+        }
+
         // Compute update banner state
         #[cfg(target_os = "macos")]
         let banner_state = self.auto_updater.as_ref().map(|e| e.read(cx).state.clone());
@@ -443,13 +608,15 @@ impl Render for TerminalView {
 
         let cell_size = self.calculate_cell_size(window, cx);
         let colors = self.colors.clone();
-        let font_family = self.font_family.clone();
-        let font_size = self.font_size;
+        let font_family = self.active_tab_font().family.clone();
+        let font_size = self.active_tab_font().size;
         self.sync_window_background_appearance(window);
         let effective_background_opacity = self.background_opacity_factor();
-        let (effective_padding_x, effective_padding_y) = self.effective_terminal_padding();
-
+        let bold_is_bright = self.bold_is_bright;
+        let minimum_contrast = self.minimum_contrast;
         self.sync_terminal_size(window, cell_size);
+        self.refresh_long_line_layout();
+        let (effective_padding_x, effective_padding_y) = self.effective_terminal_padding();
 
         // Collect cells to render - pre-allocate based on terminal size to avoid reallocations
         let terminal_size = self.active_terminal().size();
@@ -458,8 +625,9 @@ impl Render for TerminalView {
         let (cursor_col, cursor_row) = self.active_terminal().cursor_position();
         let terminal_cursor_active =
             !self.command_palette_open && self.renaming_tab.is_none() && !self.search_open;
-        let cursor_visible = terminal_cursor_active
-            && self.cursor_visible_for_focus(self.focus_handle.is_focused(window));
+        let terminal_focused = self.focus_handle.is_focused(window);
+        let cursor_visible =
+            terminal_cursor_active && self.terminal_cursor_visible_for_focus(terminal_focused);
 
         // Pre-compute search match info
         let search_active = self.search_open;
@@ -468,25 +636,64 @@ impl Render for TerminalView {
         } else {
             None
         };
+        let diagnostic_results = self.diagnostics_visible.then_some(&self.diagnostic_results);
         let mut terminal_display_offset = 0usize;
+        let long_line_layout = self.long_line_layout.layout();
+        let long_line_marker = self.long_line_marker();
 
         self.active_terminal().with_term(|term| {
             let content = term.renderable_content();
-            terminal_display_offset = content.display_offset;
-            let show_cursor = content.display_offset == 0 && cursor_visible;
-            for cell in content.display_iter {
-                let point = cell.point;
-                let cell_content = &cell.cell;
-                let term_line = point.line.0;
-                let Some(row) =
-                    Self::viewport_row_from_term_line(term_line, content.display_offset)
-                else {
-                    continue;
+            let display_offset = content.display_offset;
+            terminal_display_offset = display_offset;
+            let show_cursor = display_offset == 0 && cursor_visible;
+            let mut hyperlink_ids = HyperlinkIds::default();
+            let grid = term.grid();
+            // Each cell with its grid line, viewport row, column and whether it
+            // shows the long line cut marker. Cut long lines leave rows out, so
+            // those rows come from the layout rather than straight from the viewport.
+            let cells: Box<dyn Iterator<Item = (&Cell, i32, usize, usize, bool)> + '_> =
+                match long_line_layout {
+                    Some(layout) => {
+                        // Output may have changed the grid since the layout was computed.
+                        let lines = grid.topmost_line().0..=grid.bottommost_line().0;
+                        let last_col = grid.columns().saturating_sub(1);
+                        Box::new(
+                            layout
+                                .rows()
+                                .iter()
+                                .enumerate()
+                                .filter(move |(_, layout_row)| lines.contains(&layout_row.line))
+                                .flat_map(move |(row, layout_row)| {
+                                    let grid_row = &grid[Line(layout_row.line)];
+                                    let cut = layout_row.cut;
+                                    (0..=cut.unwrap_or(last_col).min(last_col)).map(move |col| {
+                                        let cell = &grid_row[Column(col)];
+                                        (cell, layout_row.line, row, col, cut == Some(col))
+                                    })
+                                }),
+                        )
+                    }
+                    None => Box::new(content.display_iter.filter_map(move |cell| {
+                        let term_line = cell.point.line.0;
+                        let row = Self::viewport_row_from_term_line(term_line, display_offset)?;
+                        Some((cell.cell, term_line, row, cell.point.column.0, false))
+                    })),
+                };
+            for (cell_content, term_line, row, col, marker) in cells {
+                let is_cursor = show_cursor && col == cursor_col && term_line == cursor_row as i32;
+
+                let c = if marker {
+                    long_line_marker
+                } else {
+                    cell_content.c
                 };
-                let col = point.column.0;
 
                 // Get foreground and background colors
-                let mut fg = colors.convert(cell_content.fg);
+                let mut fg = if bold_is_bright && cell_content.flags.contains(Flags::BOLD) {
+                    colors.convert_bold(cell_content.fg)
+                } else {
+                    colors.convert(cell_content.fg)
+                };
                 let mut bg = colors.convert(cell_content.bg);
                 if cell_content.flags.contains(Flags::INVERSE) {
                     std::mem::swap(&mut fg, &mut bg);
@@ -496,11 +703,10 @@ impl Render for TerminalView {
                     fg.g *= DIM_TEXT_FACTOR;
                     fg.b *= DIM_TEXT_FACTOR;
                 }
+                fg = ensure_minimum_contrast(fg, bg, colors.foreground, minimum_contrast);
                 bg.a *= effective_background_opacity;
 
-                let c = cell_content.c;
-                let is_cursor = show_cursor && col == cursor_col && row == cursor_row;
-                let selected = self.cell_is_selected(col, row);
+                let selected = self.cell_is_selected(col, term_line);
 
                 // Check search matches
                 let (search_current, search_match) = if let Some(results) = &search_results {
@@ -510,6 +716,14 @@ impl Render for TerminalView {
                 } else {
                     (false, false)
                 };
+                let (diagnostic_current, diagnostic_match) = match diagnostic_results {
+                    Some(results) if !search_current && !search_match => {
+                        let is_current = results.is_current_match(term_line, col);
+                        let is_any = results.is_any_match(term_line, col);
+                        (is_current, is_any && !is_current)
+                    }
+                    _ => (false, false),
+                };
 
                 cells_to_render.push(CellRenderInfo {
                     col,
@@ -518,13 +732,20 @@ impl Render for TerminalView {
                     fg: fg.into(),
                     bg: bg.into(),
                     bold: cell_content.flags.contains(Flags::BOLD),
-                    render_text: !cell_content.flags.intersects(
-                        Flags::WIDE_CHAR_SPACER | Flags::LEADING_WIDE_CHAR_SPACER | Flags::HIDDEN,
-                    ),
+                    render_text: marker
+                        || !cell_content.flags.intersects(
+                            Flags::WIDE_CHAR_SPACER
+                                | Flags::LEADING_WIDE_CHAR_SPACER
+                                | Flags::HIDDEN,
+                        ),
                     is_cursor,
                     selected,
                     search_current,
                     search_match,
+                    diagnostic_current,
+                    diagnostic_match,
+                    wrapped: cell_content.flags.contains(Flags::WRAPLINE),
+                    hyperlink: hyperlink_ids.id(cell_content),
                 });
             }
         });
@@ -538,7 +759,8 @@ impl Render for TerminalView {
         };
         let tab_strip_viewport_width = self.tab_strip_drag_viewport_width(window);
         self.sync_tab_display_widths_for_viewport(tab_strip_viewport_width);
-        let titlebar_left_padding = if cfg!(target_os = "macos") {
+        // Traffic lights are hidden in a macOS fullscreen space, so the strip can use the edge.
+        let titlebar_left_padding = if cfg!(target_os = "macos") && !window.is_fullscreen() {
             TOP_STRIP_MACOS_TRAFFIC_LIGHT_PADDING
         } else {
             TOP_STRIP_SIDE_PADDING
@@ -596,6 +818,10 @@ impl Render for TerminalView {
             .hovered_link
             .as_ref()
             .map(|link| (link.row, link.start_col, link.end_col));
+        let hovered_hyperlink = self.hovered_link.as_ref().and_then(|link| match link.kind {
+            LinkKind::Hyperlink { id } => Some(id),
+            _ => None,
+        });
         let active_tab_index = (self.active_tab < self.tabs.len()).then_some(self.active_tab);
         let tab_chrome_layout = show_tab_bar.then(|| {
             tab_chrome::compute_tab_chrome_layout(
@@ -817,6 +1043,27 @@ impl Render for TerminalView {
                     .children(tab_strokes.left_boundary.map(render_tab_stroke))
                     .children(tab_strokes.right_boundary.map(render_tab_stroke));
 
+                // Bell takes precedence over plain output so urgent tabs stand out.
+                let activity_color = if is_active {
+                    None
+                } else if tab.bell_since_viewed {
+                    Some(colors.ansi[11])
+                } else if tab.unseen_activity {
+                    Some(inactive_tab_text)
+                } else {
+                    None
+                };
+                let activity_indicator = activity_color.map(|color| {
+                    div()
+                        .absolute()
+                        .left(px(TAB_ACTIVITY_INDICATOR_INSET_X))
+                        .top(px((TAB_ITEM_HEIGHT - TAB_ACTIVITY_INDICATOR_SIZE) * 0.5))
+                        .w(px(TAB_ACTIVITY_INDICATOR_SIZE))
+                        .h(px(TAB_ACTIVITY_INDICATOR_SIZE))
+                        .rounded_full()
+                        .bg(color)
+                });
+
                 let drop_marker = tab_drop_marker_side.map(|side| {
                     let marker_x = match side {
                         TabDropMarkerSide::Left => 0.0,
@@ -866,6 +1113,7 @@ impl Render for TerminalView {
                             },
                         ))
                         .child(close_button)
+                        .children(activity_indicator)
                         .children(drop_marker),
                 );
             }
@@ -986,19 +1234,9 @@ impl Render for TerminalView {
         let banner_element: Option<AnyElement> = None;
         let terminal_surface_bg_hsla: gpui::Hsla = terminal_surface_bg.into();
 
-        // Search highlight colors tuned for strong contrast on dark terminal themes.
-        let search_match_bg = gpui::Hsla {
-            h: 0.14,
-            s: 0.92,
-            l: 0.62,
-            a: 0.62,
-        };
-        let search_current_bg = gpui::Hsla {
-            h: 0.09,
-            s: 0.98,
-            l: 0.56,
-            a: 0.86,
-        };
+        let search_match_bg = Self::search_highlight_bg(self.search_match_color, SEARCH_MATCH_BG);
+        let search_current_bg =
+            Self::search_highlight_bg(self.search_current_color, SEARCH_CURRENT_BG);
 
         let terminal_grid = TerminalGrid {
             cells: cells_to_render,
@@ -1012,10 +1250,21 @@ impl Render for TerminalView {
             selection_fg: selection_fg.into(),
             search_match_bg,
             search_current_bg,
+            diagnostic_match_bg: DIAGNOSTIC_MATCH_BG,
+            diagnostic_current_bg: DIAGNOSTIC_CURRENT_BG,
             hovered_link_range,
+            hovered_hyperlink,
             font_family: font_family.clone(),
             font_size,
-            cursor_style: self.terminal_cursor_style(),
+            cursor_style: self.terminal_cursor_style(terminal_focused),
+            cursor_contrast: self.cursor_contrast,
+            wrap_indicator: self.show_wrap_indicator.then(|| {
+                let mut color = colors.foreground;
+                color.a = WRAP_INDICATOR_ALPHA;
+                color.into()
+            }),
+            show_whitespace: self.render_whitespace == RenderWhitespace::All,
+            builtin_box_drawing: self.builtin_box_drawing,
         };
         if self.terminal_scrollbar_mode() == ui_scrollbar::ScrollbarVisibilityMode::OnScroll
             && !self.terminal_scrollbar_animation_active
@@ -1055,7 +1304,12 @@ impl Render for TerminalView {
         } else {
             None
         };
-        let key_context = if self.has_active_inline_input() {
+        let cli_output_overlay = self.render_cli_output_modal(cx);
+        let output_paused_overlay = self.render_output_paused_indicator(cx);
+        let hovered_hyperlink_overlay = self.render_hovered_hyperlink_target();
+        let key_context = if self.search_open {
+            "Terminal InlineInput Search"
+        } else if self.has_active_inline_input() {
             "Terminal InlineInput"
         } else {
             "Terminal"
@@ -1138,9 +1392,12 @@ impl Render for TerminalView {
             None
         } else {
             let mut container = div().flex().flex_col().gap(px(6.0));
-            for toast in self.toast_manager.active().iter() {
+            for toast in self.toast_manager.visible().iter() {
                 let toast_id = toast.id;
                 let toast_message = toast.message.clone();
+                let toast_lines = self
+                    .toast_manager
+                    .message_lines(toast, TOAST_MESSAGE_CHARS_PER_LINE);
                 let is_hovered = self.hovered_toast == Some(toast_id);
                 let is_copied = self
                     .copied_toast_feedback
@@ -1244,10 +1501,38 @@ impl Render for TerminalView {
                                 .child(
                                     div()
                                         .flex_1()
+                                        .flex()
+                                        .flex_col()
                                         .text_size(px(13.0))
                                         .text_color(text)
-                                        .child(toast_message.clone()),
+                                        .children(toast_lines.into_iter().map(|line| div().child(line))),
                                 )
+                                // Action buttons
+                                .children(toast.actions.iter().map(|action| {
+                                    let action_id = action.id.clone();
+                                    let mut action_bg = accent;
+                                    action_bg.a = 0.22 * opacity;
+                                    div()
+                                        .flex_none()
+                                        .rounded(px(6.0))
+                                        .px(px(8.0))
+                                        .py(px(4.0))
+                                        .text_size(px(11.0))
+                                        .text_color(accent)
+                                        .bg(action_bg)
+                                        .hover(|style| style.bg(border))
+                                        .cursor_pointer()
+                                        .on_mouse_down(
+                                            MouseButton::Left,
+                                            cx.listener(move |this, _event, _window, cx| {
+                                                this.toast_manager
+                                                    .activate_action(toast_id, &action_id);
+                                                cx.notify();
+                                                cx.stop_propagation();
+                                            }),
+                                        )
+                                        .child(action.label.clone())
+                                }))
                                 .child(
                                     div()
                                         .w(px(68.0))
@@ -1332,6 +1617,38 @@ impl Render for TerminalView {
                 );
             }
 
+            let overflow_count = self.toast_manager.overflow_count();
+            if overflow_count > 0 {
+                let mut bg = colors.background;
+                bg.a = 0.88;
+                let mut border = colors.foreground;
+                border.a = 0.08;
+                let mut text = colors.foreground;
+                text.a = 0.72;
+                container = container.child(
+                    div()
+                        .id(("toast", termy_toast::OVERFLOW_SUMMARY_ID))
+                        .w(px(320.0))
+                        .rounded_lg()
+                        .bg(bg)
+                        .border_1()
+                        .border_color(border)
+                        .shadow_md()
+                        .px(px(14.0))
+                        .py(px(8.0))
+                        .text_size(px(12.0))
+                        .text_color(text)
+                        .child(format!("+{} more", overflow_count))
+                        .on_mouse_move(cx.listener(|this, _event, _window, cx| {
+                            if this.hovered_toast != Some(termy_toast::OVERFLOW_SUMMARY_ID) {
+                                this.hovered_toast = Some(termy_toast::OVERFLOW_SUMMARY_ID);
+                                cx.notify();
+                            }
+                            cx.stop_propagation();
+                        })),
+                );
+            }
+
             Some(
                 div()
                     .id("toast-overlay")
@@ -1361,6 +1678,25 @@ impl Render for TerminalView {
         };
         let mut root_bg = colors.background;
         root_bg.a = self.scaled_background_alpha(root_bg.a);
+        let tabbar = div()
+            .id("tabbar")
+            .w_full()
+            .h(px(if show_tab_bar { TABBAR_HEIGHT } else { 0.0 }))
+            .flex_none()
+            .overflow_hidden()
+            .bg(tabbar_bg)
+            .child(tabs_row);
+        // The overlay is added after the terminal so it paints above the grid.
+        let (tabbar_element, tabbar_overlay) = if self.tab_bar_overlays_terminal() {
+            let overlay = tabbar
+                .absolute()
+                .top(px(titlebar_height))
+                .left_0()
+                .occlude();
+            (None, Some(overlay))
+        } else {
+            (Some(tabbar), None)
+        };
 
         div()
             .id("termy-root")
@@ -1380,17 +1716,13 @@ impl Render for TerminalView {
                     this.commit_tab_drag(cx);
                 }),
             )
+            .on_mouse_move(cx.listener(|this, event: &MouseMoveEvent, _window, cx| {
+                if this.update_tab_bar_hover_reveal(event.position.y.into()) {
+                    cx.notify();
+                }
+            }))
             .children(titlebar_element)
-            .child(
-                div()
-                    .id("tabbar")
-                    .w_full()
-                    .h(px(self.tab_bar_height()))
-                    .flex_none()
-                    .overflow_hidden()
-                    .bg(tabbar_bg)
-                    .child(tabs_row),
-            )
+            .children(tabbar_element)
             .children(banner_element)
             .child(
                 div()
@@ -1418,9 +1750,45 @@ impl Render for TerminalView {
                     .on_action(cx.listener(Self::handle_close_search_action))
                     .on_action(cx.listener(Self::handle_search_next_action))
                     .on_action(cx.listener(Self::handle_search_previous_action))
+                    .on_action(cx.listener(Self::handle_copy_match_and_next_action))
+                    .on_action(cx.listener(Self::handle_copy_search_matches_action))
+                    .on_action(cx.listener(Self::handle_search_first_match_action))
+                    .on_action(cx.listener(Self::handle_search_last_match_action))
+                    .on_action(cx.listener(Self::handle_search_next_page_action))
+                    .on_action(cx.listener(Self::handle_search_previous_page_action))
+                    .on_action(cx.listener(Self::handle_replace_current_action))
+                    .on_action(cx.listener(Self::handle_replace_all_action))
                     .on_action(cx.listener(Self::handle_toggle_search_case_sensitive_action))
                     .on_action(cx.listener(Self::handle_toggle_search_regex_action))
+                    .on_action(cx.listener(Self::handle_toggle_search_whole_word_action))
                     .on_action(cx.listener(Self::handle_install_cli_action))
+                    .on_action(cx.listener(Self::handle_copy_working_dir_action))
+                    .on_action(cx.listener(Self::handle_reveal_working_dir_action))
+                    .on_action(cx.listener(Self::handle_run_cli_command_action))
+                    .on_action(cx.listener(Self::handle_scroll_page_up_action))
+                    .on_action(cx.listener(Self::handle_scroll_page_down_action))
+                    .on_action(cx.listener(Self::handle_scroll_half_page_up_action))
+                    .on_action(cx.listener(Self::handle_scroll_half_page_down_action))
+                    .on_action(cx.listener(Self::handle_next_diagnostic_action))
+                    .on_action(cx.listener(Self::handle_previous_diagnostic_action))
+                    .on_action(cx.listener(Self::handle_increase_opacity_action))
+                    .on_action(cx.listener(Self::handle_decrease_opacity_action))
+                    .on_action(cx.listener(Self::handle_clear_tab_title_action))
+                    .on_action(cx.listener(Self::handle_toggle_fullscreen_action))
+                    .on_action(cx.listener(Self::handle_toggle_search_output_only_action))
+                    .on_action(cx.listener(Self::handle_insert_command_output_action))
+                    .on_action(cx.listener(Self::handle_save_scrollback_action))
+                    .on_action(cx.listener(Self::handle_copy_screen_action))
+                    .on_action(cx.listener(Self::handle_copy_scrollback_action))
+                    .on_action(cx.listener(Self::handle_switch_profile_action))
+                    .on_action(cx.listener(Self::handle_clear_all_highlights_action))
+                    .on_action(cx.listener(Self::handle_resume_output_action))
+                    .on_action(cx.listener(Self::handle_cycle_cursor_style_action))
+                    .on_action(cx.listener(Self::handle_toggle_shell_integration_action))
+                    .on_action(cx.listener(Self::handle_toggle_whitespace_action))
+                    .on_action(cx.listener(Self::handle_duplicate_tab_rerun_action))
+                    .on_action(cx.listener(Self::handle_reload_themes_action))
+                    .on_action(cx.listener(Self::handle_fold_long_lines_action))
                     .on_action(cx.listener(Self::handle_inline_backspace_action))
                     .on_action(cx.listener(Self::handle_inline_delete_action))
                     .on_action(cx.listener(Self::handle_inline_move_left_action))
@@ -1435,6 +1803,7 @@ impl Render for TerminalView {
                     .on_action(cx.listener(Self::handle_inline_delete_to_start_action))
                     .on_action(cx.listener(Self::handle_inline_delete_to_end_action))
                     .on_key_down(cx.listener(Self::handle_key_down))
+                    .on_key_up(cx.listener(Self::handle_key_up))
                     .on_scroll_wheel(cx.listener(Self::handle_terminal_scroll_wheel))
                     .on_mouse_down(MouseButton::Left, cx.listener(Self::handle_mouse_down))
                     .on_mouse_move(cx.listener(Self::handle_mouse_move))
@@ -1452,8 +1821,12 @@ impl Render for TerminalView {
                     .child(terminal_grid_layer)
                     .children(terminal_scrollbar_overlay)
                     .children(command_palette_overlay)
-                    .children(search_overlay),
+                    .children(output_paused_overlay)
+                    .children(hovered_hyperlink_overlay)
+                    .children(search_overlay)
+                    .children(cli_output_overlay),
             )
+            .children(tabbar_overlay)
             .children(toast_overlay)
     }
 }
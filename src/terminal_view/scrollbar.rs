@@ -1,6 +1,8 @@
 use crate::ui::scrollbar;
 
 const MARKER_TOP_LIMIT_BUCKET_STEP: f32 = 0.5;
+/// Brightness steps for density-weighted match markers, lowest first.
+pub(super) const MARKER_DENSITY_TIERS: usize = 3;
 
 #[derive(Clone, Copy, Debug)]
 pub(super) struct TerminalScrollbarLayout {
@@ -86,27 +88,93 @@ pub(super) fn deduped_marker_tops<I>(
 ) -> Vec<f32>
 where
     I: IntoIterator<Item = i32>,
+{
+    deduped_weighted_marker_tops(
+        lines.into_iter().map(|line| (line, 1)),
+        history_size,
+        viewport_rows,
+        marker_height,
+        marker_top_limit,
+    )
+    .into_iter()
+    .map(|(top, _)| top)
+    .collect()
+}
+
+/// Same as [`deduped_marker_tops`] for `(line, weight)` pairs, such as
+/// `SearchResults::match_density`. Each marker carries the summed weight of the
+/// lines collapsed into it.
+pub(super) fn deduped_weighted_marker_tops<I>(
+    lines: I,
+    history_size: usize,
+    viewport_rows: usize,
+    marker_height: f32,
+    marker_top_limit: f32,
+) -> Vec<(f32, usize)>
+where
+    I: IntoIterator<Item = (i32, usize)>,
 {
     let dedupe_bucket_size = marker_height.max(1.0);
-    let mut marker_tops = Vec::new();
+    let mut marker_tops: Vec<(f32, usize)> = Vec::new();
     let mut last_bucket = None;
     let mut previous_line = None;
 
-    for line in lines {
+    for (line, weight) in lines {
         debug_assert!(previous_line.map_or(true, |previous| previous <= line));
         previous_line = Some(line);
         let top = marker_top_for_line(line, history_size, viewport_rows, marker_top_limit);
         let bucket = (top / dedupe_bucket_size).round() as i32;
         if last_bucket == Some(bucket) {
+            if let Some((_, total)) = marker_tops.last_mut() {
+                *total = total.saturating_add(weight);
+            }
             continue;
         }
         last_bucket = Some(bucket);
-        marker_tops.push(top);
+        marker_tops.push((top, weight));
     }
 
     marker_tops
 }
 
+/// Brightness tier for a marker holding `weight` matches when the densest marker
+/// holds `max_weight`. Single matches stay on the base tier.
+pub(super) fn marker_density_tier(weight: usize, max_weight: usize) -> usize {
+    if max_weight <= 1 || weight <= 1 {
+        return 0;
+    }
+
+    let steps = MARKER_DENSITY_TIERS - 1;
+    ((weight - 1) * steps).div_ceil(max_weight - 1).min(steps)
+}
+
+/// Returns the line whose marker sits closest to `local_y`, as long as the click lands
+/// on the marker or within `slop` pixels of it.
+pub(super) fn line_near_marker<I>(
+    lines: I,
+    local_y: f32,
+    history_size: usize,
+    viewport_rows: usize,
+    marker_height: f32,
+    marker_top_limit: f32,
+    slop: f32,
+) -> Option<i32>
+where
+    I: IntoIterator<Item = i32>,
+{
+    let marker_height = marker_height.max(0.0);
+    let reach = marker_height / 2.0 + slop.max(0.0);
+    lines
+        .into_iter()
+        .map(|line| {
+            let top = marker_top_for_line(line, history_size, viewport_rows, marker_top_limit);
+            (line, (top + marker_height / 2.0 - local_y).abs())
+        })
+        .filter(|&(_, distance)| distance <= reach)
+        .min_by(|(_, left), (_, right)| left.total_cmp(right))
+        .map(|(line, _)| line)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -166,6 +234,41 @@ mod tests {
         assert!(from_iter.len() < lines.len());
     }
 
+    #[test]
+    fn weighted_marker_tops_sum_weights_of_collapsed_lines() {
+        let lines = [(-500, 2), (-499, 3), (-420, 1)];
+        let tops = deduped_weighted_marker_tops(lines, 1000, 50, 2.0, 100.0);
+        let weights: Vec<usize> = tops.iter().map(|&(_, weight)| weight).collect();
+
+        assert_eq!(weights, vec![5, 1]);
+        assert_eq!(
+            tops.iter().map(|&(top, _)| top).collect::<Vec<_>>(),
+            deduped_marker_tops(lines.map(|(line, _)| line), 1000, 50, 2.0, 100.0)
+        );
+    }
+
+    #[test]
+    fn marker_density_tier_scales_with_the_densest_marker() {
+        assert_eq!(marker_density_tier(1, 1), 0);
+        assert_eq!(marker_density_tier(1, 9), 0);
+        assert_eq!(marker_density_tier(3, 9), 1);
+        assert_eq!(marker_density_tier(6, 9), 2);
+        assert_eq!(marker_density_tier(9, 9), 2);
+    }
+
+    #[test]
+    fn line_near_marker_picks_closest_marker_within_slop() {
+        // 101 lines over a 100px track puts each marker top at `line + 100`.
+        let lines = [-100, -60, -58];
+        let near = |local_y| line_near_marker(lines, local_y, 100, 1, 2.0, 100.0, 3.0);
+
+        assert_eq!(near(1.0), Some(-100));
+        assert_eq!(near(44.0), Some(-58));
+        assert_eq!(near(41.5), Some(-60));
+        assert_eq!(near(20.0), None);
+        assert_eq!(line_near_marker([], 1.0, 100, 1, 2.0, 100.0, 3.0), None);
+    }
+
     #[test]
     fn marker_top_limit_bucket_quantizes_stably_around_boundary() {
         let boundary = MARKER_TOP_LIMIT_BUCKET_STEP * 2.5;
@@ -17,7 +17,20 @@ impl TerminalView {
 
         self.search_open = true;
         self.search_state.open();
-        self.search_input.clear();
+        if let Some((start, end)) = self.selection_range() {
+            self.search_state.set_scope(SearchScope::Range {
+                start_line: start.line,
+                end_line: end.line,
+            });
+        }
+        // Bring back the previous query, selected so typing replaces it.
+        self.search_input
+            .set_text(self.search_state.query().to_string());
+        self.search_input.select_all();
+        if !self.search_input.text().is_empty() {
+            self.perform_search();
+            self.scroll_to_current_match(cx);
+        }
         self.clear_terminal_scrollbar_marker_cache();
         self.reset_cursor_blink_phase();
         cx.notify();
@@ -29,12 +42,26 @@ impl TerminalView {
         }
 
         self.search_open = false;
+        self.search_replace_open = false;
+        self.search_replace_focused = false;
         self.search_state.close();
         self.search_input.clear();
         self.clear_terminal_scrollbar_marker_cache();
         cx.notify();
     }
 
+    /// Drops the search query, its matches and scrollbar markers, and any diagnostic
+    /// highlights. The search bar stays open with an empty input.
+    pub(super) fn clear_all_highlights(&mut self, cx: &mut Context<Self>) {
+        // Cancel a pending debounced search so it cannot bring the highlights back.
+        self.search_debounce_token = self.search_debounce_token.wrapping_add(1);
+        self.search_input.clear();
+        self.search_state.clear();
+        self.clear_terminal_scrollbar_marker_cache();
+        self.clear_diagnostics();
+        cx.notify();
+    }
+
     pub(super) fn search_next(&mut self, cx: &mut Context<Self>) {
         if !self.search_open || self.search_state.results().is_empty() {
             return;
@@ -55,40 +82,234 @@ impl TerminalView {
         cx.notify();
     }
 
-    fn scroll_to_current_match(&mut self, cx: &mut Context<Self>) {
-        let Some(current) = self.search_state.results().current() else {
+    pub(super) fn search_first_match(&mut self, cx: &mut Context<Self>) {
+        if !self.search_open || self.search_state.results().is_empty() {
+            return;
+        }
+
+        self.search_state.first_match();
+        self.scroll_to_current_match(cx);
+        cx.notify();
+    }
+
+    pub(super) fn search_last_match(&mut self, cx: &mut Context<Self>) {
+        if !self.search_open || self.search_state.results().is_empty() {
+            return;
+        }
+
+        self.search_state.last_match();
+        self.scroll_to_current_match(cx);
+        cx.notify();
+    }
+
+    /// Moves `SEARCH_PAGE_MATCHES` matches at once without wrapping past either end.
+    pub(super) fn search_page(&mut self, forward: bool, cx: &mut Context<Self>) {
+        if !self.search_open || self.search_state.results().is_empty() {
+            return;
+        }
+
+        if forward {
+            self.search_state.next_page(SEARCH_PAGE_MATCHES);
+        } else {
+            self.search_state.previous_page(SEARCH_PAGE_MATCHES);
+        }
+        self.scroll_to_current_match(cx);
+        cx.notify();
+    }
+
+    /// Copies the current match to the clipboard, then moves on to the next match.
+    pub(super) fn copy_match_and_next(&mut self, cx: &mut Context<Self>) {
+        if !self.search_open {
+            return;
+        }
+
+        let terminal = &self.tabs[self.active_tab].terminal;
+        let (display_offset, _) = terminal.scroll_state();
+        let search_state = &self.search_state;
+        let text = terminal.with_term(|term| {
+            let grid = term.grid();
+            search_state.current_match_text(|line| extract_line_text(grid, line, display_offset))
+        });
+        let Some(text) = text else {
             return;
         };
 
-        let active_tab = self.active_tab;
-        let terminal = &self.tabs[active_tab].terminal;
-        let size = terminal.size();
-        let rows = size.rows as i32;
+        cx.write_to_clipboard(ClipboardItem::new_string(text));
+        self.search_next(cx);
+    }
 
-        // Calculate required scroll to make match visible
-        let (display_offset, history_size) = terminal.scroll_state();
+    /// Copies every line holding a match, one per line, in scrollback order.
+    pub(super) fn copy_search_matches(&mut self, cx: &mut Context<Self>) {
+        if !self.search_open {
+            return;
+        }
+        self.finish_search();
+
+        let terminal = &self.tabs[self.active_tab].terminal;
+        let (display_offset, _) = terminal.scroll_state();
+        let results = self.search_state.results();
+        let lines = terminal.with_term(|term| {
+            let grid = term.grid();
+            results.matched_lines(|line| extract_line_text(grid, line, display_offset))
+        });
+        if lines.is_empty() {
+            termy_toast::warning("No search matches to copy");
+            return;
+        }
 
-        // Convert match line to viewport-relative position
-        // match.line is in Alacritty coordinates (negative = history)
-        let viewport_row = current.line + display_offset as i32;
+        let count = lines.len();
+        let text = lines
+            .into_iter()
+            .map(|(_, text)| text)
+            .collect::<Vec<_>>()
+            .join("\n");
+        self.pending_clipboard = Some(text);
+        termy_toast::success(format!(
+            "Copied {} matching {}",
+            count,
+            if count == 1 { "line" } else { "lines" }
+        ));
+        cx.notify();
+    }
 
-        // Check if match is in the current viewport
-        if viewport_row >= 0 && viewport_row < rows {
-            // Match is already visible
+    /// Replaces the current match, or with `all` every match, on the line the shell
+    /// is reading input from. The edit is typed into the PTY as cursor movement,
+    /// backspaces and the replacement text, so scrollback is never rewritten.
+    /// Only matches after the prompt, as reported by shell integration, are touched.
+    pub(super) fn replace_search_matches(&mut self, all: bool, cx: &mut Context<Self>) {
+        if !self.search_open {
+            return;
+        }
+        self.finish_search();
+        if self.search_state.results().is_empty() {
             return;
         }
 
-        // Scroll to make the match visible (centered if possible)
-        let target_offset = if current.line < 0 {
-            // Match is in scrollback history
-            (-current.line) as usize
+        let PromptInput::StartsAt {
+            line: input_line,
+            column: input_column,
+        } = self.tabs[self.active_tab].prompt_input
+        else {
+            termy_toast::warning("Replace needs shell integration to find the input line");
+            return;
+        };
+        let terminal = &self.tabs[self.active_tab].terminal;
+        let (cursor_col, cursor_row) = terminal.cursor_position();
+        let cursor_abs_line = terminal.screen_top_line() + cursor_row as u64;
+        // Wrapped input continues from the first column of the following lines.
+        let first_input_col = match cursor_abs_line.cmp(&input_line) {
+            std::cmp::Ordering::Less => None,
+            std::cmp::Ordering::Equal => Some(input_column),
+            std::cmp::Ordering::Greater => Some(0),
+        };
+        let cursor_line = cursor_row as i32;
+        let results = self.search_state.results();
+        let targets: Vec<(usize, usize)> = if all {
+            results
+                .matches()
+                .iter()
+                .filter(|m| m.line == cursor_line)
+                .map(|m| (m.start_col, m.end_col))
+                .collect()
         } else {
-            // Match is below viewport - scroll down
-            0
+            results
+                .current()
+                .filter(|m| m.line == cursor_line)
+                .map(|m| (m.start_col, m.end_col))
+                .into_iter()
+                .collect()
+        };
+        let Some(first_input_col) = first_input_col.filter(|_| !terminal.alternate_screen_mode())
+        else {
+            termy_toast::warning("Replace only works on matches on the live input line");
+            return;
+        };
+
+        let (display_offset, _) = terminal.scroll_state();
+        let Some((line_text, spacers)) = terminal.with_term(|term| {
+            let grid = term.grid();
+            let text = extract_line_text(grid, cursor_line, display_offset)?;
+            let row = &grid[alacritty_terminal::index::Line(cursor_line)];
+            let spacers: Vec<bool> = (0..grid.columns())
+                .map(|col| {
+                    row[alacritty_terminal::index::Column(col)]
+                        .flags
+                        .intersects(Flags::WIDE_CHAR_SPACER | Flags::LEADING_WIDE_CHAR_SPACER)
+                })
+                .collect();
+            Some((text, spacers))
+        }) else {
+            return;
+        };
+        // `extract_line_text` has one char per cell, so char counts are columns.
+        let edits: Vec<(usize, usize, String)> = targets
+            .into_iter()
+            .filter(|&(start, _)| line_text[..start].chars().count() >= first_input_col)
+            .filter_map(|(start, end)| {
+                let replacement = self.search_state.replace_current(&line_text, start)?;
+                Some((
+                    input_char_offset(&spacers, line_text[..start].chars().count()),
+                    input_char_offset(&spacers, line_text[..end].chars().count()),
+                    replacement,
+                ))
+            })
+            .collect();
+        if edits.is_empty() {
+            termy_toast::warning("Replace only works on matches on the live input line");
+            return;
+        }
+
+        let input = input_line_edit_bytes(
+            input_char_offset(&spacers, cursor_col),
+            &edits,
+            terminal.app_cursor_mode(),
+        );
+        self.write_terminal_input(&input, cx);
+        // The shell echoes the edit asynchronously, so search again once it settles.
+        self.handle_search_input_changed(cx);
+        cx.notify();
+    }
+
+    fn toggle_replace_focus(&mut self, cx: &mut Context<Self>) {
+        self.search_replace_open = true;
+        self.search_replace_focused = !self.search_replace_focused;
+        self.reset_cursor_blink_phase();
+        cx.notify();
+    }
+
+    pub(super) fn scroll_to_current_match(&mut self, cx: &mut Context<Self>) {
+        let Some((line, column)) = self
+            .search_state
+            .results()
+            .current()
+            .map(|current| (current.line, current.start_col))
+        else {
+            return;
         };
 
-        // Clamp to valid range
-        let target_offset = target_offset.min(history_size);
+        self.scroll_term_line_into_view(line, column, self.search_center_on_match, cx);
+    }
+
+    /// Scrolls so `line` (Alacritty coordinates, negative = history) is on screen,
+    /// optionally centering it in the viewport. When `column` is past a long line
+    /// cut, fold mode unfolds the line and truncate mode shows the cut row.
+    pub(super) fn scroll_term_line_into_view(
+        &mut self,
+        line: i32,
+        column: usize,
+        center: bool,
+        cx: &mut Context<Self>,
+    ) {
+        let line = self.reveal_long_line_cell(line, column);
+        let terminal = &self.tabs[self.active_tab].terminal;
+        let rows = terminal.size().rows as i32;
+        let (display_offset, history_size) = terminal.scroll_state();
+
+        let Some(target_offset) =
+            line_reveal_offset(line, rows, display_offset, history_size, center)
+        else {
+            return;
+        };
         let delta = target_offset as i32 - display_offset as i32;
 
         if delta != 0 {
@@ -107,30 +328,139 @@ impl TerminalView {
             return;
         }
 
-        let active_tab = self.active_tab;
-        let terminal = &self.tabs[active_tab].terminal;
-        let (display_offset, history_size) = terminal.scroll_state();
+        let terminal = &self.tabs[self.active_tab].terminal;
         let rows = terminal.size().rows as i32;
+        let (display_offset, history_size, screen_top_line) =
+            terminal.with_term_at_screen_top(|term, screen_top_line| {
+                let grid = term.grid();
+                (grid.display_offset(), grid.history_size(), screen_top_line)
+            });
 
         // Search range: from deepest history to current viewport
         let start_line = -(history_size as i32);
         let end_line = rows - 1;
+        let viewport_top = -(display_offset as i32);
+        // Keep the viewport steady while the query is edited. A fresh search starts
+        // from the newest match.
+        let anchor = self
+            .search_state
+            .results()
+            .current()
+            .map(|current| (current.line, current.start_col));
+        self.search_state.begin_search(
+            start_line,
+            end_line,
+            (viewport_top, viewport_top + rows - 1),
+            anchor,
+            screen_top_line,
+        );
+        self.continue_search();
+        if self.search_state.results().is_empty() {
+            self.clear_terminal_scrollbar_marker_cache();
+        }
+    }
+
+    /// Renumbers the search results for output that scrolled the screen since.
+    pub(super) fn follow_search_screen(&mut self) {
+        let terminal = &self.tabs[self.active_tab].terminal;
+        let search_state = &mut self.search_state;
+        terminal.with_term_at_screen_top(|term, screen_top_line| {
+            search_state.follow_screen(screen_top_line, term.grid().history_size());
+        });
+    }
+
+    /// Runs the search in flight to the end, for actions that need every match.
+    fn finish_search(&mut self) {
+        while self.search_state.is_searching() {
+            self.continue_search();
+        }
+    }
+
+    /// Searches the next `SEARCH_CHUNK_LINES` lines of the search in flight.
+    pub(super) fn continue_search(&mut self) {
+        let active_tab = self.active_tab;
+        let terminal = &self.tabs[active_tab].terminal;
         let search_state = &mut self.search_state;
+        // Without any shell-integration marks there is nothing to scope to, so search everything.
+        let output_zones = &self.tabs[active_tab].output_zones;
+        let output_only = self.search_output_only && !output_zones.is_empty();
 
         // Search directly against terminal grid lines to avoid duplicating
         // the entire visible + scrollback range in a temporary map.
-        terminal.with_term(|term| {
+        terminal.with_term_at_screen_top(|term, screen_top_line| {
             let grid = term.grid();
-            search_state.search(start_line, end_line, |line_idx| {
+            let display_offset = grid.display_offset();
+            search_state.follow_screen(screen_top_line, grid.history_size());
+            search_state.continue_search(SEARCH_CHUNK_LINES, |line_idx| {
+                let absolute_line = (screen_top_line as i64 + i64::from(line_idx)) as u64;
+                if output_only && !output_zones_contain(output_zones, absolute_line) {
+                    return None;
+                }
                 extract_line_text(grid, line_idx, display_offset)
             });
         });
+    }
 
-        // Start from the newest output match.
-        self.search_state.jump_to_last();
-        if self.search_state.results().is_empty() {
+    pub(super) fn toggle_search_output_only(&mut self, cx: &mut Context<Self>) {
+        self.search_output_only = !self.search_output_only;
+        if self.search_open {
+            self.perform_search();
+            self.scroll_to_current_match(cx);
             self.clear_terminal_scrollbar_marker_cache();
         }
+        cx.notify();
+    }
+
+    pub(super) fn begin_output_zone(&mut self, index: usize, start: u64) {
+        // Shell integration reports the command after Enter, so the cursor already
+        // sits on the first output line.
+        self.prune_output_zones(index);
+        let zones = &mut self.tabs[index].output_zones;
+        if zones.last().is_some_and(|zone| zone.end.is_none()) {
+            zones.pop();
+        }
+        zones.push(OutputZone { start, end: None });
+        if zones.len() > MAX_OUTPUT_ZONES {
+            zones.remove(0);
+        }
+        self.bump_output_zones_revision(index);
+    }
+
+    /// Drops zones whose lines have all been evicted from scrollback or cleared.
+    pub(super) fn prune_output_zones(&mut self, index: usize) {
+        let tab = &self.tabs[index];
+        let Some(first_end) = tab.output_zones.first().and_then(|zone| zone.end) else {
+            return;
+        };
+        let (_, history_size) = tab.terminal.scroll_state();
+        let oldest_line = tab
+            .terminal
+            .screen_top_line()
+            .saturating_sub(history_size as u64);
+        if first_end > oldest_line {
+            return;
+        }
+
+        self.tabs[index]
+            .output_zones
+            .retain(|zone| zone.end.is_none_or(|end| end > oldest_line));
+        self.bump_output_zones_revision(index);
+    }
+
+    pub(super) fn bump_output_zones_revision(&mut self, index: usize) {
+        self.next_output_zones_revision += 1;
+        self.tabs[index].output_zones_revision = self.next_output_zones_revision;
+    }
+
+    pub(super) fn end_output_zone(&mut self, index: usize, end: u64) {
+        if let Some(zone) = self.tabs[index]
+            .output_zones
+            .last_mut()
+            .filter(|zone| zone.end.is_none())
+        {
+            zone.end = Some(end.max(zone.start));
+            self.bump_output_zones_revision(index);
+        }
     }
 
     pub(super) fn handle_search_key_down(&mut self, key: &str, cx: &mut Context<Self>) {
@@ -138,11 +468,21 @@ impl TerminalView {
             "escape" => {
                 self.close_search(cx);
             }
+            "tab" => {
+                self.toggle_replace_focus(cx);
+            }
+            "enter" if self.search_replace_focused => {
+                self.replace_search_matches(false, cx);
+            }
             "enter" => {
-                self.search_next(cx);
+                if self.begin_navigation_repeat(CommandAction::SearchNext, cx) {
+                    self.search_next(cx);
+                }
             }
             "shift-enter" => {
-                self.search_previous(cx);
+                if self.begin_navigation_repeat(CommandAction::SearchPrevious, cx) {
+                    self.search_previous(cx);
+                }
             }
             _ => {
                 // Text input is handled elsewhere via InlineInput actions
@@ -181,26 +521,71 @@ impl TerminalView {
         .detach();
     }
 
+    fn search_bar_readable_target(background: gpui::Rgba) -> gpui::Rgba {
+        let luma = 0.2126 * background.r + 0.7152 * background.g + 0.0722 * background.b;
+        let level = if luma > 0.5 { 0.0 } else { 1.0 };
+        gpui::Rgba {
+            r: level,
+            g: level,
+            b: level,
+            a: 1.0,
+        }
+    }
+
     pub(super) fn render_search_bar(&self, cx: &mut Context<Self>) -> AnyElement {
-        let colors = &self.colors;
         let overlay_style = self.overlay_style();
-        let bar_bg = overlay_style.panel_background(SEARCH_BAR_BG_ALPHA);
-        let bar_border = overlay_style.panel_cursor(OVERLAY_PANEL_BORDER_ALPHA);
-        let input_bg = overlay_style.panel_background(SEARCH_INPUT_BG_ALPHA);
-        let counter_text = overlay_style.panel_foreground(SEARCH_COUNTER_TEXT_ALPHA);
-        let button_text = overlay_style.panel_foreground(SEARCH_BUTTON_TEXT_ALPHA);
+        let mut bar_bg = overlay_style.panel_background(SEARCH_BAR_BG_ALPHA);
+        let mut bar_border = overlay_style.panel_cursor(OVERLAY_PANEL_BORDER_ALPHA);
+        let mut input_bg = overlay_style.panel_background(SEARCH_INPUT_BG_ALPHA);
+        let mut counter_text = overlay_style.panel_foreground(SEARCH_COUNTER_TEXT_ALPHA);
+        let mut button_text = overlay_style.panel_foreground(SEARCH_BUTTON_TEXT_ALPHA);
+        if self.search_bar_style == SearchBarStyle::Accent {
+            bar_bg = blend_rgba(
+                bar_bg,
+                overlay_style.panel_cursor(SEARCH_BAR_BG_ALPHA),
+                SEARCH_BAR_ACCENT_BG_BLEND,
+            );
+            bar_border = overlay_style.panel_cursor(SEARCH_BAR_ACCENT_BORDER_ALPHA);
+            input_bg = blend_rgba(
+                input_bg,
+                overlay_style.panel_cursor(SEARCH_INPUT_BG_ALPHA),
+                SEARCH_BAR_ACCENT_INPUT_BLEND,
+            );
+            // The tint can pull the bar toward the text color, so push muted text back
+            // to a readable contrast.
+            let readable = Self::search_bar_readable_target(bar_bg);
+            counter_text = ensure_minimum_contrast(
+                counter_text,
+                bar_bg,
+                readable,
+                SEARCH_BAR_ACCENT_TEXT_MIN_CONTRAST,
+            );
+            button_text = ensure_minimum_contrast(
+                button_text,
+                bar_bg,
+                readable,
+                SEARCH_BAR_ACCENT_TEXT_MIN_CONTRAST,
+            );
+        }
         let button_hover_bg = overlay_style.panel_cursor(SEARCH_BUTTON_HOVER_BG_ALPHA);
 
         let (current, total) = self.search_state.results().position().unwrap_or((0, 0));
 
-        let counter_label = if total > 0 {
+        let counter_label = if let Some(progress) = self.search_state.search_progress() {
+            format!("Searching\u{2026} {}%", (progress * 100.0) as u32)
+        } else if total > 0 && self.search_output_only {
+            format!("{} of {} in output", current, total)
+        } else if total > 0 {
             format!("{} of {}", current, total)
         } else if self.search_input.text().is_empty() {
             String::new()
+        } else if self.search_state.query_is_blank() {
+            "Whitespace only".to_string()
         } else {
             "No matches".to_string()
         };
 
+        let in_selection = matches!(self.search_state.scope(), SearchScope::Range { .. });
         let has_error = self.search_state.error().is_some();
         let error_color = gpui::Rgba {
             r: 0.98,
@@ -209,12 +594,9 @@ impl TerminalView {
             a: 1.0,
         };
 
-        div()
+        let search_bar = div()
             .id("search-bar")
-            .absolute()
-            .top(px(12.0))
-            .right(px(12.0))
-            .w(px(SEARCH_BAR_WIDTH))
+            .w_full()
             .h(px(SEARCH_BAR_HEIGHT))
             .bg(bar_bg)
             .border_1()
@@ -226,28 +608,17 @@ impl TerminalView {
             .px(px(8.0))
             .gap(px(6.0))
             // Search input
-            .child(
+            .child(self.render_search_field(false, input_bg, counter_text, cx))
+            .children(in_selection.then(|| {
                 div()
-                    .flex_1()
-                    .h(px(24.0))
+                    .flex_none()
+                    .px(px(5.0))
                     .rounded_sm()
-                    .bg(input_bg)
-                    .px(px(6.0))
-                    .flex()
-                    .items_center()
-                    .child(self.render_inline_input_layer(
-                        Font::default(),
-                        px(12.0),
-                        colors.foreground.into(),
-                        {
-                            overlay_style
-                                .panel_cursor(SEARCH_INPUT_SELECTION_ALPHA)
-                                .into()
-                        },
-                        InlineInputAlignment::Left,
-                        cx,
-                    )),
-            )
+                    .bg(button_hover_bg)
+                    .text_size(px(10.0))
+                    .text_color(counter_text)
+                    .child("in selection")
+            }))
             // Match counter
             .child(
                 div()
@@ -331,13 +702,162 @@ impl TerminalView {
                         }),
                     )
                     .child("\u{00d7}"), // X
+            );
+
+        let replace_bar = self.search_replace_open.then(|| {
+            div()
+                .id("replace-bar")
+                .w_full()
+                .h(px(SEARCH_BAR_HEIGHT))
+                .bg(bar_bg)
+                .border_1()
+                .border_color(bar_border)
+                .rounded_md()
+                .shadow_lg()
+                .flex()
+                .items_center()
+                .px(px(8.0))
+                .gap(px(6.0))
+                .child(self.render_search_field(true, input_bg, counter_text, cx))
+                .child(Self::render_replace_button(
+                    "replace-current",
+                    "Replace",
+                    false,
+                    button_text,
+                    button_hover_bg,
+                    cx,
+                ))
+                .child(Self::render_replace_button(
+                    "replace-all",
+                    "All",
+                    true,
+                    button_text,
+                    button_hover_bg,
+                    cx,
+                ))
+        });
+
+        div()
+            // Clicks on the bars must not reach the terminal beneath them.
+            .on_mouse_down(
+                MouseButton::Left,
+                cx.listener(|_this, _event: &MouseDownEvent, _window, cx| {
+                    cx.stop_propagation();
+                }),
+            )
+            .absolute()
+            .top(px(12.0))
+            .right(px(12.0))
+            .w(px(SEARCH_BAR_WIDTH))
+            .flex()
+            .flex_col()
+            .gap(px(4.0))
+            .child(search_bar)
+            .children(replace_bar)
+            .into_any()
+    }
+
+    /// The find or replace field. Only the focused one hosts the inline input; the
+    /// other shows its text and takes focus when clicked.
+    fn render_search_field(
+        &self,
+        replace_field: bool,
+        input_bg: gpui::Rgba,
+        placeholder_color: gpui::Rgba,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let field = div()
+            .flex_1()
+            .h(px(24.0))
+            .rounded_sm()
+            .bg(input_bg)
+            .px(px(6.0))
+            .flex()
+            .items_center();
+        if replace_field == self.search_replace_focused {
+            return field
+                .child(self.render_inline_input_layer(
+                    Font::default(),
+                    px(12.0),
+                    self.colors.foreground.into(),
+                    {
+                        self.overlay_style()
+                            .panel_cursor(SEARCH_INPUT_SELECTION_ALPHA)
+                            .into()
+                    },
+                    InlineInputAlignment::Left,
+                    cx,
+                ))
+                .into_any();
+        }
+
+        let (text, placeholder) = if replace_field {
+            (self.replace_input.text(), "Replace")
+        } else {
+            (self.search_input.text(), "Find")
+        };
+        field
+            .overflow_hidden()
+            .whitespace_nowrap()
+            .text_size(px(12.0))
+            .text_color(if text.is_empty() {
+                placeholder_color
+            } else {
+                self.colors.foreground.into()
+            })
+            .cursor(gpui::CursorStyle::IBeam)
+            .on_mouse_down(
+                MouseButton::Left,
+                cx.listener(|this, _event: &MouseDownEvent, _window, cx| {
+                    cx.stop_propagation();
+                    this.toggle_replace_focus(cx);
+                }),
             )
+            .child(if text.is_empty() { placeholder } else { text }.to_string())
             .into_any()
     }
+
+    fn render_replace_button(
+        id: &'static str,
+        label: &'static str,
+        all: bool,
+        text_color: gpui::Rgba,
+        hover_bg: gpui::Rgba,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        div()
+            .id(id)
+            .h(px(22.0))
+            .px(px(6.0))
+            .rounded_sm()
+            .flex()
+            .items_center()
+            .text_size(px(11.0))
+            .text_color(text_color)
+            .hover(move |style| style.bg(hover_bg))
+            .cursor_pointer()
+            .on_mouse_down(
+                MouseButton::Left,
+                cx.listener(move |this, _event, _window, cx| {
+                    this.replace_search_matches(all, cx);
+                    cx.stop_propagation();
+                }),
+            )
+            .child(label)
+    }
+}
+
+/// Whether an absolute grid line falls inside any recorded command output.
+/// Once scrollback is full, old lines are dropped and older zones drift by that
+/// amount; recent zones, the ones usually searched, stay accurate.
+fn output_zones_contain(zones: &[OutputZone], absolute_line: u64) -> bool {
+    zones
+        .iter()
+        .any(|zone| absolute_line >= zone.start && zone.end.is_none_or(|end| absolute_line < end))
 }
 
 /// Extract text from a terminal grid line
-fn extract_line_text(
+pub(super) fn extract_line_text(
     grid: &alacritty_terminal::grid::Grid<alacritty_terminal::term::cell::Cell>,
     line_idx: i32,
     _display_offset: usize,
@@ -370,3 +890,145 @@ fn extract_line_text(
 
     Some(text)
 }
+
+/// Cursor steps from the start of a line to `column`. Wide characters fill two
+/// cells but the shell moves over them in one step.
+fn input_char_offset(spacers: &[bool], column: usize) -> usize {
+    let column_spacers = spacers
+        .iter()
+        .take(column)
+        .filter(|spacer| **spacer)
+        .count();
+    column - column_spacers
+}
+
+/// Keystrokes that apply `edits` (start, end, replacement) to the shell's input
+/// line, starting with the cursor at `cursor`. Positions count characters, not
+/// cells. Edits must be in order; they are applied right to left so earlier
+/// positions stay valid. `app_cursor` sends arrows as DECCKM expects.
+fn input_line_edit_bytes(
+    cursor: usize,
+    edits: &[(usize, usize, String)],
+    app_cursor: bool,
+) -> Vec<u8> {
+    const BACKSPACE: u8 = 0x7f;
+    let (cursor_right, cursor_left): (&[u8], &[u8]) = if app_cursor {
+        (b"\x1bOC", b"\x1bOD")
+    } else {
+        (b"\x1b[C", b"\x1b[D")
+    };
+
+    let mut bytes = Vec::new();
+    let mut cursor = cursor;
+    for (start, end, replacement) in edits.iter().rev() {
+        if *end > cursor {
+            bytes.extend(cursor_right.repeat(end - cursor));
+        } else {
+            bytes.extend(cursor_left.repeat(cursor - end));
+        }
+        bytes.extend(std::iter::repeat_n(BACKSPACE, end - start));
+
+        // A newline would run the half-edited command.
+        let replacement: String = replacement.chars().filter(|c| !c.is_control()).collect();
+        bytes.extend_from_slice(replacement.as_bytes());
+        cursor = start + replacement.chars().count();
+    }
+    bytes
+}
+
+/// Display offset that brings `line` on screen, or `None` when it is already
+/// visible. Without `center` the line lands on the top row.
+fn line_reveal_offset(
+    line: i32,
+    rows: i32,
+    display_offset: usize,
+    history_size: usize,
+    center: bool,
+) -> Option<usize> {
+    let viewport_row = line + display_offset as i32;
+    if viewport_row >= 0 && viewport_row < rows {
+        return None;
+    }
+
+    let target_row = if center { rows / 2 } else { 0 };
+    let target_offset = (target_row - line).max(0) as usize;
+    Some(target_offset.min(history_size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn output_zones_cover_finished_and_running_commands() {
+        let zones = [
+            OutputZone {
+                start: 3,
+                end: Some(6),
+            },
+            OutputZone {
+                start: 10,
+                end: None,
+            },
+        ];
+
+        assert!(!output_zones_contain(&zones, 2));
+        assert!(output_zones_contain(&zones, 3));
+        assert!(output_zones_contain(&zones, 5));
+        assert!(!output_zones_contain(&zones, 6));
+        assert!(!output_zones_contain(&zones, 9));
+        assert!(output_zones_contain(&zones, 10));
+        assert!(output_zones_contain(&zones, 500));
+        assert!(!output_zones_contain(&[], 0));
+    }
+
+    #[test]
+    fn input_line_edits_walk_the_cursor_right_to_left() {
+        let edits = [(2, 5, "ab".to_string()), (7, 8, "xyz".to_string())];
+
+        let mut expected = b"\x1b[C".repeat(2);
+        expected.push(0x7f);
+        expected.extend_from_slice(b"xyz");
+        expected.extend(b"\x1b[D".repeat(5));
+        expected.extend([0x7f; 3]);
+        expected.extend_from_slice(b"ab");
+        assert_eq!(input_line_edit_bytes(6, &edits, false), expected);
+
+        let multiline = [(0, 1, "a\nb".to_string())];
+        assert_eq!(
+            input_line_edit_bytes(1, &multiline, false),
+            b"\x7fab".to_vec()
+        );
+
+        let app_cursor = [(0, 1, "a".to_string())];
+        assert_eq!(
+            input_line_edit_bytes(3, &app_cursor, true),
+            b"\x1bOD\x1bOD\x7fa".to_vec()
+        );
+    }
+
+    #[test]
+    fn input_char_offset_steps_over_wide_characters_once() {
+        // "漢x": the wide char's spacer sits in column 1.
+        let spacers = [false, true, false, false];
+        assert_eq!(input_char_offset(&spacers, 0), 0);
+        assert_eq!(input_char_offset(&spacers, 2), 1);
+        assert_eq!(input_char_offset(&spacers, 4), 3);
+    }
+
+    #[test]
+    fn line_reveal_offset_centers_match_within_scrollback_bounds() {
+        // Already visible: no scroll.
+        assert_eq!(line_reveal_offset(5, 24, 0, 100, true), None);
+
+        assert_eq!(line_reveal_offset(-50, 24, 0, 100, false), Some(50));
+        assert_eq!(line_reveal_offset(-50, 24, 0, 100, true), Some(62));
+
+        // Near the oldest history the offset stops at the scrollback size.
+        assert_eq!(line_reveal_offset(-95, 24, 0, 100, true), Some(100));
+
+        // Below the viewport near the live screen, centering can't go past the bottom.
+        assert_eq!(line_reveal_offset(20, 24, 40, 100, true), Some(0));
+        assert_eq!(line_reveal_offset(-10, 24, 40, 100, true), Some(22));
+    }
+}
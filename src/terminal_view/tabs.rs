@@ -360,28 +360,98 @@ impl TerminalView {
         true
     }
 
+    /// `max_tabs = 0` means tabs are unlimited.
+    pub(super) fn tab_limit_reached(open_tabs: usize, max_tabs: usize) -> bool {
+        max_tabs > 0 && open_tabs >= max_tabs
+    }
+
     pub(super) fn add_tab(&mut self, cx: &mut Context<Self>) {
+        let working_dir = self.configured_working_dir.clone();
+        let (profile, _) = self.new_tab_overrides();
+        let runtime = TerminalRuntimeConfig {
+            startup_command: self.resolve_startup_command(profile.as_deref()),
+            ..self.terminal_runtime.clone()
+        };
+        self.open_tab(working_dir.as_deref(), &runtime, cx);
+    }
+
+    /// Profile and font a new tab starts with. Profile and zoom are per-tab session
+    /// state; the config font is the persisted baseline.
+    fn new_tab_overrides(&self) -> (Option<String>, TabFont) {
+        self.tabs
+            .get(self.active_tab)
+            .filter(|_| self.inherit_tab_overrides)
+            .map(|tab| (tab.profile.clone(), tab.font.clone()))
+            .unwrap_or_else(|| (None, self.resolve_tab_font(None)))
+    }
+
+    /// Opens a tab in the active tab's directory and types its last command into
+    /// the new shell. Without a recorded command this is a plain duplicate.
+    pub(super) fn duplicate_tab_rerun(&mut self, cx: &mut Context<Self>) {
+        let Some(tab) = self.tabs.get(self.active_tab) else {
+            return;
+        };
+        // The source tab already ran the configured startup command.
+        let command = tab.last_command.clone();
+        self.open_tab_in_active_dir(command, cx);
+    }
+
+    /// Opens a tab in the active tab's directory whose shell runs `command` instead
+    /// of the configured startup command.
+    pub(super) fn open_tab_running(&mut self, command: String, cx: &mut Context<Self>) {
+        self.open_tab_in_active_dir(Some(command), cx);
+    }
+
+    /// Opens a tab in the active tab's directory, falling back to the configured
+    /// one, with `startup_command` in place of the configured startup command.
+    fn open_tab_in_active_dir(&mut self, startup_command: Option<String>, cx: &mut Context<Self>) {
+        let working_dir = self
+            .tabs
+            .get(self.active_tab)
+            .and_then(|tab| tab.working_dir.clone())
+            .or_else(|| self.configured_working_dir.clone());
+        let runtime = TerminalRuntimeConfig {
+            startup_command,
+            ..self.terminal_runtime.clone()
+        };
+        self.open_tab(working_dir.as_deref(), &runtime, cx);
+    }
+
+    fn open_tab(
+        &mut self,
+        working_dir: Option<&str>,
+        runtime: &TerminalRuntimeConfig,
+        cx: &mut Context<Self>,
+    ) {
         if !self.use_tabs {
             return;
         }
 
+        if Self::tab_limit_reached(self.tabs.len(), self.max_tabs) {
+            termy_toast::warning(format!("Tab limit reached ({})", self.max_tabs));
+            return;
+        }
+
         let terminal = Terminal::new(
             TerminalSize::default(),
-            self.configured_working_dir.as_deref(),
+            working_dir,
             Some(self.event_wakeup_tx.clone()),
             Some(&self.tab_shell_integration),
-            Some(&self.terminal_runtime),
+            Some(runtime),
         )
         .expect("Failed to create terminal tab");
 
-        let predicted_prompt_cwd = Self::predicted_prompt_cwd(
-            self.configured_working_dir.as_deref(),
-            self.terminal_runtime.working_dir_fallback,
-        );
+        let predicted_prompt_cwd =
+            Self::predicted_prompt_cwd(working_dir, runtime.working_dir_fallback);
         let predicted_title =
             Self::predicted_prompt_seed_title(&self.tab_title, predicted_prompt_cwd.as_deref());
 
-        self.tabs.push(TerminalTab::new(terminal, predicted_title));
+        let (profile, font) = self.new_tab_overrides();
+        let id = self.next_tab_id;
+        self.next_tab_id += 1;
+        let mut tab = TerminalTab::new(id, terminal, font, predicted_title, predicted_prompt_cwd);
+        tab.profile = profile;
+        self.tabs.push(tab);
         self.active_tab = self.tabs.len() - 1;
         self.refresh_tab_title(self.active_tab);
         self.renaming_tab = None;
@@ -391,10 +461,16 @@ impl TerminalView {
         self.hovered_tab_close = None;
         self.finish_tab_drag();
         self.clear_selection();
+        self.clear_diagnostics();
         self.scroll_active_tab_into_view();
         cx.notify();
     }
 
+    /// Current index of the tab with `id`, for work that outlives reordering or closing tabs.
+    pub(super) fn tab_index_by_id(&self, id: u64) -> Option<usize> {
+        self.tabs.iter().position(|tab| tab.id == id)
+    }
+
     pub(super) fn close_tab(&mut self, index: usize, cx: &mut Context<Self>) {
         if self.tabs.len() <= 1 || index >= self.tabs.len() {
             return;
@@ -433,6 +509,7 @@ impl TerminalView {
         self.finish_tab_drag();
 
         self.clear_selection();
+        self.clear_diagnostics();
         self.scroll_active_tab_into_view();
         cx.notify();
     }
@@ -441,6 +518,117 @@ impl TerminalView {
         self.close_tab(self.active_tab, cx);
     }
 
+    pub(super) fn handle_shell_exit(&mut self, index: usize, cx: &mut Context<Self>) -> bool {
+        if index >= self.tabs.len() || self.tabs[index].exited {
+            return false;
+        }
+
+        match self.on_shell_exit {
+            ShellExitBehavior::Close => self.close_exited_tab(index, cx),
+            ShellExitBehavior::Hold => self.hold_exited_tab(index),
+            ShellExitBehavior::Restart => {
+                if Self::register_shell_restart(
+                    &mut self.tabs[index].recent_restarts,
+                    Instant::now(),
+                ) {
+                    self.restart_tab_shell(index);
+                } else {
+                    termy_toast::warning("Shell keeps exiting; stopped restarting it");
+                    self.hold_exited_tab(index);
+                }
+            }
+        }
+
+        true
+    }
+
+    pub(super) fn close_exited_tab(&mut self, index: usize, cx: &mut Context<Self>) {
+        if self.tabs.len() > 1 {
+            self.close_tab(index, cx);
+            return;
+        }
+
+        // Nothing left to show once the last shell is gone.
+        self.allow_quit_without_prompt = true;
+        cx.quit();
+    }
+
+    fn hold_exited_tab(&mut self, index: usize) {
+        let tab = &mut self.tabs[index];
+        tab.exited = true;
+        tab.running_process = false;
+        tab.output_paused = false;
+        tab.terminal
+            .write_output(SHELL_EXIT_HOLD_MESSAGE.as_bytes());
+    }
+
+    fn restart_tab_shell(&mut self, index: usize) {
+        // A restarted shell replaces one that already ran the startup command; running
+        // it again could loop when the command itself is what exits the shell.
+        let runtime = TerminalRuntimeConfig {
+            startup_command: None,
+            ..self.terminal_runtime.clone()
+        };
+        // Start where the user last was rather than where the old shell began.
+        let working_dir = self.tabs[index]
+            .working_dir
+            .clone()
+            .or_else(|| self.configured_working_dir.clone());
+        let terminal = match Terminal::new(
+            self.tabs[index].terminal.size(),
+            working_dir.as_deref(),
+            Some(self.event_wakeup_tx.clone()),
+            Some(&self.tab_shell_integration),
+            Some(&runtime),
+        ) {
+            Ok(terminal) => terminal,
+            Err(error) => {
+                termy_toast::error(format!("Failed to restart shell: {}", error));
+                self.hold_exited_tab(index);
+                return;
+            }
+        };
+
+        if index != self.active_tab
+            && let Some(inactive_scrollback) = self.inactive_tab_scrollback
+        {
+            terminal.set_scrollback_history(inactive_scrollback);
+        }
+
+        let tab = &mut self.tabs[index];
+        tab.terminal = terminal;
+        tab.output_paused = false;
+        tab.prompt_input = PromptInput::Unknown;
+        tab.shell_title = None;
+        tab.pending_command_title = None;
+        tab.running_process = false;
+        // The new terminal numbers its lines from 0 again, so anything that points at
+        // the old shell's lines is gone with it.
+        tab.last_command = None;
+        tab.output_zones.clear();
+        tab.unfolded_long_lines.clear();
+        tab.long_line_revision = tab.long_line_revision.wrapping_add(1);
+        self.bump_output_zones_revision(index);
+        self.refresh_tab_title(index);
+        if index == self.active_tab {
+            self.clear_selection();
+            self.search_debounce_token = self.search_debounce_token.wrapping_add(1);
+            self.search_state.clear_results_preserving_query();
+            self.clear_terminal_scrollbar_marker_cache();
+        }
+    }
+
+    fn register_shell_restart(recent_restarts: &mut Vec<Instant>, now: Instant) -> bool {
+        recent_restarts
+            .retain(|restarted_at| now.duration_since(*restarted_at) < SHELL_RESTART_WINDOW);
+        if recent_restarts.len() >= SHELL_RESTART_LIMIT {
+            return false;
+        }
+
+        recent_restarts.push(now);
+        true
+    }
+
     pub(super) fn begin_rename_tab(&mut self, index: usize, cx: &mut Context<Self>) {
         if !self.use_tabs || index >= self.tabs.len() {
             return;
@@ -465,6 +653,25 @@ impl TerminalView {
         cx.notify();
     }
 
+    pub(super) fn set_active_tab_profile(
+        &mut self,
+        profile: Option<String>,
+        cx: &mut Context<Self>,
+    ) {
+        // Diagnostics found with the old profile's patterns no longer apply.
+        self.clear_diagnostics();
+        let font = self.resolve_tab_font(profile.as_deref());
+        let tab = &mut self.tabs[self.active_tab];
+        tab.profile = profile;
+        tab.font = font;
+        self.cell_size = None;
+        termy_toast::info(format!(
+            "Tab profile set to {}",
+            tab.profile.as_deref().unwrap_or("Default")
+        ));
+        cx.notify();
+    }
+
     pub(super) fn switch_tab(&mut self, index: usize, cx: &mut Context<Self>) {
         if index >= self.tabs.len() || index == self.active_tab {
             return;
@@ -472,6 +679,8 @@ impl TerminalView {
 
         let old_active = self.active_tab;
         self.active_tab = index;
+        self.tabs[index].unseen_activity = false;
+        self.tabs[index].bell_since_viewed = false;
 
         // Apply inactive_tab_scrollback optimization if configured
         if let Some(inactive_scrollback) = self.inactive_tab_scrollback {
@@ -491,6 +700,7 @@ impl TerminalView {
         self.inline_input_selecting = false;
         self.finish_tab_drag();
         self.clear_selection();
+        self.clear_diagnostics();
         self.scroll_active_tab_into_view();
         cx.notify();
     }
@@ -513,6 +723,20 @@ impl TerminalView {
         cx.notify();
     }
 
+    /// Drops a rename so the tab title follows shell/command updates again.
+    pub(super) fn clear_manual_tab_title(&mut self, index: usize, cx: &mut Context<Self>) {
+        if index >= self.tabs.len() || self.tabs[index].manual_title.take().is_none() {
+            return;
+        }
+
+        if self.renaming_tab == Some(index) {
+            self.cancel_rename_tab(cx);
+        }
+        self.refresh_tab_title(index);
+        termy_toast::info("Tab title reset to automatic");
+        cx.notify();
+    }
+
     pub(super) fn cancel_rename_tab(&mut self, cx: &mut Context<Self>) {
         if self.renaming_tab.is_none() {
             return;
@@ -537,6 +761,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn tab_limit_is_reached_at_max_tabs_unless_unlimited() {
+        assert!(!TerminalView::tab_limit_reached(2, 3));
+        assert!(TerminalView::tab_limit_reached(3, 3));
+        assert!(TerminalView::tab_limit_reached(4, 3));
+        assert!(!TerminalView::tab_limit_reached(100, 0));
+    }
+
     #[test]
     fn tab_display_width_for_title_clamps_to_min() {
         let width = TerminalView::tab_display_width_for_title("a");
@@ -730,4 +962,31 @@ mod tests {
             1
         );
     }
+
+    #[test]
+    fn shell_restart_guard_stops_a_restart_loop() {
+        let start = Instant::now();
+        let mut recent_restarts = Vec::new();
+        for attempt in 0..SHELL_RESTART_LIMIT {
+            let now = start + Duration::from_millis(attempt as u64 * 100);
+            assert!(TerminalView::register_shell_restart(
+                &mut recent_restarts,
+                now
+            ));
+        }
+
+        let burst_end = start + Duration::from_secs(1);
+        assert!(!TerminalView::register_shell_restart(
+            &mut recent_restarts,
+            burst_end
+        ));
+
+        // Once the burst falls outside the window, restarting is allowed again.
+        let later = start + SHELL_RESTART_WINDOW + Duration::from_secs(1);
+        assert!(TerminalView::register_shell_restart(
+            &mut recent_restarts,
+            later
+        ));
+        assert_eq!(recent_restarts, vec![later]);
+    }
 }
@@ -126,11 +126,10 @@ impl TerminalView {
             if prompt.is_empty() {
                 return None;
             }
-            return Some(ExplicitTitlePayload::Prompt(Self::resolve_template(
-                &self.tab_title.prompt_format,
-                Some(prompt),
-                None,
-            )));
+            return Some(ExplicitTitlePayload::Prompt {
+                cwd: prompt.to_string(),
+                title: Self::resolve_template(&self.tab_title.prompt_format, Some(prompt), None),
+            });
         }
 
         if let Some(command) = payload.strip_prefix("command:") {
@@ -138,11 +137,10 @@ impl TerminalView {
             if command.is_empty() {
                 return None;
             }
-            return Some(ExplicitTitlePayload::Command(Self::resolve_template(
-                &self.tab_title.command_format,
-                None,
-                Some(command),
-            )));
+            return Some(ExplicitTitlePayload::Command {
+                command: command.to_string(),
+                title: Self::resolve_template(&self.tab_title.command_format, None, Some(command)),
+            });
         }
 
         let explicit = payload.strip_prefix("title:").unwrap_or(payload).trim();
@@ -266,6 +264,7 @@ impl TerminalView {
         &mut self,
         index: usize,
         title: &str,
+        cursor_line: u64,
         cx: &mut Context<Self>,
     ) -> bool {
         let title = title.trim();
@@ -275,8 +274,16 @@ impl TerminalView {
 
         if let Some(explicit_payload) = self.parse_explicit_title(title) {
             return match explicit_payload {
-                ExplicitTitlePayload::Prompt(prompt_title) => {
+                ExplicitTitlePayload::Prompt {
+                    cwd,
+                    title: prompt_title,
+                } => {
                     self.tabs[index].running_process = false;
+                    // A fresh prompt means output is flowing again.
+                    self.tabs[index].output_paused = false;
+                    self.tabs[index].prompt_input = PromptInput::AwaitingInput;
+                    self.tabs[index].working_dir = Some(cwd);
+                    self.end_output_zone(index, cursor_line);
                     self.cancel_pending_command_title(index);
                     self.set_explicit_title(index, prompt_title)
                 }
@@ -284,8 +291,14 @@ impl TerminalView {
                     self.cancel_pending_command_title(index);
                     self.set_explicit_title(index, prompt_title)
                 }
-                ExplicitTitlePayload::Command(command_title) => {
+                ExplicitTitlePayload::Command {
+                    command,
+                    title: command_title,
+                } => {
                     self.tabs[index].running_process = true;
+                    self.tabs[index].prompt_input = PromptInput::Unknown;
+                    self.tabs[index].last_command = Some(command);
+                    self.begin_output_zone(index, cursor_line);
                     self.schedule_delayed_command_title(
                         index,
                         command_title,
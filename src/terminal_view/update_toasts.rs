@@ -1,5 +1,7 @@
 use super::*;
 
+pub(super) const INSTALL_UPDATE_TOAST_ACTION: &str = "install_update";
+
 impl TerminalView {
     pub(super) fn sync_update_toasts(&mut self, state: Option<&UpdateState>) {
         let changed = self.last_notified_update_state.as_ref() != state;
@@ -22,10 +24,19 @@ impl TerminalView {
 
         match state {
             Some(UpdateState::Available { version, .. }) => {
-                update_or_create(
-                    &mut self.update_check_toast_id,
+                // Replace the loading toast rather than update it so it can carry a button.
+                if let Some(id) = self.update_check_toast_id.take() {
+                    termy_toast::dismiss_toast(id);
+                }
+                termy_toast::enqueue_toast_with_actions(
                     termy_toast::ToastKind::Info,
                     format!("Update v{} available", version),
+                    Some(Duration::from_millis(8000)),
+                    vec![termy_toast::ToastAction::new(
+                        INSTALL_UPDATE_TOAST_ACTION,
+                        "Install",
+                    )],
+                    false,
                 );
             }
             Some(UpdateState::Downloaded { version, .. }) => {
@@ -72,11 +83,11 @@ impl TerminalView {
                 );
             }
             Some(UpdateState::Error(message)) => {
-                update_or_create(
-                    &mut self.update_check_toast_id,
-                    termy_toast::ToastKind::Error,
-                    format!("Update failed: {}", message),
-                );
+                // A fresh toast, so the failure isn't buried under other toasts.
+                if let Some(id) = self.update_check_toast_id.take() {
+                    termy_toast::dismiss_toast(id);
+                }
+                termy_toast::error_high(format!("Update failed: {}", message));
             }
             Some(UpdateState::UpToDate) => {
                 if let Some(id) = self.update_check_toast_id.take() {
@@ -86,4 +97,33 @@ impl TerminalView {
             _ => {}
         }
     }
+
+    pub(super) fn install_update_from_toast(&mut self, cx: &mut Context<Self>) {
+        if let Some(updater) = self.auto_updater.as_ref() {
+            AutoUpdater::install(updater.downgrade(), cx);
+            termy_toast::info("Downloading update...");
+        }
+    }
+
+    pub(super) fn skip_available_update(&mut self, cx: &mut Context<Self>) {
+        let Some(updater) = self.auto_updater.clone() else {
+            return;
+        };
+        let version = match &updater.read(cx).state {
+            UpdateState::Available { version, .. } => version.clone(),
+            _ => return,
+        };
+
+        updater.update(cx, |updater, cx| {
+            updater.skip_version(version.clone());
+            cx.notify();
+        });
+        if let Err(error) = config::set_config_value("skipped_update_version", &version) {
+            termy_toast::error(error);
+        }
+        termy_toast::info(format!(
+            "Skipping v{}; newer versions will still be offered",
+            version
+        ));
+    }
 }
@@ -57,6 +57,22 @@ impl ScrollbarPaintStyle {
     }
 }
 
+/// An extra set of markers with its own color, painted beneath the primary markers.
+#[derive(Clone, Copy, Debug)]
+pub struct ScrollbarMarkerLayer<'a> {
+    pub tops: &'a [f32],
+    pub color: Rgba,
+}
+
+impl ScrollbarMarkerLayer<'_> {
+    pub fn scale_alpha(self, alpha: f32) -> Self {
+        Self {
+            color: scale_color_alpha(self.color, alpha.clamp(0.0, 1.0)),
+            ..self
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Default)]
 pub struct ScrollbarVisibilityController {
     last_activity: Option<Instant>,
@@ -206,6 +222,7 @@ pub fn render_vertical(
     metrics: ScrollbarMetrics,
     style: ScrollbarPaintStyle,
     thumb_active: bool,
+    marker_layers: &[ScrollbarMarkerLayer<'_>],
     marker_tops: &[f32],
     current_marker_top: Option<f32>,
     marker_height: f32,
@@ -220,6 +237,10 @@ pub fn render_vertical(
     let marker_radius = style.marker_radius.max(0.0);
     let mut marker_elements = Vec::new();
     if marker_height > 0.0 {
+        let layer_marker_count = marker_layers
+            .iter()
+            .map(|layer| layer.tops.len())
+            .sum::<usize>();
         let marker_count = if style.marker_color.is_some() {
             marker_tops.len()
         } else {
@@ -227,36 +248,35 @@ pub fn render_vertical(
         };
         let current_marker_count =
             usize::from(style.current_marker_color.is_some() && current_marker_top.is_some());
-        marker_elements.reserve(marker_count.saturating_add(current_marker_count));
+        marker_elements.reserve(
+            layer_marker_count
+                .saturating_add(marker_count)
+                .saturating_add(current_marker_count),
+        );
         let marker_top_max = (metrics.track_height - marker_height).max(0.0);
+        let marker = |top: f32, color: Rgba| {
+            div()
+                .absolute()
+                .left(px(marker_inset))
+                .right(px(marker_inset))
+                .top(px(top.clamp(0.0, marker_top_max)))
+                .h(px(marker_height))
+                .rounded(px(marker_radius))
+                .bg(color)
+                .into_any_element()
+        };
+
+        for layer in marker_layers {
+            marker_elements.extend(layer.tops.iter().map(|&top| marker(top, layer.color)));
+        }
 
         if let Some(color) = style.marker_color {
-            marker_elements.extend(marker_tops.iter().copied().map(|top| {
-                div()
-                    .absolute()
-                    .left(px(marker_inset))
-                    .right(px(marker_inset))
-                    .top(px(top.clamp(0.0, marker_top_max)))
-                    .h(px(marker_height))
-                    .rounded(px(marker_radius))
-                    .bg(color)
-                    .into_any_element()
-            }));
+            marker_elements.extend(marker_tops.iter().map(|&top| marker(top, color)));
         }
 
         if let Some(color) = style.current_marker_color {
             if let Some(top) = current_marker_top {
-                marker_elements.push(
-                    div()
-                        .absolute()
-                        .left(px(marker_inset))
-                        .right(px(marker_inset))
-                        .top(px(top.clamp(0.0, marker_top_max)))
-                        .h(px(marker_height))
-                        .rounded(px(marker_radius))
-                        .bg(color)
-                        .into_any_element(),
-                );
+                marker_elements.push(marker(top, color));
             }
         }
     }